@@ -38,97 +38,173 @@ impl Result {
 single_register_result64!(Result);
 
 #[cfg(not(doc))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! syscall {
-	($nr:expr $(,)?) => {{
+macro_rules! __syscall_with_options {
+	([$($opt:ident),*] $nr:expr $(,)?) => {{
 		let mut out_x0;
 		core::arch::asm!(
 			"svc #0",
-			in("x8") u32::from(Into::<$crate::Syscall>::into($nr)),
+			in("x8") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
 			lateout("x0") out_x0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::aarch64::Result::new(out_x0)
 	}};
-	($nr:expr, $a1:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr $(,)?) => {{
 		let mut out_x0;
 		core::arch::asm!(
 			"svc #0",
-			in("x8") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("x0") $a1,
+			in("x8") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
 			lateout("x0") out_x0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::aarch64::Result::new(out_x0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr $(,)?) => {{
 		let mut out_x0;
 		core::arch::asm!(
 			"svc #0",
-			in("x8") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("x0") $a1,
-			in("x1") $a2,
+			in("x8") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("x1") $crate::IntoSyscallArg::into_syscall_arg($a2),
 			lateout("x0") out_x0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::aarch64::Result::new(out_x0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
 		let mut out_x0;
 		core::arch::asm!(
 			"svc #0",
-			in("x8") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("x0") $a1,
-			in("x1") $a2,
-			in("x2") $a3,
+			in("x8") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("x1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("x2") $crate::IntoSyscallArg::into_syscall_arg($a3),
 			lateout("x0") out_x0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::aarch64::Result::new(out_x0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
 		let mut out_x0;
 		core::arch::asm!(
 			"svc #0",
-			in("x8") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("x0") $a1,
-			in("x1") $a2,
-			in("x2") $a3,
-			in("x3") $a4,
+			in("x8") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("x1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("x2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("x3") $crate::IntoSyscallArg::into_syscall_arg($a4),
 			lateout("x0") out_x0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::aarch64::Result::new(out_x0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
 		let mut out_x0;
 		core::arch::asm!(
 			"svc #0",
-			in("x8") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("x0") $a1,
-			in("x1") $a2,
-			in("x2") $a3,
-			in("x3") $a4,
-			in("x4") $a5,
+			in("x8") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("x1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("x2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("x3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("x4") $crate::IntoSyscallArg::into_syscall_arg($a5),
 			lateout("x0") out_x0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::aarch64::Result::new(out_x0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
 		let mut out_x0;
 		core::arch::asm!(
 			"svc #0",
-			in("x8") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("x0") $a1,
-			in("x1") $a2,
-			in("x2") $a3,
-			in("x3") $a4,
-			in("x4") $a5,
-			in("x5") $a6,
+			in("x8") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("x1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("x2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("x3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("x4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("x5") $crate::IntoSyscallArg::into_syscall_arg($a6),
 			lateout("x0") out_x0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::aarch64::Result::new(out_x0)
 	}};
 }
+
+#[cfg(not(doc))]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($nr:expr $(,)?) => {{
+		core::arch::asm!(
+			"svc #0",
+			in("x8") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		core::arch::asm!(
+			"svc #0",
+			in("x8") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		core::arch::asm!(
+			"svc #0",
+			in("x8") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("x1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		core::arch::asm!(
+			"svc #0",
+			in("x8") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("x1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("x2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		core::arch::asm!(
+			"svc #0",
+			in("x8") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("x1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("x2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("x3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		core::arch::asm!(
+			"svc #0",
+			in("x8") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("x1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("x2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("x3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("x4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		core::arch::asm!(
+			"svc #0",
+			in("x8") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("x0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("x1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("x2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("x3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("x4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("x5") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			options(noreturn, nostack),
+		)
+	}};
+}
@@ -16,307 +16,307 @@
 #![allow(non_upper_case_globals)]
 
 syscall_constants! {
-	SYS_io_setup                = 0,
-	SYS_io_destroy              = 1,
-	SYS_io_submit               = 2,
-	SYS_io_cancel               = 3,
-	SYS_io_getevents            = 4,
-	SYS_setxattr                = 5,
-	SYS_lsetxattr               = 6,
-	SYS_fsetxattr               = 7,
-	SYS_getxattr                = 8,
-	SYS_lgetxattr               = 9,
-	SYS_fgetxattr               = 10,
-	SYS_listxattr               = 11,
-	SYS_llistxattr              = 12,
-	SYS_flistxattr              = 13,
-	SYS_removexattr             = 14,
-	SYS_lremovexattr            = 15,
-	SYS_fremovexattr            = 16,
-	SYS_getcwd                  = 17,
-	SYS_lookup_dcookie          = 18,
-	SYS_eventfd2                = 19,
-	SYS_epoll_create1           = 20,
-	SYS_epoll_ctl               = 21,
-	SYS_epoll_pwait             = 22,
-	SYS_dup                     = 23,
-	SYS_dup3                    = 24,
-	SYS_fcntl                   = 25,
-	SYS_inotify_init1           = 26,
-	SYS_inotify_add_watch       = 27,
-	SYS_inotify_rm_watch        = 28,
-	SYS_ioctl                   = 29,
-	SYS_ioprio_set              = 30,
-	SYS_ioprio_get              = 31,
-	SYS_flock                   = 32,
-	SYS_mknodat                 = 33,
-	SYS_mkdirat                 = 34,
-	SYS_unlinkat                = 35,
-	SYS_symlinkat               = 36,
-	SYS_linkat                  = 37,
-	SYS_renameat                = 38,
-	SYS_umount2                 = 39,
-	SYS_mount                   = 40,
-	SYS_pivot_root              = 41,
-	SYS_statfs                  = 43,
-	SYS_fstatfs                 = 44,
-	SYS_truncate                = 45,
-	SYS_ftruncate               = 46,
-	SYS_fallocate               = 47,
-	SYS_faccessat               = 48,
-	SYS_chdir                   = 49,
-	SYS_fchdir                  = 50,
-	SYS_chroot                  = 51,
-	SYS_fchmod                  = 52,
-	SYS_fchmodat                = 53,
-	SYS_fchownat                = 54,
-	SYS_fchown                  = 55,
-	SYS_openat                  = 56,
-	SYS_close                   = 57,
-	SYS_vhangup                 = 58,
-	SYS_pipe2                   = 59,
-	SYS_quotactl                = 60,
-	SYS_getdents64              = 61,
-	SYS_lseek                   = 62,
-	SYS_read                    = 63,
-	SYS_write                   = 64,
-	SYS_readv                   = 65,
-	SYS_writev                  = 66,
-	SYS_pread64                 = 67,
-	SYS_pwrite64                = 68,
-	SYS_preadv                  = 69,
-	SYS_pwritev                 = 70,
-	SYS_sendfile                = 71,
-	SYS_pselect6                = 72,
-	SYS_ppoll                   = 73,
-	SYS_signalfd4               = 74,
-	SYS_vmsplice                = 75,
-	SYS_splice                  = 76,
-	SYS_tee                     = 77,
-	SYS_readlinkat              = 78,
-	SYS_newfstatat              = 79,
-	SYS_fstat                   = 80,
-	SYS_sync                    = 81,
-	SYS_fsync                   = 82,
-	SYS_fdatasync               = 83,
-	SYS_sync_file_range         = 84,
-	SYS_timerfd_create          = 85,
-	SYS_timerfd_settime         = 86,
-	SYS_timerfd_gettime         = 87,
-	SYS_utimensat               = 88,
-	SYS_acct                    = 89,
-	SYS_capget                  = 90,
-	SYS_capset                  = 91,
-	SYS_personality             = 92,
-	SYS_exit                    = 93,
-	SYS_exit_group              = 94,
-	SYS_waitid                  = 95,
-	SYS_set_tid_address         = 96,
-	SYS_unshare                 = 97,
-	SYS_futex                   = 98,
-	SYS_set_robust_list         = 99,
-	SYS_get_robust_list         = 100,
-	SYS_nanosleep               = 101,
-	SYS_getitimer               = 102,
-	SYS_setitimer               = 103,
-	SYS_kexec_load              = 104,
-	SYS_init_module             = 105,
-	SYS_delete_module           = 106,
-	SYS_timer_create            = 107,
-	SYS_timer_gettime           = 108,
-	SYS_timer_getoverrun        = 109,
-	SYS_timer_settime           = 110,
-	SYS_timer_delete            = 111,
-	SYS_clock_settime           = 112,
-	SYS_clock_gettime           = 113,
-	SYS_clock_getres            = 114,
-	SYS_clock_nanosleep         = 115,
-	SYS_syslog                  = 116,
-	SYS_ptrace                  = 117,
-	SYS_sched_setparam          = 118,
-	SYS_sched_setscheduler      = 119,
-	SYS_sched_getscheduler      = 120,
-	SYS_sched_getparam          = 121,
-	SYS_sched_setaffinity       = 122,
-	SYS_sched_getaffinity       = 123,
-	SYS_sched_yield             = 124,
-	SYS_sched_get_priority_max  = 125,
-	SYS_sched_get_priority_min  = 126,
-	SYS_sched_rr_get_interval   = 127,
-	SYS_kill                    = 129,
-	SYS_tkill                   = 130,
-	SYS_tgkill                  = 131,
-	SYS_sigaltstack             = 132,
-	SYS_rt_sigsuspend           = 133,
-	SYS_rt_sigaction            = 134,
-	SYS_rt_sigprocmask          = 135,
-	SYS_rt_sigpending           = 136,
-	SYS_rt_sigtimedwait         = 137,
-	SYS_rt_sigqueueinfo         = 138,
-	SYS_rt_sigreturn            = 139,
-	SYS_setpriority             = 140,
-	SYS_getpriority             = 141,
-	SYS_reboot                  = 142,
-	SYS_setregid                = 143,
-	SYS_setgid                  = 144,
-	SYS_setreuid                = 145,
-	SYS_setuid                  = 146,
-	SYS_setresuid               = 147,
-	SYS_getresuid               = 148,
-	SYS_setresgid               = 149,
-	SYS_getresgid               = 150,
-	SYS_setfsuid                = 151,
-	SYS_setfsgid                = 152,
-	SYS_times                   = 153,
-	SYS_setpgid                 = 154,
-	SYS_getpgid                 = 155,
-	SYS_getsid                  = 156,
-	SYS_setsid                  = 157,
-	SYS_getgroups               = 158,
-	SYS_setgroups               = 159,
-	SYS_uname                   = 160,
-	SYS_sethostname             = 161,
-	SYS_setdomainname           = 162,
-	SYS_getrlimit               = 163,
-	SYS_setrlimit               = 164,
-	SYS_getrusage               = 165,
-	SYS_umask                   = 166,
-	SYS_prctl                   = 167,
-	SYS_getcpu                  = 168,
-	SYS_gettimeofday            = 169,
-	SYS_settimeofday            = 170,
-	SYS_adjtimex                = 171,
-	SYS_getpid                  = 172,
-	SYS_getppid                 = 173,
-	SYS_getuid                  = 174,
-	SYS_geteuid                 = 175,
-	SYS_getgid                  = 176,
-	SYS_getegid                 = 177,
-	SYS_gettid                  = 178,
-	SYS_sysinfo                 = 179,
-	SYS_mq_open                 = 180,
-	SYS_mq_unlink               = 181,
-	SYS_mq_timedsend            = 182,
-	SYS_mq_timedreceive         = 183,
-	SYS_mq_notify               = 184,
-	SYS_mq_getsetattr           = 185,
-	SYS_msgget                  = 186,
-	SYS_msgctl                  = 187,
-	SYS_msgrcv                  = 188,
-	SYS_msgsnd                  = 189,
-	SYS_semget                  = 190,
-	SYS_semctl                  = 191,
-	SYS_semtimedop              = 192,
-	SYS_semop                   = 193,
-	SYS_shmget                  = 194,
-	SYS_shmctl                  = 195,
-	SYS_shmat                   = 196,
-	SYS_shmdt                   = 197,
-	SYS_socket                  = 198,
-	SYS_socketpair              = 199,
-	SYS_bind                    = 200,
-	SYS_listen                  = 201,
-	SYS_accept                  = 202,
-	SYS_connect                 = 203,
-	SYS_getsockname             = 204,
-	SYS_getpeername             = 205,
-	SYS_sendto                  = 206,
-	SYS_recvfrom                = 207,
-	SYS_setsockopt              = 208,
-	SYS_getsockopt              = 209,
-	SYS_shutdown                = 210,
-	SYS_sendmsg                 = 211,
-	SYS_recvmsg                 = 212,
-	SYS_readahead               = 213,
-	SYS_brk                     = 214,
-	SYS_munmap                  = 215,
-	SYS_mremap                  = 216,
-	SYS_add_key                 = 217,
-	SYS_request_key             = 218,
-	SYS_keyctl                  = 219,
-	SYS_clone                   = 220,
-	SYS_execve                  = 221,
-	SYS_mmap                    = 222,
-	SYS_fadvise64               = 223,
-	SYS_swapon                  = 224,
-	SYS_swapoff                 = 225,
-	SYS_mprotect                = 226,
-	SYS_msync                   = 227,
-	SYS_mlock                   = 228,
-	SYS_munlock                 = 229,
-	SYS_mlockall                = 230,
-	SYS_munlockall              = 231,
-	SYS_mincore                 = 232,
-	SYS_madvise                 = 233,
-	SYS_mbind                   = 235,
-	SYS_get_mempolicy           = 236,
-	SYS_set_mempolicy           = 237,
-	SYS_migrate_pages           = 238,
-	SYS_move_pages              = 239,
-	SYS_rt_tgsigqueueinfo       = 240,
-	SYS_perf_event_open         = 241,
-	SYS_accept4                 = 242,
-	SYS_recvmmsg                = 243,
-	SYS_wait4                   = 260,
-	SYS_prlimit64               = 261,
-	SYS_fanotify_init           = 262,
-	SYS_fanotify_mark           = 263,
-	SYS_name_to_handle_at       = 264,
-	SYS_open_by_handle_at       = 265,
-	SYS_clock_adjtime           = 266,
-	SYS_syncfs                  = 267,
-	SYS_setns                   = 268,
-	SYS_sendmmsg                = 269,
-	SYS_process_vm_readv        = 270,
-	SYS_process_vm_writev       = 271,
-	SYS_kcmp                    = 272,
-	SYS_finit_module            = 273,
-	SYS_sched_setattr           = 274,
-	SYS_sched_getattr           = 275,
-	SYS_renameat2               = 276,
-	SYS_seccomp                 = 277,
-	SYS_getrandom               = 278,
-	SYS_memfd_create            = 279,
-	SYS_bpf                     = 280,
-	SYS_execveat                = 281,
-	SYS_userfaultfd             = 282,
-	SYS_membarrier              = 283,
-	SYS_mlock2                  = 284,
-	SYS_copy_file_range         = 285,
-	SYS_preadv2                 = 286,
-	SYS_pwritev2                = 287,
-	SYS_pkey_mprotect           = 288,
-	SYS_pkey_alloc              = 289,
-	SYS_pkey_free               = 290,
-	SYS_statx                   = 291,
-	SYS_io_pgetevents           = 292,
-	SYS_rseq                    = 293,
-	SYS_kexec_file_load         = 294,
-	SYS_pidfd_send_signal       = 424,
-	SYS_io_uring_setup          = 425,
-	SYS_io_uring_enter          = 426,
-	SYS_io_uring_register       = 427,
-	SYS_open_tree               = 428,
-	SYS_move_mount              = 429,
-	SYS_fsopen                  = 430,
-	SYS_fsconfig                = 431,
-	SYS_fsmount                 = 432,
-	SYS_fspick                  = 433,
-	SYS_pidfd_open              = 434,
-	SYS_clone3                  = 435,
-	SYS_close_range             = 436,
-	SYS_openat2                 = 437,
-	SYS_pidfd_getfd             = 438,
-	SYS_faccessat2              = 439,
-	SYS_process_madvise         = 440,
-	SYS_epoll_pwait2            = 441,
-	SYS_mount_setattr           = 442,
-	SYS_quotactl_fd             = 443,
-	SYS_landlock_create_ruleset = 444,
-	SYS_landlock_add_rule       = 445,
-	SYS_landlock_restrict_self  = 446,
-	SYS_memfd_secret            = 447,
-	SYS_process_mrelease        = 448,
-	SYS_futex_waitv             = 449,
-	SYS_set_mempolicy_home_node = 450,
+	SYS_io_setup                = 0   (Len, PtrOut),
+	SYS_io_destroy              = 1   (Int),
+	SYS_io_submit               = 2   (Int, Len, PtrIn),
+	SYS_io_cancel               = 3   (Int, PtrIn, PtrOut),
+	SYS_io_getevents            = 4   (Int, Len, Len, PtrOut, PtrIn),
+	SYS_setxattr                = 5   (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_lsetxattr               = 6   (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_fsetxattr               = 7   (Fd, PtrIn, PtrIn, Len, Flags),
+	SYS_getxattr                = 8   (PtrIn, PtrIn, PtrOut, Len),
+	SYS_lgetxattr               = 9   (PtrIn, PtrIn, PtrOut, Len),
+	SYS_fgetxattr               = 10  (Fd, PtrIn, PtrOut, Len),
+	SYS_listxattr               = 11  (PtrIn, PtrOut, Len),
+	SYS_llistxattr              = 12  (PtrIn, PtrOut, Len),
+	SYS_flistxattr              = 13  (Fd, PtrOut, Len),
+	SYS_removexattr             = 14  (PtrIn, PtrIn),
+	SYS_lremovexattr            = 15  (PtrIn, PtrIn),
+	SYS_fremovexattr            = 16  (Fd, PtrIn),
+	SYS_getcwd                  = 17  (PtrOut, Len),
+	SYS_lookup_dcookie          = 18  (Int64, PtrOut, Len),
+	SYS_eventfd2                = 19  (Int, Flags),
+	SYS_epoll_create1           = 20  (Flags),
+	SYS_epoll_ctl               = 21  (Fd, Flags, Fd, PtrIn),
+	SYS_epoll_pwait             = 22  (Fd, PtrOut, Len, Int, PtrIn, Len),
+	SYS_dup                     = 23  (Fd),
+	SYS_dup3                    = 24  (Fd, Fd, Flags),
+	SYS_fcntl                   = 25  (Fd, Flags, Int),
+	SYS_inotify_init1           = 26  (Flags),
+	SYS_inotify_add_watch       = 27  (Fd, PtrIn, Flags),
+	SYS_inotify_rm_watch        = 28  (Fd, Int),
+	SYS_ioctl                   = 29  (Fd, Flags, Int),
+	SYS_ioprio_set              = 30  (Int, Int, Int),
+	SYS_ioprio_get              = 31  (Int, Int),
+	SYS_flock                   = 32  (Fd, Flags),
+	SYS_mknodat                 = 33  (Fd, PtrIn, Flags, Int),
+	SYS_mkdirat                 = 34  (Fd, PtrIn, Flags),
+	SYS_unlinkat                = 35  (Fd, PtrIn, Flags),
+	SYS_symlinkat               = 36  (PtrIn, Fd, PtrIn),
+	SYS_linkat                  = 37  (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_renameat                = 38  (Fd, PtrIn, Fd, PtrIn),
+	SYS_umount2                 = 39  (PtrIn, Flags),
+	SYS_mount                   = 40  (PtrIn, PtrIn, PtrIn, Flags, PtrIn),
+	SYS_pivot_root              = 41  (PtrIn, PtrIn),
+	SYS_statfs                  = 43  (PtrIn, PtrOut),
+	SYS_fstatfs                 = 44  (Fd, PtrOut),
+	SYS_truncate                = 45  (PtrIn, Int64),
+	SYS_ftruncate               = 46  (Fd, Int64),
+	SYS_fallocate               = 47  (Fd, Flags, Int64, Int64),
+	SYS_faccessat               = 48  (Fd, PtrIn, Flags),
+	SYS_chdir                   = 49  (PtrIn),
+	SYS_fchdir                  = 50  (Fd),
+	SYS_chroot                  = 51  (PtrIn),
+	SYS_fchmod                  = 52  (Fd, Flags),
+	SYS_fchmodat                = 53  (Fd, PtrIn, Flags),
+	SYS_fchownat                = 54  (Fd, PtrIn, Int, Int, Flags),
+	SYS_fchown                  = 55  (Fd, Int, Int),
+	SYS_openat                  = 56  (Fd, PtrIn, Flags, Flags),
+	SYS_close                   = 57  (Fd),
+	SYS_vhangup                 = 58  (),
+	SYS_pipe2                   = 59  (PtrOut, Flags),
+	SYS_quotactl                = 60  (Flags, PtrIn, Int, PtrInOut),
+	SYS_getdents64              = 61  (Fd, PtrOut, Len),
+	SYS_lseek                   = 62  (Fd, Int64, Int),
+	SYS_read                    = 63  (Fd, PtrOut, Len),
+	SYS_write                   = 64  (Fd, PtrIn, Len),
+	SYS_readv                   = 65  (Fd, PtrOut, Len),
+	SYS_writev                  = 66  (Fd, PtrIn, Len),
+	SYS_pread64                 = 67  (Fd, PtrOut, Len, Int64),
+	SYS_pwrite64                = 68  (Fd, PtrIn, Len, Int64),
+	SYS_preadv                  = 69  (Fd, PtrOut, Len, Int64, Int),
+	SYS_pwritev                 = 70  (Fd, PtrIn, Len, Int64, Int),
+	SYS_sendfile                = 71  (Fd, Fd, PtrInOut, Len),
+	SYS_pselect6                = 72  (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll                   = 73  (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_signalfd4               = 74  (Fd, PtrIn, Len, Flags),
+	SYS_vmsplice                = 75  (Fd, PtrIn, Len, Flags),
+	SYS_splice                  = 76  (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_tee                     = 77  (Fd, Fd, Len, Flags),
+	SYS_readlinkat              = 78  (Fd, PtrIn, PtrOut, Len),
+	SYS_newfstatat              = 79  (Fd, PtrIn, PtrOut, Flags),
+	SYS_fstat                   = 80  (Fd, PtrOut),
+	SYS_sync                    = 81  (),
+	SYS_fsync                   = 82  (Fd),
+	SYS_fdatasync               = 83  (Fd),
+	SYS_sync_file_range         = 84  (Fd, Int64, Int64, Flags),
+	SYS_timerfd_create          = 85  (Int, Flags),
+	SYS_timerfd_settime         = 86  (Fd, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime         = 87  (Fd, PtrOut),
+	SYS_utimensat               = 88  (Fd, PtrIn, PtrIn, Flags),
+	SYS_acct                    = 89  (PtrIn),
+	SYS_capget                  = 90  (PtrInOut, PtrOut),
+	SYS_capset                  = 91  (PtrIn, PtrIn),
+	SYS_personality             = 92  (Flags),
+	SYS_exit                    = 93  (Int),
+	SYS_exit_group              = 94  (Int),
+	SYS_waitid                  = 95  (Int, Int, PtrOut, Flags, PtrOut),
+	SYS_set_tid_address         = 96  (Addr),
+	SYS_unshare                 = 97  (Flags),
+	SYS_futex                   = 98  (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_set_robust_list         = 99  (Addr, Len),
+	SYS_get_robust_list         = 100 (Int, PtrOut, PtrOut),
+	SYS_nanosleep               = 101 (PtrIn, PtrOut),
+	SYS_getitimer               = 102 (Int, PtrOut),
+	SYS_setitimer               = 103 (Int, PtrIn, PtrOut),
+	SYS_kexec_load              = 104 (Addr, Len, PtrIn, Flags),
+	SYS_init_module             = 105 (PtrIn, Len, PtrIn),
+	SYS_delete_module           = 106 (PtrIn, Flags),
+	SYS_timer_create            = 107 (Int, PtrIn, PtrOut),
+	SYS_timer_gettime           = 108 (Int, PtrOut),
+	SYS_timer_getoverrun        = 109 (Int),
+	SYS_timer_settime           = 110 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_delete            = 111 (Int),
+	SYS_clock_settime           = 112 (Int, PtrIn),
+	SYS_clock_gettime           = 113 (Int, PtrOut),
+	SYS_clock_getres            = 114 (Int, PtrOut),
+	SYS_clock_nanosleep         = 115 (Int, Flags, PtrIn, PtrOut),
+	SYS_syslog                  = 116 (Flags, PtrOut, Len),
+	SYS_ptrace                  = 117 (Flags, Int, Addr, Int),
+	SYS_sched_setparam          = 118 (Int, PtrIn),
+	SYS_sched_setscheduler      = 119 (Int, Int, PtrIn),
+	SYS_sched_getscheduler      = 120 (Int),
+	SYS_sched_getparam          = 121 (Int, PtrOut),
+	SYS_sched_setaffinity       = 122 (Int, Len, PtrIn),
+	SYS_sched_getaffinity       = 123 (Int, Len, PtrOut),
+	SYS_sched_yield             = 124 (),
+	SYS_sched_get_priority_max  = 125 (Int),
+	SYS_sched_get_priority_min  = 126 (Int),
+	SYS_sched_rr_get_interval   = 127 (Int, PtrOut),
+	SYS_kill                    = 129 (Int, Int),
+	SYS_tkill                   = 130 (Int, Int),
+	SYS_tgkill                  = 131 (Int, Int, Int),
+	SYS_sigaltstack             = 132 (PtrIn, PtrOut),
+	SYS_rt_sigsuspend           = 133 (PtrIn, Len),
+	SYS_rt_sigaction            = 134 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigprocmask          = 135 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigpending           = 136 (PtrOut, Len),
+	SYS_rt_sigtimedwait         = 137 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_rt_sigqueueinfo         = 138 (Int, Int, PtrIn),
+	SYS_rt_sigreturn            = 139 (),
+	SYS_setpriority             = 140 (Int, Int, Int),
+	SYS_getpriority             = 141 (Int, Int),
+	SYS_reboot                  = 142 (Int, Int, Flags, PtrIn),
+	SYS_setregid                = 143 (Int, Int),
+	SYS_setgid                  = 144 (Int),
+	SYS_setreuid                = 145 (Int, Int),
+	SYS_setuid                  = 146 (Int),
+	SYS_setresuid               = 147 (Int, Int, Int),
+	SYS_getresuid               = 148 (PtrOut, PtrOut, PtrOut),
+	SYS_setresgid               = 149 (Int, Int, Int),
+	SYS_getresgid               = 150 (PtrOut, PtrOut, PtrOut),
+	SYS_setfsuid                = 151 (Int),
+	SYS_setfsgid                = 152 (Int),
+	SYS_times                   = 153 (PtrOut),
+	SYS_setpgid                 = 154 (Int, Int),
+	SYS_getpgid                 = 155 (Int),
+	SYS_getsid                  = 156 (Int),
+	SYS_setsid                  = 157 (),
+	SYS_getgroups               = 158 (Len, PtrOut),
+	SYS_setgroups               = 159 (Len, PtrIn),
+	SYS_uname                   = 160 (PtrOut),
+	SYS_sethostname             = 161 (PtrIn, Len),
+	SYS_setdomainname           = 162 (PtrIn, Len),
+	SYS_getrlimit               = 163 (Int, PtrOut),
+	SYS_setrlimit               = 164 (Int, PtrIn),
+	SYS_getrusage               = 165 (Int, PtrOut),
+	SYS_umask                   = 166 (Flags),
+	SYS_prctl                   = 167 (Flags, Int, Int, Int, Int),
+	SYS_getcpu                  = 168 (PtrOut, PtrOut, PtrOut),
+	SYS_gettimeofday            = 169 (PtrOut, PtrOut),
+	SYS_settimeofday            = 170 (PtrIn, PtrIn),
+	SYS_adjtimex                = 171 (PtrInOut),
+	SYS_getpid                  = 172 (),
+	SYS_getppid                 = 173 (),
+	SYS_getuid                  = 174 (),
+	SYS_geteuid                 = 175 (),
+	SYS_getgid                  = 176 (),
+	SYS_getegid                 = 177 (),
+	SYS_gettid                  = 178 (),
+	SYS_sysinfo                 = 179 (PtrOut),
+	SYS_mq_open                 = 180 (PtrIn, Flags, Flags, PtrIn),
+	SYS_mq_unlink               = 181 (PtrIn),
+	SYS_mq_timedsend            = 182 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive         = 183 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_mq_notify               = 184 (Fd, PtrIn),
+	SYS_mq_getsetattr           = 185 (Fd, PtrIn, PtrOut),
+	SYS_msgget                  = 186 (Int, Flags),
+	SYS_msgctl                  = 187 (Int, Flags, PtrInOut),
+	SYS_msgrcv                  = 188 (Int, PtrOut, Len, Int, Flags),
+	SYS_msgsnd                  = 189 (Int, PtrIn, Len, Flags),
+	SYS_semget                  = 190 (Int, Len, Flags),
+	SYS_semctl                  = 191 (Int, Int, Flags, Int),
+	SYS_semtimedop              = 192 (Int, PtrIn, Len, PtrIn),
+	SYS_semop                   = 193 (Int, PtrIn, Len),
+	SYS_shmget                  = 194 (Int, Len, Flags),
+	SYS_shmctl                  = 195 (Int, Flags, PtrInOut),
+	SYS_shmat                   = 196 (Int, Addr, Flags),
+	SYS_shmdt                   = 197 (Addr),
+	SYS_socket                  = 198 (Int, Flags, Int),
+	SYS_socketpair              = 199 (Int, Flags, Int, PtrOut),
+	SYS_bind                    = 200 (Fd, PtrIn, Len),
+	SYS_listen                  = 201 (Fd, Int),
+	SYS_accept                  = 202 (Fd, PtrOut, PtrInOut),
+	SYS_connect                 = 203 (Fd, PtrIn, Len),
+	SYS_getsockname             = 204 (Fd, PtrOut, PtrInOut),
+	SYS_getpeername             = 205 (Fd, PtrOut, PtrInOut),
+	SYS_sendto                  = 206 (Fd, PtrIn, Len, Flags, PtrIn, Len),
+	SYS_recvfrom                = 207 (Fd, PtrOut, Len, Flags, PtrOut, PtrInOut),
+	SYS_setsockopt              = 208 (Fd, Int, Int, PtrIn, Len),
+	SYS_getsockopt              = 209 (Fd, Int, Int, PtrOut, PtrInOut),
+	SYS_shutdown                = 210 (Fd, Int),
+	SYS_sendmsg                 = 211 (Fd, PtrIn, Flags),
+	SYS_recvmsg                 = 212 (Fd, PtrInOut, Flags),
+	SYS_readahead               = 213 (Fd, Int64, Len),
+	SYS_brk                     = 214 (Addr),
+	SYS_munmap                  = 215 (Addr, Len),
+	SYS_mremap                  = 216 (Addr, Len, Len, Flags, Addr),
+	SYS_add_key                 = 217 (PtrIn, PtrIn, PtrIn, Len, Int),
+	SYS_request_key             = 218 (PtrIn, PtrIn, PtrIn, Int),
+	SYS_keyctl                  = 219 (Flags, Int, Int, Int, Int),
+	SYS_clone                   = 220 (Flags, Addr, PtrOut, Addr, PtrOut),
+	SYS_execve                  = 221 (PtrIn, PtrIn, PtrIn),
+	SYS_mmap                    = 222 (Addr, Len, Flags, Flags, Fd, Int64),
+	SYS_fadvise64               = 223 (Fd, Int64, Len, Int),
+	SYS_swapon                  = 224 (PtrIn, Flags),
+	SYS_swapoff                 = 225 (PtrIn),
+	SYS_mprotect                = 226 (Addr, Len, Flags),
+	SYS_msync                   = 227 (Addr, Len, Flags),
+	SYS_mlock                   = 228 (Addr, Len),
+	SYS_munlock                 = 229 (Addr, Len),
+	SYS_mlockall                = 230 (Flags),
+	SYS_munlockall              = 231 (),
+	SYS_mincore                 = 232 (Addr, Len, PtrOut),
+	SYS_madvise                 = 233 (Addr, Len, Flags),
+	SYS_mbind                   = 235 (Addr, Len, Flags, PtrIn, Len, Flags),
+	SYS_get_mempolicy           = 236 (PtrOut, PtrOut, Len, Addr, Flags),
+	SYS_set_mempolicy           = 237 (Flags, PtrIn, Len),
+	SYS_migrate_pages           = 238 (Int, Len, PtrIn, PtrIn),
+	SYS_move_pages              = 239 (Int, Len, PtrIn, PtrIn, PtrOut, Flags),
+	SYS_rt_tgsigqueueinfo       = 240 (Int, Int, Int, PtrIn),
+	SYS_perf_event_open         = 241 (PtrInOut, Int, Int, Fd, Flags),
+	SYS_accept4                 = 242 (Fd, PtrOut, PtrInOut, Flags),
+	SYS_recvmmsg                = 243 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_wait4                   = 260 (Int, PtrOut, Flags, PtrOut),
+	SYS_prlimit64               = 261 (Int, Int, PtrIn, PtrOut),
+	SYS_fanotify_init           = 262 (Flags, Flags),
+	SYS_fanotify_mark           = 263 (Fd, Flags, Int64, Fd, PtrIn),
+	SYS_name_to_handle_at       = 264 (Fd, PtrIn, PtrInOut, PtrOut, Flags),
+	SYS_open_by_handle_at       = 265 (Fd, PtrIn, Flags),
+	SYS_clock_adjtime           = 266 (Int, PtrInOut),
+	SYS_syncfs                  = 267 (Fd),
+	SYS_setns                   = 268 (Fd, Flags),
+	SYS_sendmmsg                = 269 (Fd, PtrInOut, Len, Flags),
+	SYS_process_vm_readv        = 270 (Int, PtrOut, Len, PtrIn, Len, Flags),
+	SYS_process_vm_writev       = 271 (Int, PtrIn, Len, PtrIn, Len, Flags),
+	SYS_kcmp                    = 272 (Int, Int, Int, Int, Int),
+	SYS_finit_module            = 273 (Fd, PtrIn, Flags),
+	SYS_sched_setattr           = 274 (Int, PtrIn, Flags),
+	SYS_sched_getattr           = 275 (Int, PtrOut, Len, Flags),
+	SYS_renameat2               = 276 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_seccomp                 = 277 (Flags, Flags, PtrInOut),
+	SYS_getrandom               = 278 (PtrOut, Len, Flags),
+	SYS_memfd_create            = 279 (PtrIn, Flags),
+	SYS_bpf                     = 280 (Flags, PtrInOut, Len),
+	SYS_execveat                = 281 (Fd, PtrIn, PtrIn, PtrIn, Flags),
+	SYS_userfaultfd             = 282 (Flags),
+	SYS_membarrier              = 283 (Flags, Flags, Int),
+	SYS_mlock2                  = 284 (Addr, Len, Flags),
+	SYS_copy_file_range         = 285 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_preadv2                 = 286 (Fd, PtrOut, Len, Int64, Int, Flags),
+	SYS_pwritev2                = 287 (Fd, PtrIn, Len, Int64, Int, Flags),
+	SYS_pkey_mprotect           = 288 (Addr, Len, Flags, Int),
+	SYS_pkey_alloc              = 289 (Flags, Flags),
+	SYS_pkey_free               = 290 (Int),
+	SYS_statx                   = 291 (Fd, PtrIn, Flags, Flags, PtrOut),
+	SYS_io_pgetevents           = 292 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_rseq                    = 293 (PtrInOut, Len, Flags, Int),
+	SYS_kexec_file_load         = 294 (Fd, Fd, Len, PtrIn, Flags),
+	SYS_pidfd_send_signal       = 424 (Fd, Int, PtrIn, Flags),
+	SYS_io_uring_setup          = 425 (Len, PtrInOut),
+	SYS_io_uring_enter          = 426 (Fd, Len, Len, Flags, PtrIn, Len),
+	SYS_io_uring_register       = 427 (Fd, Flags, PtrInOut, Len),
+	SYS_open_tree               = 428 (Fd, PtrIn, Flags),
+	SYS_move_mount              = 429 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_fsopen                  = 430 (PtrIn, Flags),
+	SYS_fsconfig                = 431 (Fd, Flags, PtrIn, PtrIn, Int),
+	SYS_fsmount                 = 432 (Fd, Flags, Flags),
+	SYS_fspick                  = 433 (Fd, PtrIn, Flags),
+	SYS_pidfd_open              = 434 (Int, Flags),
+	SYS_clone3                  = 435 (PtrIn, Len),
+	SYS_close_range             = 436 (Fd, Fd, Flags),
+	SYS_openat2                 = 437 (Fd, PtrIn, PtrIn, Len),
+	SYS_pidfd_getfd             = 438 (Fd, Fd, Flags),
+	SYS_faccessat2              = 439 (Fd, PtrIn, Flags, Flags),
+	SYS_process_madvise         = 440 (Fd, PtrIn, Len, Flags, Flags),
+	SYS_epoll_pwait2            = 441 (Fd, PtrOut, Len, PtrIn, PtrIn, Len),
+	SYS_mount_setattr           = 442 (Fd, PtrIn, Flags, PtrIn, Len),
+	SYS_quotactl_fd             = 443 (Fd, Flags, Int, PtrInOut),
+	SYS_landlock_create_ruleset = 444 (PtrIn, Len, Flags),
+	SYS_landlock_add_rule       = 445 (Fd, Int, PtrIn, Flags),
+	SYS_landlock_restrict_self  = 446 (Fd, Flags),
+	SYS_memfd_secret            = 447 (Flags),
+	SYS_process_mrelease        = 448 (Fd, Flags),
+	SYS_futex_waitv             = 449 (PtrIn, Len, Flags, PtrIn, Int),
+	SYS_set_mempolicy_home_node = 450 (Addr, Len, Int, Flags),
 }
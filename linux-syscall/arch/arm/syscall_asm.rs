@@ -37,98 +37,241 @@ impl Result {
 
 single_register_result32!(Result);
 
+/// Flushes the instruction cache for a range of memory.
+///
+/// Code written to memory at runtime, for example by a JIT compiler, must be
+/// flushed from the data cache before it can be executed.
+#[inline]
+pub fn cacheflush(
+	addr: *const u8,
+	len: usize,
+) -> core::result::Result<(), Error> {
+	let start = addr as usize;
+	let end = start.saturating_add(len);
+	let rc = unsafe { crate::syscall!(super::SYS_cacheflush, start, end, 0) };
+	crate::Result::check(&rc)
+}
+
+/// Sets the thread-local storage pointer of the current thread.
+///
+/// # Safety
+///
+/// The C library and the Rust standard library use the thread-local storage
+/// pointer to locate thread-local variables. Changing it in a thread that
+/// uses either may cause undefined behavior.
+#[inline]
+pub unsafe fn set_tls(tls: *mut ()) -> core::result::Result<(), Error> {
+	let rc = crate::syscall!(super::SYS_set_tls, tls);
+	crate::Result::check(&rc)
+}
+
+// In Thumb mode r7 is the frame pointer and can't be used as an `asm!` operand,
+// so the syscall number is moved into r7 for the duration of the `svc`. The
+// `thumb-mode` target feature isn't visible to `cfg` on stable Rust, so this
+// is done in ARM mode too.
 #[cfg(not(doc))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! syscall {
-	($nr:expr $(,)?) => {{
+macro_rules! __syscall_with_options {
+	([$($opt:ident),*] $nr:expr $(,)?) => {{
 		let mut out_r0;
 		core::arch::asm!(
+			"mov {saved_r7}, r7",
+			"mov r7, {nr}",
 			"svc #0",
-			in("r7") u32::from(Into::<$crate::Syscall>::into($nr)),
+			"mov r7, {saved_r7}",
+			nr = in(reg) u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			saved_r7 = out(reg) _,
 			lateout("r0") out_r0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::arm::Result::new(out_r0)
 	}};
-	($nr:expr, $a1:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr $(,)?) => {{
 		let mut out_r0;
 		core::arch::asm!(
+			"mov {saved_r7}, r7",
+			"mov r7, {nr}",
 			"svc #0",
-			in("r7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("r0") $a1,
+			"mov r7, {saved_r7}",
+			nr = in(reg) u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			saved_r7 = out(reg) _,
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
 			lateout("r0") out_r0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::arm::Result::new(out_r0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr $(,)?) => {{
 		let mut out_r0;
 		core::arch::asm!(
+			"mov {saved_r7}, r7",
+			"mov r7, {nr}",
 			"svc #0",
-			in("r7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("r0") $a1,
-			in("r1") $a2,
+			"mov r7, {saved_r7}",
+			nr = in(reg) u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			saved_r7 = out(reg) _,
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r1") $crate::IntoSyscallArg::into_syscall_arg($a2),
 			lateout("r0") out_r0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::arm::Result::new(out_r0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
 		let mut out_r0;
 		core::arch::asm!(
+			"mov {saved_r7}, r7",
+			"mov r7, {nr}",
 			"svc #0",
-			in("r7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("r0") $a1,
-			in("r1") $a2,
-			in("r2") $a3,
+			"mov r7, {saved_r7}",
+			nr = in(reg) u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			saved_r7 = out(reg) _,
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r2") $crate::IntoSyscallArg::into_syscall_arg($a3),
 			lateout("r0") out_r0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::arm::Result::new(out_r0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
 		let mut out_r0;
 		core::arch::asm!(
+			"mov {saved_r7}, r7",
+			"mov r7, {nr}",
 			"svc #0",
-			in("r7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("r0") $a1,
-			in("r1") $a2,
-			in("r2") $a3,
-			in("r3") $a4,
+			"mov r7, {saved_r7}",
+			nr = in(reg) u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			saved_r7 = out(reg) _,
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a4),
 			lateout("r0") out_r0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::arm::Result::new(out_r0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
 		let mut out_r0;
 		core::arch::asm!(
+			"mov {saved_r7}, r7",
+			"mov r7, {nr}",
 			"svc #0",
-			in("r7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("r0") $a1,
-			in("r1") $a2,
-			in("r2") $a3,
-			in("r3") $a4,
-			in("r4") $a5,
+			"mov r7, {saved_r7}",
+			nr = in(reg) u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			saved_r7 = out(reg) _,
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a5),
 			lateout("r0") out_r0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::arm::Result::new(out_r0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
 		let mut out_r0;
 		core::arch::asm!(
+			"mov {saved_r7}, r7",
+			"mov r7, {nr}",
 			"svc #0",
-			in("r7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("r0") $a1,
-			in("r1") $a2,
-			in("r2") $a3,
-			in("r3") $a4,
-			in("r4") $a5,
-			in("r5") $a6,
+			"mov r7, {saved_r7}",
+			nr = in(reg) u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			saved_r7 = out(reg) _,
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("r5") $crate::IntoSyscallArg::into_syscall_arg($a6),
 			lateout("r0") out_r0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::arm::Result::new(out_r0)
 	}};
 }
+
+#[cfg(not(doc))]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($nr:expr $(,)?) => {{
+		core::arch::asm!(
+			"mov r7, {nr}",
+			"svc #0",
+			nr = in(reg) u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		core::arch::asm!(
+			"mov r7, {nr}",
+			"svc #0",
+			nr = in(reg) u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		core::arch::asm!(
+			"mov r7, {nr}",
+			"svc #0",
+			nr = in(reg) u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		core::arch::asm!(
+			"mov r7, {nr}",
+			"svc #0",
+			nr = in(reg) u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		core::arch::asm!(
+			"mov r7, {nr}",
+			"svc #0",
+			nr = in(reg) u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		core::arch::asm!(
+			"mov r7, {nr}",
+			"svc #0",
+			nr = in(reg) u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		core::arch::asm!(
+			"mov r7, {nr}",
+			"svc #0",
+			nr = in(reg) u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("r0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("r5") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			options(noreturn, nostack),
+		)
+	}};
+}
@@ -16,411 +16,417 @@
 #![allow(non_upper_case_globals)]
 
 syscall_constants! {
-	SYS_exit                         = 1,
-	SYS_fork                         = 2,
-	SYS_read                         = 3,
-	SYS_write                        = 4,
-	SYS_open                         = 5,
-	SYS_close                        = 6,
-	SYS_creat                        = 8,
-	SYS_link                         = 9,
-	SYS_unlink                       = 10,
-	SYS_execve                       = 11,
-	SYS_chdir                        = 12,
-	SYS_time                         = 13,
-	SYS_mknod                        = 14,
-	SYS_chmod                        = 15,
-	SYS_lchown                       = 16,
-	SYS_lseek                        = 19,
-	SYS_getpid                       = 20,
-	SYS_mount                        = 21,
-	SYS_umount                       = 22,
-	SYS_setuid                       = 23,
-	SYS_getuid                       = 24,
-	SYS_stime                        = 25,
-	SYS_ptrace                       = 26,
-	SYS_alarm                        = 27,
-	SYS_pause                        = 29,
-	SYS_utime                        = 30,
-	SYS_access                       = 33,
-	SYS_nice                         = 34,
-	SYS_sync                         = 36,
-	SYS_kill                         = 37,
-	SYS_rename                       = 38,
-	SYS_mkdir                        = 39,
-	SYS_rmdir                        = 40,
-	SYS_dup                          = 41,
-	SYS_pipe                         = 42,
-	SYS_times                        = 43,
-	SYS_brk                          = 45,
-	SYS_setgid                       = 46,
-	SYS_getgid                       = 47,
-	SYS_geteuid                      = 49,
-	SYS_getegid                      = 50,
-	SYS_acct                         = 51,
-	SYS_umount2                      = 52,
-	SYS_ioctl                        = 54,
-	SYS_fcntl                        = 55,
-	SYS_setpgid                      = 57,
-	SYS_umask                        = 60,
-	SYS_chroot                       = 61,
-	SYS_ustat                        = 62,
-	SYS_dup2                         = 63,
-	SYS_getppid                      = 64,
-	SYS_getpgrp                      = 65,
-	SYS_setsid                       = 66,
-	SYS_sigaction                    = 67,
-	SYS_setreuid                     = 70,
-	SYS_setregid                     = 71,
-	SYS_sigsuspend                   = 72,
-	SYS_sigpending                   = 73,
-	SYS_sethostname                  = 74,
-	SYS_setrlimit                    = 75,
-	SYS_getrlimit                    = 76,
-	SYS_getrusage                    = 77,
-	SYS_gettimeofday                 = 78,
-	SYS_settimeofday                 = 79,
-	SYS_getgroups                    = 80,
-	SYS_setgroups                    = 81,
-	SYS_select                       = 82,
-	SYS_symlink                      = 83,
-	SYS_readlink                     = 85,
-	SYS_swapon                       = 87,
-	SYS_reboot                       = 88,
-	SYS_readdir                      = 89,
-	SYS_mmap                         = 90,
-	SYS_munmap                       = 91,
-	SYS_truncate                     = 92,
-	SYS_ftruncate                    = 93,
-	SYS_fchmod                       = 94,
-	SYS_fchown                       = 95,
-	SYS_getpriority                  = 96,
-	SYS_setpriority                  = 97,
-	SYS_statfs                       = 99,
-	SYS_fstatfs                      = 100,
-	SYS_socketcall                   = 102,
-	SYS_syslog                       = 103,
-	SYS_setitimer                    = 104,
-	SYS_getitimer                    = 105,
-	SYS_stat                         = 106,
-	SYS_lstat                        = 107,
-	SYS_fstat                        = 108,
-	SYS_vhangup                      = 111,
-	SYS_syscall                      = 113,
-	SYS_wait4                        = 114,
-	SYS_swapoff                      = 115,
-	SYS_sysinfo                      = 116,
-	SYS_ipc                          = 117,
-	SYS_fsync                        = 118,
-	SYS_sigreturn                    = 119,
-	SYS_clone                        = 120,
-	SYS_setdomainname                = 121,
-	SYS_uname                        = 122,
-	SYS_adjtimex                     = 124,
-	SYS_mprotect                     = 125,
-	SYS_sigprocmask                  = 126,
-	SYS_init_module                  = 128,
-	SYS_delete_module                = 129,
-	SYS_quotactl                     = 131,
-	SYS_getpgid                      = 132,
-	SYS_fchdir                       = 133,
-	SYS_personality                  = 136,
-	SYS_setfsuid                     = 138,
-	SYS_setfsgid                     = 139,
-	SYS__llseek                      = 140,
-	SYS_getdents                     = 141,
-	SYS__newselect                   = 142,
-	SYS_flock                        = 143,
-	SYS_msync                        = 144,
-	SYS_readv                        = 145,
-	SYS_writev                       = 146,
-	SYS_getsid                       = 147,
-	SYS_fdatasync                    = 148,
-	SYS_mlock                        = 150,
-	SYS_munlock                      = 151,
-	SYS_mlockall                     = 152,
-	SYS_munlockall                   = 153,
-	SYS_sched_setparam               = 154,
-	SYS_sched_getparam               = 155,
-	SYS_sched_setscheduler           = 156,
-	SYS_sched_getscheduler           = 157,
-	SYS_sched_yield                  = 158,
-	SYS_sched_get_priority_max       = 159,
-	SYS_sched_get_priority_min       = 160,
-	SYS_sched_rr_get_interval        = 161,
-	SYS_nanosleep                    = 162,
-	SYS_mremap                       = 163,
-	SYS_setresuid                    = 164,
-	SYS_getresuid                    = 165,
-	SYS_poll                         = 168,
-	SYS_setresgid                    = 170,
-	SYS_getresgid                    = 171,
-	SYS_prctl                        = 172,
-	SYS_rt_sigreturn                 = 173,
-	SYS_rt_sigaction                 = 174,
-	SYS_rt_sigprocmask               = 175,
-	SYS_rt_sigpending                = 176,
-	SYS_rt_sigtimedwait              = 177,
-	SYS_rt_sigqueueinfo              = 178,
-	SYS_rt_sigsuspend                = 179,
-	SYS_pread64                      = 180,
-	SYS_pwrite64                     = 181,
-	SYS_chown                        = 182,
-	SYS_getcwd                       = 183,
-	SYS_capget                       = 184,
-	SYS_capset                       = 185,
-	SYS_sigaltstack                  = 186,
-	SYS_sendfile                     = 187,
-	SYS_vfork                        = 190,
-	SYS_ugetrlimit                   = 191,
-	SYS_mmap2                        = 192,
-	SYS_truncate64                   = 193,
-	SYS_ftruncate64                  = 194,
-	SYS_stat64                       = 195,
-	SYS_lstat64                      = 196,
-	SYS_fstat64                      = 197,
-	SYS_lchown32                     = 198,
-	SYS_getuid32                     = 199,
-	SYS_getgid32                     = 200,
-	SYS_geteuid32                    = 201,
-	SYS_getegid32                    = 202,
-	SYS_setreuid32                   = 203,
-	SYS_setregid32                   = 204,
-	SYS_getgroups32                  = 205,
-	SYS_setgroups32                  = 206,
-	SYS_fchown32                     = 207,
-	SYS_setresuid32                  = 208,
-	SYS_getresuid32                  = 209,
-	SYS_setresgid32                  = 210,
-	SYS_getresgid32                  = 211,
-	SYS_chown32                      = 212,
-	SYS_setuid32                     = 213,
-	SYS_setgid32                     = 214,
-	SYS_setfsuid32                   = 215,
-	SYS_setfsgid32                   = 216,
-	SYS_getdents64                   = 217,
-	SYS_pivot_root                   = 218,
-	SYS_mincore                      = 219,
-	SYS_madvise                      = 220,
-	SYS_fcntl64                      = 221,
-	SYS_gettid                       = 224,
-	SYS_readahead                    = 225,
-	SYS_setxattr                     = 226,
-	SYS_lsetxattr                    = 227,
-	SYS_fsetxattr                    = 228,
-	SYS_getxattr                     = 229,
-	SYS_lgetxattr                    = 230,
-	SYS_fgetxattr                    = 231,
-	SYS_listxattr                    = 232,
-	SYS_llistxattr                   = 233,
-	SYS_flistxattr                   = 234,
-	SYS_removexattr                  = 235,
-	SYS_lremovexattr                 = 236,
-	SYS_fremovexattr                 = 237,
-	SYS_tkill                        = 238,
-	SYS_sendfile64                   = 239,
-	SYS_futex                        = 240,
-	SYS_sched_setaffinity            = 241,
-	SYS_sched_getaffinity            = 242,
-	SYS_io_setup                     = 243,
-	SYS_io_destroy                   = 244,
-	SYS_io_getevents                 = 245,
-	SYS_io_submit                    = 246,
-	SYS_io_cancel                    = 247,
-	SYS_exit_group                   = 248,
-	SYS_lookup_dcookie               = 249,
-	SYS_epoll_create                 = 250,
-	SYS_epoll_ctl                    = 251,
-	SYS_epoll_wait                   = 252,
-	SYS_set_tid_address              = 256,
-	SYS_timer_create                 = 257,
-	SYS_timer_settime                = 258,
-	SYS_timer_gettime                = 259,
-	SYS_timer_getoverrun             = 260,
-	SYS_timer_delete                 = 261,
-	SYS_clock_settime                = 262,
-	SYS_clock_gettime                = 263,
-	SYS_clock_getres                 = 264,
-	SYS_clock_nanosleep              = 265,
-	SYS_statfs64                     = 266,
-	SYS_fstatfs64                    = 267,
-	SYS_tgkill                       = 268,
-	SYS_utimes                       = 269,
-	SYS_arm_fadvise64_64             = 270,
-	SYS_pciconfig_iobase             = 271,
-	SYS_pciconfig_read               = 272,
-	SYS_pciconfig_write              = 273,
-	SYS_mq_open                      = 274,
-	SYS_mq_unlink                    = 275,
-	SYS_mq_timedsend                 = 276,
-	SYS_mq_timedreceive              = 277,
-	SYS_mq_notify                    = 278,
-	SYS_mq_getsetattr                = 279,
-	SYS_waitid                       = 280,
-	SYS_socket                       = 281,
-	SYS_bind                         = 282,
-	SYS_connect                      = 283,
-	SYS_listen                       = 284,
-	SYS_accept                       = 285,
-	SYS_getsockname                  = 286,
-	SYS_getpeername                  = 287,
-	SYS_socketpair                   = 288,
-	SYS_send                         = 289,
-	SYS_sendto                       = 290,
-	SYS_recv                         = 291,
-	SYS_recvfrom                     = 292,
-	SYS_shutdown                     = 293,
-	SYS_setsockopt                   = 294,
-	SYS_getsockopt                   = 295,
-	SYS_sendmsg                      = 296,
-	SYS_recvmsg                      = 297,
-	SYS_semop                        = 298,
-	SYS_semget                       = 299,
-	SYS_semctl                       = 300,
-	SYS_msgsnd                       = 301,
-	SYS_msgrcv                       = 302,
-	SYS_msgget                       = 303,
-	SYS_msgctl                       = 304,
-	SYS_shmat                        = 305,
-	SYS_shmdt                        = 306,
-	SYS_shmget                       = 307,
-	SYS_shmctl                       = 308,
-	SYS_add_key                      = 309,
-	SYS_request_key                  = 310,
-	SYS_keyctl                       = 311,
-	SYS_semtimedop                   = 312,
-	SYS_ioprio_set                   = 314,
-	SYS_ioprio_get                   = 315,
-	SYS_inotify_init                 = 316,
-	SYS_inotify_add_watch            = 317,
-	SYS_inotify_rm_watch             = 318,
-	SYS_mbind                        = 319,
-	SYS_get_mempolicy                = 320,
-	SYS_set_mempolicy                = 321,
-	SYS_openat                       = 322,
-	SYS_mkdirat                      = 323,
-	SYS_mknodat                      = 324,
-	SYS_fchownat                     = 325,
-	SYS_futimesat                    = 326,
-	SYS_fstatat64                    = 327,
-	SYS_unlinkat                     = 328,
-	SYS_renameat                     = 329,
-	SYS_linkat                       = 330,
-	SYS_symlinkat                    = 331,
-	SYS_readlinkat                   = 332,
-	SYS_fchmodat                     = 333,
-	SYS_faccessat                    = 334,
-	SYS_pselect6                     = 335,
-	SYS_ppoll                        = 336,
-	SYS_unshare                      = 337,
-	SYS_set_robust_list              = 338,
-	SYS_get_robust_list              = 339,
-	SYS_splice                       = 340,
-	SYS_arm_sync_file_range          = 341,
-	SYS_tee                          = 342,
-	SYS_vmsplice                     = 343,
-	SYS_move_pages                   = 344,
-	SYS_getcpu                       = 345,
-	SYS_epoll_pwait                  = 346,
-	SYS_kexec_load                   = 347,
-	SYS_utimensat                    = 348,
-	SYS_signalfd                     = 349,
-	SYS_timerfd_create               = 350,
-	SYS_eventfd                      = 351,
-	SYS_fallocate                    = 352,
-	SYS_timerfd_settime              = 353,
-	SYS_timerfd_gettime              = 354,
-	SYS_signalfd4                    = 355,
-	SYS_eventfd2                     = 356,
-	SYS_epoll_create1                = 357,
-	SYS_dup3                         = 358,
-	SYS_pipe2                        = 359,
-	SYS_inotify_init1                = 360,
-	SYS_preadv                       = 361,
-	SYS_pwritev                      = 362,
-	SYS_rt_tgsigqueueinfo            = 363,
-	SYS_perf_event_open              = 364,
-	SYS_recvmmsg                     = 365,
-	SYS_accept4                      = 366,
-	SYS_fanotify_init                = 367,
-	SYS_fanotify_mark                = 368,
-	SYS_prlimit64                    = 369,
-	SYS_name_to_handle_at            = 370,
-	SYS_open_by_handle_at            = 371,
-	SYS_clock_adjtime                = 372,
-	SYS_syncfs                       = 373,
-	SYS_sendmmsg                     = 374,
-	SYS_setns                        = 375,
-	SYS_process_vm_readv             = 376,
-	SYS_process_vm_writev            = 377,
-	SYS_kcmp                         = 378,
-	SYS_finit_module                 = 379,
-	SYS_sched_setattr                = 380,
-	SYS_sched_getattr                = 381,
-	SYS_renameat2                    = 382,
-	SYS_seccomp                      = 383,
-	SYS_getrandom                    = 384,
-	SYS_memfd_create                 = 385,
-	SYS_bpf                          = 386,
-	SYS_execveat                     = 387,
-	SYS_userfaultfd                  = 388,
-	SYS_membarrier                   = 389,
-	SYS_mlock2                       = 390,
-	SYS_copy_file_range              = 391,
-	SYS_preadv2                      = 392,
-	SYS_pwritev2                     = 393,
-	SYS_pkey_mprotect                = 394,
-	SYS_pkey_alloc                   = 395,
-	SYS_pkey_free                    = 396,
-	SYS_statx                        = 397,
-	SYS_rseq                         = 398,
-	SYS_io_pgetevents                = 399,
-	SYS_migrate_pages                = 400,
-	SYS_kexec_file_load              = 401,
-	SYS_clock_gettime64              = 403,
-	SYS_clock_settime64              = 404,
-	SYS_clock_adjtime64              = 405,
-	SYS_clock_getres_time64          = 406,
-	SYS_clock_nanosleep_time64       = 407,
-	SYS_timer_gettime64              = 408,
-	SYS_timer_settime64              = 409,
-	SYS_timerfd_gettime64            = 410,
-	SYS_timerfd_settime64            = 411,
-	SYS_utimensat_time64             = 412,
-	SYS_pselect6_time64              = 413,
-	SYS_ppoll_time64                 = 414,
-	SYS_io_pgetevents_time64         = 416,
-	SYS_recvmmsg_time64              = 417,
-	SYS_mq_timedsend_time64          = 418,
-	SYS_mq_timedreceive_time64       = 419,
-	SYS_semtimedop_time64            = 420,
-	SYS_rt_sigtimedwait_time64       = 421,
-	SYS_futex_time64                 = 422,
-	SYS_sched_rr_get_interval_time64 = 423,
-	SYS_pidfd_send_signal            = 424,
-	SYS_io_uring_setup               = 425,
-	SYS_io_uring_enter               = 426,
-	SYS_io_uring_register            = 427,
-	SYS_open_tree                    = 428,
-	SYS_move_mount                   = 429,
-	SYS_fsopen                       = 430,
-	SYS_fsconfig                     = 431,
-	SYS_fsmount                      = 432,
-	SYS_fspick                       = 433,
-	SYS_pidfd_open                   = 434,
-	SYS_clone3                       = 435,
-	SYS_close_range                  = 436,
-	SYS_openat2                      = 437,
-	SYS_pidfd_getfd                  = 438,
-	SYS_faccessat2                   = 439,
-	SYS_process_madvise              = 440,
-	SYS_epoll_pwait2                 = 441,
-	SYS_mount_setattr                = 442,
-	SYS_quotactl_fd                  = 443,
-	SYS_landlock_create_ruleset      = 444,
-	SYS_landlock_add_rule            = 445,
-	SYS_landlock_restrict_self       = 446,
-	SYS_process_mrelease             = 448,
-	SYS_futex_waitv                  = 449,
-	SYS_set_mempolicy_home_node      = 450,
+	SYS_exit                         = 1   (Int),
+	SYS_fork                         = 2   (),
+	SYS_read                         = 3   (Fd, PtrOut, Len),
+	SYS_write                        = 4   (Fd, PtrIn, Len),
+	SYS_open                         = 5   (PtrIn, Flags, Flags),
+	SYS_close                        = 6   (Fd),
+	SYS_creat                        = 8   (PtrIn, Flags),
+	SYS_link                         = 9   (PtrIn, PtrIn),
+	SYS_unlink                       = 10  (PtrIn),
+	SYS_execve                       = 11  (PtrIn, PtrIn, PtrIn),
+	SYS_chdir                        = 12  (PtrIn),
+	SYS_time                         = 13  (PtrOut),
+	SYS_mknod                        = 14  (PtrIn, Flags, Int),
+	SYS_chmod                        = 15  (PtrIn, Flags),
+	SYS_lchown                       = 16  (PtrIn, Int, Int),
+	SYS_lseek                        = 19  (Fd, Int, Int),
+	SYS_getpid                       = 20  (),
+	SYS_mount                        = 21  (PtrIn, PtrIn, PtrIn, Flags, PtrIn),
+	SYS_umount                       = 22  (PtrIn),
+	SYS_setuid                       = 23  (Int),
+	SYS_getuid                       = 24  (),
+	SYS_stime                        = 25  (PtrIn),
+	SYS_ptrace                       = 26  (Flags, Int, Addr, Int),
+	SYS_alarm                        = 27  (Int),
+	SYS_pause                        = 29  (),
+	SYS_utime                        = 30  (PtrIn, PtrIn),
+	SYS_access                       = 33  (PtrIn, Flags),
+	SYS_nice                         = 34  (Int),
+	SYS_sync                         = 36  (),
+	SYS_kill                         = 37  (Int, Int),
+	SYS_rename                       = 38  (PtrIn, PtrIn),
+	SYS_mkdir                        = 39  (PtrIn, Flags),
+	SYS_rmdir                        = 40  (PtrIn),
+	SYS_dup                          = 41  (Fd),
+	SYS_pipe                         = 42  (PtrOut),
+	SYS_times                        = 43  (PtrOut),
+	SYS_brk                          = 45  (Addr),
+	SYS_setgid                       = 46  (Int),
+	SYS_getgid                       = 47  (),
+	SYS_geteuid                      = 49  (),
+	SYS_getegid                      = 50  (),
+	SYS_acct                         = 51  (PtrIn),
+	SYS_umount2                      = 52  (PtrIn, Flags),
+	SYS_ioctl                        = 54  (Fd, Flags, Int),
+	SYS_fcntl                        = 55  (Fd, Flags, Int),
+	SYS_setpgid                      = 57  (Int, Int),
+	SYS_umask                        = 60  (Flags),
+	SYS_chroot                       = 61  (PtrIn),
+	SYS_ustat                        = 62  (Int, PtrOut),
+	SYS_dup2                         = 63  (Fd, Fd),
+	SYS_getppid                      = 64  (),
+	SYS_getpgrp                      = 65  (),
+	SYS_setsid                       = 66  (),
+	SYS_sigaction                    = 67  (Int, PtrIn, PtrOut),
+	SYS_setreuid                     = 70  (Int, Int),
+	SYS_setregid                     = 71  (Int, Int),
+	SYS_sigsuspend                   = 72  (Int, Int, Flags),
+	SYS_sigpending                   = 73  (PtrOut),
+	SYS_sethostname                  = 74  (PtrIn, Len),
+	SYS_setrlimit                    = 75  (Int, PtrIn),
+	SYS_getrlimit                    = 76  (Int, PtrOut),
+	SYS_getrusage                    = 77  (Int, PtrOut),
+	SYS_gettimeofday                 = 78  (PtrOut, PtrOut),
+	SYS_settimeofday                 = 79  (PtrIn, PtrIn),
+	SYS_getgroups                    = 80  (Len, PtrOut),
+	SYS_setgroups                    = 81  (Len, PtrIn),
+	SYS_select                       = 82  (PtrIn),
+	SYS_symlink                      = 83  (PtrIn, PtrIn),
+	SYS_readlink                     = 85  (PtrIn, PtrOut, Len),
+	SYS_swapon                       = 87  (PtrIn, Flags),
+	SYS_reboot                       = 88  (Int, Int, Flags, PtrIn),
+	SYS_readdir                      = 89  (Fd, PtrOut, Int),
+	SYS_mmap                         = 90  (PtrIn),
+	SYS_munmap                       = 91  (Addr, Len),
+	SYS_truncate                     = 92  (PtrIn, Int),
+	SYS_ftruncate                    = 93  (Fd, Int),
+	SYS_fchmod                       = 94  (Fd, Flags),
+	SYS_fchown                       = 95  (Fd, Int, Int),
+	SYS_getpriority                  = 96  (Int, Int),
+	SYS_setpriority                  = 97  (Int, Int, Int),
+	SYS_statfs                       = 99  (PtrIn, PtrOut),
+	SYS_fstatfs                      = 100 (Fd, PtrOut),
+	SYS_socketcall                   = 102 (Flags, PtrIn),
+	SYS_syslog                       = 103 (Flags, PtrOut, Len),
+	SYS_setitimer                    = 104 (Int, PtrIn, PtrOut),
+	SYS_getitimer                    = 105 (Int, PtrOut),
+	SYS_stat                         = 106 (PtrIn, PtrOut),
+	SYS_lstat                        = 107 (PtrIn, PtrOut),
+	SYS_fstat                        = 108 (Fd, PtrOut),
+	SYS_vhangup                      = 111 (),
+	SYS_syscall                      = 113 (Int, Int, Int, Int, Int, Int),
+	SYS_wait4                        = 114 (Int, PtrOut, Flags, PtrOut),
+	SYS_swapoff                      = 115 (PtrIn),
+	SYS_sysinfo                      = 116 (PtrOut),
+	SYS_ipc                          = 117 (Flags, Int, Int, Int, PtrInOut, Int),
+	SYS_fsync                        = 118 (Fd),
+	SYS_sigreturn                    = 119 (),
+	SYS_clone                        = 120 (Flags, Addr, PtrOut, Addr, PtrOut),
+	SYS_setdomainname                = 121 (PtrIn, Len),
+	SYS_uname                        = 122 (PtrOut),
+	SYS_adjtimex                     = 124 (PtrInOut),
+	SYS_mprotect                     = 125 (Addr, Len, Flags),
+	SYS_sigprocmask                  = 126 (Int, PtrIn, PtrOut),
+	SYS_init_module                  = 128 (PtrIn, Len, PtrIn),
+	SYS_delete_module                = 129 (PtrIn, Flags),
+	SYS_quotactl                     = 131 (Flags, PtrIn, Int, PtrInOut),
+	SYS_getpgid                      = 132 (Int),
+	SYS_fchdir                       = 133 (Fd),
+	SYS_personality                  = 136 (Flags),
+	SYS_setfsuid                     = 138 (Int),
+	SYS_setfsgid                     = 139 (Int),
+	SYS__llseek                      = 140 (Fd, Int64High, Int64Low, PtrOut, Int),
+	SYS_getdents                     = 141 (Fd, PtrOut, Len),
+	SYS__newselect                   = 142 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut),
+	SYS_flock                        = 143 (Fd, Flags),
+	SYS_msync                        = 144 (Addr, Len, Flags),
+	SYS_readv                        = 145 (Fd, PtrOut, Len),
+	SYS_writev                       = 146 (Fd, PtrIn, Len),
+	SYS_getsid                       = 147 (Int),
+	SYS_fdatasync                    = 148 (Fd),
+	SYS_mlock                        = 150 (Addr, Len),
+	SYS_munlock                      = 151 (Addr, Len),
+	SYS_mlockall                     = 152 (Flags),
+	SYS_munlockall                   = 153 (),
+	SYS_sched_setparam               = 154 (Int, PtrIn),
+	SYS_sched_getparam               = 155 (Int, PtrOut),
+	SYS_sched_setscheduler           = 156 (Int, Int, PtrIn),
+	SYS_sched_getscheduler           = 157 (Int),
+	SYS_sched_yield                  = 158 (),
+	SYS_sched_get_priority_max       = 159 (Int),
+	SYS_sched_get_priority_min       = 160 (Int),
+	SYS_sched_rr_get_interval        = 161 (Int, PtrOut),
+	SYS_nanosleep                    = 162 (PtrIn, PtrOut),
+	SYS_mremap                       = 163 (Addr, Len, Len, Flags, Addr),
+	SYS_setresuid                    = 164 (Int, Int, Int),
+	SYS_getresuid                    = 165 (PtrOut, PtrOut, PtrOut),
+	SYS_poll                         = 168 (PtrInOut, Len, Int),
+	SYS_setresgid                    = 170 (Int, Int, Int),
+	SYS_getresgid                    = 171 (PtrOut, PtrOut, PtrOut),
+	SYS_prctl                        = 172 (Flags, Int, Int, Int, Int),
+	SYS_rt_sigreturn                 = 173 (),
+	SYS_rt_sigaction                 = 174 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigprocmask               = 175 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigpending                = 176 (PtrOut, Len),
+	SYS_rt_sigtimedwait              = 177 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_rt_sigqueueinfo              = 178 (Int, Int, PtrIn),
+	SYS_rt_sigsuspend                = 179 (PtrIn, Len),
+	SYS_pread64                      = 180 (Fd, PtrOut, Len, Pad, Int64Low, Int64High),
+	SYS_pwrite64                     = 181 (Fd, PtrIn, Len, Pad, Int64Low, Int64High),
+	SYS_chown                        = 182 (PtrIn, Int, Int),
+	SYS_getcwd                       = 183 (PtrOut, Len),
+	SYS_capget                       = 184 (PtrInOut, PtrOut),
+	SYS_capset                       = 185 (PtrIn, PtrIn),
+	SYS_sigaltstack                  = 186 (PtrIn, PtrOut),
+	SYS_sendfile                     = 187 (Fd, Fd, PtrInOut, Len),
+	SYS_vfork                        = 190 (),
+	SYS_ugetrlimit                   = 191 (Int, PtrOut),
+	SYS_mmap2                        = 192 (Addr, Len, Flags, Flags, Fd, Int),
+	SYS_truncate64                   = 193 (PtrIn, Pad, Int64Low, Int64High),
+	SYS_ftruncate64                  = 194 (Fd, Pad, Int64Low, Int64High),
+	SYS_stat64                       = 195 (PtrIn, PtrOut),
+	SYS_lstat64                      = 196 (PtrIn, PtrOut),
+	SYS_fstat64                      = 197 (Fd, PtrOut),
+	SYS_lchown32                     = 198 (PtrIn, Int, Int),
+	SYS_getuid32                     = 199 (),
+	SYS_getgid32                     = 200 (),
+	SYS_geteuid32                    = 201 (),
+	SYS_getegid32                    = 202 (),
+	SYS_setreuid32                   = 203 (Int, Int),
+	SYS_setregid32                   = 204 (Int, Int),
+	SYS_getgroups32                  = 205 (Len, PtrOut),
+	SYS_setgroups32                  = 206 (Len, PtrIn),
+	SYS_fchown32                     = 207 (Fd, Int, Int),
+	SYS_setresuid32                  = 208 (Int, Int, Int),
+	SYS_getresuid32                  = 209 (PtrOut, PtrOut, PtrOut),
+	SYS_setresgid32                  = 210 (Int, Int, Int),
+	SYS_getresgid32                  = 211 (PtrOut, PtrOut, PtrOut),
+	SYS_chown32                      = 212 (PtrIn, Int, Int),
+	SYS_setuid32                     = 213 (Int),
+	SYS_setgid32                     = 214 (Int),
+	SYS_setfsuid32                   = 215 (Int),
+	SYS_setfsgid32                   = 216 (Int),
+	SYS_getdents64                   = 217 (Fd, PtrOut, Len),
+	SYS_pivot_root                   = 218 (PtrIn, PtrIn),
+	SYS_mincore                      = 219 (Addr, Len, PtrOut),
+	SYS_madvise                      = 220 (Addr, Len, Flags),
+	SYS_fcntl64                      = 221 (Fd, Flags, Int),
+	SYS_gettid                       = 224 (),
+	SYS_readahead                    = 225 (Fd, Pad, Int64Low, Int64High, Len),
+	SYS_setxattr                     = 226 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_lsetxattr                    = 227 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_fsetxattr                    = 228 (Fd, PtrIn, PtrIn, Len, Flags),
+	SYS_getxattr                     = 229 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_lgetxattr                    = 230 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_fgetxattr                    = 231 (Fd, PtrIn, PtrOut, Len),
+	SYS_listxattr                    = 232 (PtrIn, PtrOut, Len),
+	SYS_llistxattr                   = 233 (PtrIn, PtrOut, Len),
+	SYS_flistxattr                   = 234 (Fd, PtrOut, Len),
+	SYS_removexattr                  = 235 (PtrIn, PtrIn),
+	SYS_lremovexattr                 = 236 (PtrIn, PtrIn),
+	SYS_fremovexattr                 = 237 (Fd, PtrIn),
+	SYS_tkill                        = 238 (Int, Int),
+	SYS_sendfile64                   = 239 (Fd, Fd, PtrInOut, Len),
+	SYS_futex                        = 240 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_setaffinity            = 241 (Int, Len, PtrIn),
+	SYS_sched_getaffinity            = 242 (Int, Len, PtrOut),
+	SYS_io_setup                     = 243 (Len, PtrOut),
+	SYS_io_destroy                   = 244 (Int),
+	SYS_io_getevents                 = 245 (Int, Len, Len, PtrOut, PtrIn),
+	SYS_io_submit                    = 246 (Int, Len, PtrIn),
+	SYS_io_cancel                    = 247 (Int, PtrIn, PtrOut),
+	SYS_exit_group                   = 248 (Int),
+	SYS_lookup_dcookie               = 249 (Int64Low, Int64High, PtrOut, Len),
+	SYS_epoll_create                 = 250 (Int),
+	SYS_epoll_ctl                    = 251 (Fd, Flags, Fd, PtrIn),
+	SYS_epoll_wait                   = 252 (Fd, PtrOut, Len, Int),
+	SYS_set_tid_address              = 256 (Addr),
+	SYS_timer_create                 = 257 (Int, PtrIn, PtrOut),
+	SYS_timer_settime                = 258 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime                = 259 (Int, PtrOut),
+	SYS_timer_getoverrun             = 260 (Int),
+	SYS_timer_delete                 = 261 (Int),
+	SYS_clock_settime                = 262 (Int, PtrIn),
+	SYS_clock_gettime                = 263 (Int, PtrOut),
+	SYS_clock_getres                 = 264 (Int, PtrOut),
+	SYS_clock_nanosleep              = 265 (Int, Flags, PtrIn, PtrOut),
+	SYS_statfs64                     = 266 (PtrIn, Len, PtrOut),
+	SYS_fstatfs64                    = 267 (Fd, Len, PtrOut),
+	SYS_tgkill                       = 268 (Int, Int, Int),
+	SYS_utimes                       = 269 (PtrIn, PtrIn),
+	SYS_arm_fadvise64_64             = 270 (Fd, Int, Int64Low, Int64High, Int64Low, Int64High),
+	SYS_pciconfig_iobase             = 271 (Int, Int, Int),
+	SYS_pciconfig_read               = 272 (Int, Int, Int, Len, PtrOut),
+	SYS_pciconfig_write              = 273 (Int, Int, Int, Len, PtrIn),
+	SYS_mq_open                      = 274 (PtrIn, Flags, Flags, PtrIn),
+	SYS_mq_unlink                    = 275 (PtrIn),
+	SYS_mq_timedsend                 = 276 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive              = 277 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_mq_notify                    = 278 (Fd, PtrIn),
+	SYS_mq_getsetattr                = 279 (Fd, PtrIn, PtrOut),
+	SYS_waitid                       = 280 (Int, Int, PtrOut, Flags, PtrOut),
+	SYS_socket                       = 281 (Int, Flags, Int),
+	SYS_bind                         = 282 (Fd, PtrIn, Len),
+	SYS_connect                      = 283 (Fd, PtrIn, Len),
+	SYS_listen                       = 284 (Fd, Int),
+	SYS_accept                       = 285 (Fd, PtrOut, PtrInOut),
+	SYS_getsockname                  = 286 (Fd, PtrOut, PtrInOut),
+	SYS_getpeername                  = 287 (Fd, PtrOut, PtrInOut),
+	SYS_socketpair                   = 288 (Int, Flags, Int, PtrOut),
+	SYS_send                         = 289 (Fd, PtrIn, Len, Flags),
+	SYS_sendto                       = 290 (Fd, PtrIn, Len, Flags, PtrIn, Len),
+	SYS_recv                         = 291 (Fd, PtrOut, Len, Flags),
+	SYS_recvfrom                     = 292 (Fd, PtrOut, Len, Flags, PtrOut, PtrInOut),
+	SYS_shutdown                     = 293 (Fd, Int),
+	SYS_setsockopt                   = 294 (Fd, Int, Int, PtrIn, Len),
+	SYS_getsockopt                   = 295 (Fd, Int, Int, PtrOut, PtrInOut),
+	SYS_sendmsg                      = 296 (Fd, PtrIn, Flags),
+	SYS_recvmsg                      = 297 (Fd, PtrInOut, Flags),
+	SYS_semop                        = 298 (Int, PtrIn, Len),
+	SYS_semget                       = 299 (Int, Len, Flags),
+	SYS_semctl                       = 300 (Int, Int, Flags, Int),
+	SYS_msgsnd                       = 301 (Int, PtrIn, Len, Flags),
+	SYS_msgrcv                       = 302 (Int, PtrOut, Len, Int, Flags),
+	SYS_msgget                       = 303 (Int, Flags),
+	SYS_msgctl                       = 304 (Int, Flags, PtrInOut),
+	SYS_shmat                        = 305 (Int, Addr, Flags),
+	SYS_shmdt                        = 306 (Addr),
+	SYS_shmget                       = 307 (Int, Len, Flags),
+	SYS_shmctl                       = 308 (Int, Flags, PtrInOut),
+	SYS_add_key                      = 309 (PtrIn, PtrIn, PtrIn, Len, Int),
+	SYS_request_key                  = 310 (PtrIn, PtrIn, PtrIn, Int),
+	SYS_keyctl                       = 311 (Flags, Int, Int, Int, Int),
+	SYS_semtimedop                   = 312 (Int, PtrIn, Len, PtrIn),
+	SYS_ioprio_set                   = 314 (Int, Int, Int),
+	SYS_ioprio_get                   = 315 (Int, Int),
+	SYS_inotify_init                 = 316 (),
+	SYS_inotify_add_watch            = 317 (Fd, PtrIn, Flags),
+	SYS_inotify_rm_watch             = 318 (Fd, Int),
+	SYS_mbind                        = 319 (Addr, Len, Flags, PtrIn, Len, Flags),
+	SYS_get_mempolicy                = 320 (PtrOut, PtrOut, Len, Addr, Flags),
+	SYS_set_mempolicy                = 321 (Flags, PtrIn, Len),
+	SYS_openat                       = 322 (Fd, PtrIn, Flags, Flags),
+	SYS_mkdirat                      = 323 (Fd, PtrIn, Flags),
+	SYS_mknodat                      = 324 (Fd, PtrIn, Flags, Int),
+	SYS_fchownat                     = 325 (Fd, PtrIn, Int, Int, Flags),
+	SYS_futimesat                    = 326 (Fd, PtrIn, PtrIn),
+	SYS_fstatat64                    = 327 (Fd, PtrIn, PtrOut, Flags),
+	SYS_unlinkat                     = 328 (Fd, PtrIn, Flags),
+	SYS_renameat                     = 329 (Fd, PtrIn, Fd, PtrIn),
+	SYS_linkat                       = 330 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_symlinkat                    = 331 (PtrIn, Fd, PtrIn),
+	SYS_readlinkat                   = 332 (Fd, PtrIn, PtrOut, Len),
+	SYS_fchmodat                     = 333 (Fd, PtrIn, Flags),
+	SYS_faccessat                    = 334 (Fd, PtrIn, Flags),
+	SYS_pselect6                     = 335 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll                        = 336 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_unshare                      = 337 (Flags),
+	SYS_set_robust_list              = 338 (Addr, Len),
+	SYS_get_robust_list              = 339 (Int, PtrOut, PtrOut),
+	SYS_splice                       = 340 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_arm_sync_file_range          = 341 (Fd, Flags, Int64Low, Int64High, Int64Low, Int64High),
+	SYS_tee                          = 342 (Fd, Fd, Len, Flags),
+	SYS_vmsplice                     = 343 (Fd, PtrIn, Len, Flags),
+	SYS_move_pages                   = 344 (Int, Len, PtrIn, PtrIn, PtrOut, Flags),
+	SYS_getcpu                       = 345 (PtrOut, PtrOut, PtrOut),
+	SYS_epoll_pwait                  = 346 (Fd, PtrOut, Len, Int, PtrIn, Len),
+	SYS_kexec_load                   = 347 (Addr, Len, PtrIn, Flags),
+	SYS_utimensat                    = 348 (Fd, PtrIn, PtrIn, Flags),
+	SYS_signalfd                     = 349 (Fd, PtrIn, Len),
+	SYS_timerfd_create               = 350 (Int, Flags),
+	SYS_eventfd                      = 351 (Int),
+	SYS_fallocate                    = 352 (Fd, Flags, Int64Low, Int64High, Int64Low, Int64High),
+	SYS_timerfd_settime              = 353 (Fd, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime              = 354 (Fd, PtrOut),
+	SYS_signalfd4                    = 355 (Fd, PtrIn, Len, Flags),
+	SYS_eventfd2                     = 356 (Int, Flags),
+	SYS_epoll_create1                = 357 (Flags),
+	SYS_dup3                         = 358 (Fd, Fd, Flags),
+	SYS_pipe2                        = 359 (PtrOut, Flags),
+	SYS_inotify_init1                = 360 (Flags),
+	SYS_preadv                       = 361 (Fd, PtrOut, Len, Int64Low, Int64High),
+	SYS_pwritev                      = 362 (Fd, PtrIn, Len, Int64Low, Int64High),
+	SYS_rt_tgsigqueueinfo            = 363 (Int, Int, Int, PtrIn),
+	SYS_perf_event_open              = 364 (PtrInOut, Int, Int, Fd, Flags),
+	SYS_recvmmsg                     = 365 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_accept4                      = 366 (Fd, PtrOut, PtrInOut, Flags),
+	SYS_fanotify_init                = 367 (Flags, Flags),
+	SYS_fanotify_mark                = 368 (Fd, Flags, Int64Low, Int64High, Fd, PtrIn),
+	SYS_prlimit64                    = 369 (Int, Int, PtrIn, PtrOut),
+	SYS_name_to_handle_at            = 370 (Fd, PtrIn, PtrInOut, PtrOut, Flags),
+	SYS_open_by_handle_at            = 371 (Fd, PtrIn, Flags),
+	SYS_clock_adjtime                = 372 (Int, PtrInOut),
+	SYS_syncfs                       = 373 (Fd),
+	SYS_sendmmsg                     = 374 (Fd, PtrInOut, Len, Flags),
+	SYS_setns                        = 375 (Fd, Flags),
+	SYS_process_vm_readv             = 376 (Int, PtrOut, Len, PtrIn, Len, Flags),
+	SYS_process_vm_writev            = 377 (Int, PtrIn, Len, PtrIn, Len, Flags),
+	SYS_kcmp                         = 378 (Int, Int, Int, Int, Int),
+	SYS_finit_module                 = 379 (Fd, PtrIn, Flags),
+	SYS_sched_setattr                = 380 (Int, PtrIn, Flags),
+	SYS_sched_getattr                = 381 (Int, PtrOut, Len, Flags),
+	SYS_renameat2                    = 382 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_seccomp                      = 383 (Flags, Flags, PtrInOut),
+	SYS_getrandom                    = 384 (PtrOut, Len, Flags),
+	SYS_memfd_create                 = 385 (PtrIn, Flags),
+	SYS_bpf                          = 386 (Flags, PtrInOut, Len),
+	SYS_execveat                     = 387 (Fd, PtrIn, PtrIn, PtrIn, Flags),
+	SYS_userfaultfd                  = 388 (Flags),
+	SYS_membarrier                   = 389 (Flags, Flags, Int),
+	SYS_mlock2                       = 390 (Addr, Len, Flags),
+	SYS_copy_file_range              = 391 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_preadv2                      = 392 (Fd, PtrOut, Len, Int64Low, Int64High, Flags),
+	SYS_pwritev2                     = 393 (Fd, PtrIn, Len, Int64Low, Int64High, Flags),
+	SYS_pkey_mprotect                = 394 (Addr, Len, Flags, Int),
+	SYS_pkey_alloc                   = 395 (Flags, Flags),
+	SYS_pkey_free                    = 396 (Int),
+	SYS_statx                        = 397 (Fd, PtrIn, Flags, Flags, PtrOut),
+	SYS_rseq                         = 398 (PtrInOut, Len, Flags, Int),
+	SYS_io_pgetevents                = 399 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_migrate_pages                = 400 (Int, Len, PtrIn, PtrIn),
+	SYS_kexec_file_load              = 401 (Fd, Fd, Len, PtrIn, Flags),
+	SYS_clock_gettime64              = 403 (Int, PtrOut),
+	SYS_clock_settime64              = 404 (Int, PtrIn),
+	SYS_clock_adjtime64              = 405 (Int, PtrInOut),
+	SYS_clock_getres_time64          = 406 (Int, PtrOut),
+	SYS_clock_nanosleep_time64       = 407 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime64              = 408 (Int, PtrOut),
+	SYS_timer_settime64              = 409 (Int, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime64            = 410 (Fd, PtrOut),
+	SYS_timerfd_settime64            = 411 (Fd, Flags, PtrIn, PtrOut),
+	SYS_utimensat_time64             = 412 (Fd, PtrIn, PtrIn, Flags),
+	SYS_pselect6_time64              = 413 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll_time64                 = 414 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_io_pgetevents_time64         = 416 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_recvmmsg_time64              = 417 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_mq_timedsend_time64          = 418 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive_time64       = 419 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_semtimedop_time64            = 420 (Int, PtrIn, Len, PtrIn),
+	SYS_rt_sigtimedwait_time64       = 421 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_futex_time64                 = 422 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_rr_get_interval_time64 = 423 (Int, PtrOut),
+	SYS_pidfd_send_signal            = 424 (Fd, Int, PtrIn, Flags),
+	SYS_io_uring_setup               = 425 (Len, PtrInOut),
+	SYS_io_uring_enter               = 426 (Fd, Len, Len, Flags, PtrIn, Len),
+	SYS_io_uring_register            = 427 (Fd, Flags, PtrInOut, Len),
+	SYS_open_tree                    = 428 (Fd, PtrIn, Flags),
+	SYS_move_mount                   = 429 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_fsopen                       = 430 (PtrIn, Flags),
+	SYS_fsconfig                     = 431 (Fd, Flags, PtrIn, PtrIn, Int),
+	SYS_fsmount                      = 432 (Fd, Flags, Flags),
+	SYS_fspick                       = 433 (Fd, PtrIn, Flags),
+	SYS_pidfd_open                   = 434 (Int, Flags),
+	SYS_clone3                       = 435 (PtrIn, Len),
+	SYS_close_range                  = 436 (Fd, Fd, Flags),
+	SYS_openat2                      = 437 (Fd, PtrIn, PtrIn, Len),
+	SYS_pidfd_getfd                  = 438 (Fd, Fd, Flags),
+	SYS_faccessat2                   = 439 (Fd, PtrIn, Flags, Flags),
+	SYS_process_madvise              = 440 (Fd, PtrIn, Len, Flags, Flags),
+	SYS_epoll_pwait2                 = 441 (Fd, PtrOut, Len, PtrIn, PtrIn, Len),
+	SYS_mount_setattr                = 442 (Fd, PtrIn, Flags, PtrIn, Len),
+	SYS_quotactl_fd                  = 443 (Fd, Flags, Int, PtrInOut),
+	SYS_landlock_create_ruleset      = 444 (PtrIn, Len, Flags),
+	SYS_landlock_add_rule            = 445 (Fd, Int, PtrIn, Flags),
+	SYS_landlock_restrict_self       = 446 (Fd, Flags),
+	SYS_process_mrelease             = 448 (Fd, Flags),
+	SYS_futex_waitv                  = 449 (PtrIn, Len, Flags, PtrIn, Int),
+	SYS_set_mempolicy_home_node      = 450 (Addr, Len, Int, Flags),
+	SYS_breakpoint                   = 0x0f0001 (),
+	SYS_cacheflush                   = 0x0f0002 (Addr, Addr, Flags),
+	SYS_usr26                        = 0x0f0003 (),
+	SYS_usr32                        = 0x0f0004 (),
+	SYS_set_tls                      = 0x0f0005 (Addr),
+	SYS_get_tls                      = 0x0f0006 (),
 }
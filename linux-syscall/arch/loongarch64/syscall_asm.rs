@@ -0,0 +1,274 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+/// Linux syscall result for the `loongarch64` architecture.
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[must_use]
+pub struct Result(u64);
+
+impl Result {
+	#[inline]
+	pub const fn new(register_a0: u64) -> Self {
+		Self(register_a0)
+	}
+
+	#[inline]
+	pub const fn as_u64_unchecked(self) -> u64 {
+		self.0
+	}
+
+	#[inline]
+	pub const fn as_usize_unchecked(self) -> usize {
+		self.0 as usize
+	}
+}
+
+single_register_result64!(Result);
+
+// The kernel may clobber the temporary registers `$t0` through `$t8`.
+#[cfg(not(doc))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_with_options {
+	([$($opt:ident),*] $nr:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			lateout("$a0") out_a0,
+			out("$t0") _,
+			out("$t1") _,
+			out("$t2") _,
+			out("$t3") _,
+			out("$t4") _,
+			out("$t5") _,
+			out("$t6") _,
+			out("$t7") _,
+			out("$t8") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::loongarch64::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			lateout("$a0") out_a0,
+			out("$t0") _,
+			out("$t1") _,
+			out("$t2") _,
+			out("$t3") _,
+			out("$t4") _,
+			out("$t5") _,
+			out("$t6") _,
+			out("$t7") _,
+			out("$t8") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::loongarch64::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			lateout("$a0") out_a0,
+			out("$t0") _,
+			out("$t1") _,
+			out("$t2") _,
+			out("$t3") _,
+			out("$t4") _,
+			out("$t5") _,
+			out("$t6") _,
+			out("$t7") _,
+			out("$t8") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::loongarch64::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			lateout("$a0") out_a0,
+			out("$t0") _,
+			out("$t1") _,
+			out("$t2") _,
+			out("$t3") _,
+			out("$t4") _,
+			out("$t5") _,
+			out("$t6") _,
+			out("$t7") _,
+			out("$t8") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::loongarch64::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			lateout("$a0") out_a0,
+			out("$t0") _,
+			out("$t1") _,
+			out("$t2") _,
+			out("$t3") _,
+			out("$t4") _,
+			out("$t5") _,
+			out("$t6") _,
+			out("$t7") _,
+			out("$t8") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::loongarch64::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("$a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			lateout("$a0") out_a0,
+			out("$t0") _,
+			out("$t1") _,
+			out("$t2") _,
+			out("$t3") _,
+			out("$t4") _,
+			out("$t5") _,
+			out("$t6") _,
+			out("$t7") _,
+			out("$t8") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::loongarch64::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("$a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("$a5") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			lateout("$a0") out_a0,
+			out("$t0") _,
+			out("$t1") _,
+			out("$t2") _,
+			out("$t3") _,
+			out("$t4") _,
+			out("$t5") _,
+			out("$t6") _,
+			out("$t7") _,
+			out("$t8") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::loongarch64::Result::new(out_a0)
+	}};
+}
+
+#[cfg(not(doc))]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($nr:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("$a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall 0",
+			in("$a7") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("$a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("$a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("$a5") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			options(noreturn, nostack),
+		)
+	}};
+}
@@ -0,0 +1,402 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+#![allow(non_upper_case_globals)]
+
+syscall_constants! {
+	SYS_exit                         = 4001 (Int),
+	SYS_fork                         = 4002 (),
+	SYS_read                         = 4003 (Fd, PtrOut, Len),
+	SYS_write                        = 4004 (Fd, PtrIn, Len),
+	SYS_open                         = 4005 (PtrIn, Flags, Flags),
+	SYS_close                        = 4006 (Fd),
+	SYS_waitpid                      = 4007 (Int, PtrOut, Flags),
+	SYS_creat                        = 4008 (PtrIn, Flags),
+	SYS_link                         = 4009 (PtrIn, PtrIn),
+	SYS_unlink                       = 4010 (PtrIn),
+	SYS_execve                       = 4011 (PtrIn, PtrIn, PtrIn),
+	SYS_chdir                        = 4012 (PtrIn),
+	SYS_time                         = 4013 (PtrOut),
+	SYS_mknod                        = 4014 (PtrIn, Flags, Int),
+	SYS_chmod                        = 4015 (PtrIn, Flags),
+	SYS_lchown                       = 4016 (PtrIn, Int, Int),
+	SYS_lseek                        = 4019 (Fd, Int, Int),
+	SYS_getpid                       = 4020 (),
+	SYS_mount                        = 4021 (PtrIn, PtrIn, PtrIn, Flags, PtrIn),
+	SYS_umount                       = 4022 (PtrIn),
+	SYS_setuid                       = 4023 (Int),
+	SYS_getuid                       = 4024 (),
+	SYS_stime                        = 4025 (PtrIn),
+	SYS_ptrace                       = 4026 (Flags, Int, Addr, Int),
+	SYS_alarm                        = 4027 (Int),
+	SYS_pause                        = 4029 (),
+	SYS_utime                        = 4030 (PtrIn, PtrIn),
+	SYS_access                       = 4033 (PtrIn, Flags),
+	SYS_nice                         = 4034 (Int),
+	SYS_sync                         = 4036 (),
+	SYS_kill                         = 4037 (Int, Int),
+	SYS_rename                       = 4038 (PtrIn, PtrIn),
+	SYS_mkdir                        = 4039 (PtrIn, Flags),
+	SYS_rmdir                        = 4040 (PtrIn),
+	SYS_dup                          = 4041 (Fd),
+	SYS_pipe                         = 4042 (PtrOut),
+	SYS_times                        = 4043 (PtrOut),
+	SYS_brk                          = 4045 (Addr),
+	SYS_setgid                       = 4046 (Int),
+	SYS_getgid                       = 4047 (),
+	SYS_geteuid                      = 4049 (),
+	SYS_getegid                      = 4050 (),
+	SYS_acct                         = 4051 (PtrIn),
+	SYS_umount2                      = 4052 (PtrIn, Flags),
+	SYS_ioctl                        = 4054 (Fd, Flags, Int),
+	SYS_fcntl                        = 4055 (Fd, Flags, Int),
+	SYS_setpgid                      = 4057 (Int, Int),
+	SYS_umask                        = 4060 (Flags),
+	SYS_chroot                       = 4061 (PtrIn),
+	SYS_ustat                        = 4062 (Int, PtrOut),
+	SYS_dup2                         = 4063 (Fd, Fd),
+	SYS_getppid                      = 4064 (),
+	SYS_getpgrp                      = 4065 (),
+	SYS_setsid                       = 4066 (),
+	SYS_sigaction                    = 4067 (Int, PtrIn, PtrOut),
+	SYS_sgetmask                     = 4068 (),
+	SYS_ssetmask                     = 4069 (Flags),
+	SYS_setreuid                     = 4070 (Int, Int),
+	SYS_setregid                     = 4071 (Int, Int),
+	SYS_sigsuspend                   = 4072 (Int, Int, Flags),
+	SYS_sigpending                   = 4073 (PtrOut),
+	SYS_sethostname                  = 4074 (PtrIn, Len),
+	SYS_setrlimit                    = 4075 (Int, PtrIn),
+	SYS_getrlimit                    = 4076 (Int, PtrOut),
+	SYS_getrusage                    = 4077 (Int, PtrOut),
+	SYS_gettimeofday                 = 4078 (PtrOut, PtrOut),
+	SYS_settimeofday                 = 4079 (PtrIn, PtrIn),
+	SYS_getgroups                    = 4080 (Len, PtrOut),
+	SYS_setgroups                    = 4081 (Len, PtrIn),
+	SYS_symlink                      = 4083 (PtrIn, PtrIn),
+	SYS_readlink                     = 4085 (PtrIn, PtrOut, Len),
+	SYS_swapon                       = 4087 (PtrIn, Flags),
+	SYS_reboot                       = 4088 (Int, Int, Flags, PtrIn),
+	SYS_readdir                      = 4089 (Fd, PtrOut, Int),
+	SYS_mmap                         = 4090 (Addr, Len, Flags, Flags, Fd, Int),
+	SYS_munmap                       = 4091 (Addr, Len),
+	SYS_truncate                     = 4092 (PtrIn, Int),
+	SYS_ftruncate                    = 4093 (Fd, Int),
+	SYS_fchmod                       = 4094 (Fd, Flags),
+	SYS_fchown                       = 4095 (Fd, Int, Int),
+	SYS_getpriority                  = 4096 (Int, Int),
+	SYS_setpriority                  = 4097 (Int, Int, Int),
+	SYS_statfs                       = 4099 (PtrIn, PtrOut),
+	SYS_fstatfs                      = 4100 (Fd, PtrOut),
+	SYS_socketcall                   = 4102 (Flags, PtrIn),
+	SYS_syslog                       = 4103 (Flags, PtrOut, Len),
+	SYS_setitimer                    = 4104 (Int, PtrIn, PtrOut),
+	SYS_getitimer                    = 4105 (Int, PtrOut),
+	SYS_stat                         = 4106 (PtrIn, PtrOut),
+	SYS_lstat                        = 4107 (PtrIn, PtrOut),
+	SYS_fstat                        = 4108 (Fd, PtrOut),
+	SYS_vhangup                      = 4111 (),
+	SYS_wait4                        = 4114 (Int, PtrOut, Flags, PtrOut),
+	SYS_swapoff                      = 4115 (PtrIn),
+	SYS_sysinfo                      = 4116 (PtrOut),
+	SYS_ipc                          = 4117 (Flags, Int, Int, Int, PtrInOut, Int),
+	SYS_fsync                        = 4118 (Fd),
+	SYS_sigreturn                    = 4119 (),
+	SYS_clone                        = 4120 (Flags, Addr, PtrOut, Addr, PtrOut),
+	SYS_setdomainname                = 4121 (PtrIn, Len),
+	SYS_uname                        = 4122 (PtrOut),
+	SYS_adjtimex                     = 4124 (PtrInOut),
+	SYS_mprotect                     = 4125 (Addr, Len, Flags),
+	SYS_sigprocmask                  = 4126 (Int, PtrIn, PtrOut),
+	SYS_init_module                  = 4128 (PtrIn, Len, PtrIn),
+	SYS_delete_module                = 4129 (PtrIn, Flags),
+	SYS_quotactl                     = 4131 (Flags, PtrIn, Int, PtrInOut),
+	SYS_getpgid                      = 4132 (Int),
+	SYS_fchdir                       = 4133 (Fd),
+	SYS_personality                  = 4136 (Flags),
+	SYS_setfsuid                     = 4138 (Int),
+	SYS_setfsgid                     = 4139 (Int),
+	SYS__llseek                      = 4140 (Fd, Int64High, Int64Low, PtrOut, Int),
+	SYS_getdents                     = 4141 (Fd, PtrOut, Len),
+	SYS__newselect                   = 4142 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut),
+	SYS_flock                        = 4143 (Fd, Flags),
+	SYS_msync                        = 4144 (Addr, Len, Flags),
+	SYS_readv                        = 4145 (Fd, PtrOut, Len),
+	SYS_writev                       = 4146 (Fd, PtrIn, Len),
+	SYS_cacheflush                   = 4147 (Addr, Len, Flags),
+	SYS_cachectl                     = 4148 (Addr, Len, Flags),
+	SYS_sysmips                      = 4149 (Flags, Int, Int),
+	SYS_getsid                       = 4151 (Int),
+	SYS_fdatasync                    = 4152 (Fd),
+	SYS_mlock                        = 4154 (Addr, Len),
+	SYS_munlock                      = 4155 (Addr, Len),
+	SYS_mlockall                     = 4156 (Flags),
+	SYS_munlockall                   = 4157 (),
+	SYS_sched_setparam               = 4158 (Int, PtrIn),
+	SYS_sched_getparam               = 4159 (Int, PtrOut),
+	SYS_sched_setscheduler           = 4160 (Int, Int, PtrIn),
+	SYS_sched_getscheduler           = 4161 (Int),
+	SYS_sched_yield                  = 4162 (),
+	SYS_sched_get_priority_max       = 4163 (Int),
+	SYS_sched_get_priority_min       = 4164 (Int),
+	SYS_sched_rr_get_interval        = 4165 (Int, PtrOut),
+	SYS_nanosleep                    = 4166 (PtrIn, PtrOut),
+	SYS_mremap                       = 4167 (Addr, Len, Len, Flags, Addr),
+	SYS_accept                       = 4168 (Fd, PtrOut, PtrInOut),
+	SYS_bind                         = 4169 (Fd, PtrIn, Len),
+	SYS_connect                      = 4170 (Fd, PtrIn, Len),
+	SYS_getpeername                  = 4171 (Fd, PtrOut, PtrInOut),
+	SYS_getsockname                  = 4172 (Fd, PtrOut, PtrInOut),
+	SYS_getsockopt                   = 4173 (Fd, Int, Int, PtrOut, PtrInOut),
+	SYS_listen                       = 4174 (Fd, Int),
+	SYS_recv                         = 4175 (Fd, PtrOut, Len, Flags),
+	SYS_recvfrom                     = 4176 (Fd, PtrOut, Len, Flags, PtrOut, PtrInOut),
+	SYS_recvmsg                      = 4177 (Fd, PtrInOut, Flags),
+	SYS_send                         = 4178 (Fd, PtrIn, Len, Flags),
+	SYS_sendmsg                      = 4179 (Fd, PtrIn, Flags),
+	SYS_sendto                       = 4180 (Fd, PtrIn, Len, Flags, PtrIn, Len),
+	SYS_setsockopt                   = 4181 (Fd, Int, Int, PtrIn, Len),
+	SYS_shutdown                     = 4182 (Fd, Int),
+	SYS_socket                       = 4183 (Int, Flags, Int),
+	SYS_socketpair                   = 4184 (Int, Flags, Int, PtrOut),
+	SYS_setresuid                    = 4185 (Int, Int, Int),
+	SYS_getresuid                    = 4186 (PtrOut, PtrOut, PtrOut),
+	SYS_poll                         = 4188 (PtrInOut, Len, Int),
+	SYS_setresgid                    = 4190 (Int, Int, Int),
+	SYS_getresgid                    = 4191 (PtrOut, PtrOut, PtrOut),
+	SYS_prctl                        = 4192 (Flags, Int, Int, Int, Int),
+	SYS_rt_sigreturn                 = 4193 (),
+	SYS_rt_sigaction                 = 4194 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigprocmask               = 4195 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigpending                = 4196 (PtrOut, Len),
+	SYS_rt_sigtimedwait              = 4197 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_rt_sigqueueinfo              = 4198 (Int, Int, PtrIn),
+	SYS_rt_sigsuspend                = 4199 (PtrIn, Len),
+	SYS_pread64                      = 4200 (Fd, PtrOut, Len, Pad, Int64Low, Int64High),
+	SYS_pwrite64                     = 4201 (Fd, PtrIn, Len, Pad, Int64Low, Int64High),
+	SYS_chown                        = 4202 (PtrIn, Int, Int),
+	SYS_getcwd                       = 4203 (PtrOut, Len),
+	SYS_capget                       = 4204 (PtrInOut, PtrOut),
+	SYS_capset                       = 4205 (PtrIn, PtrIn),
+	SYS_sigaltstack                  = 4206 (PtrIn, PtrOut),
+	SYS_sendfile                     = 4207 (Fd, Fd, PtrInOut, Len),
+	SYS_mmap2                        = 4210 (Addr, Len, Flags, Flags, Fd, Int),
+	SYS_truncate64                   = 4211 (PtrIn, Pad, Int64Low, Int64High),
+	SYS_ftruncate64                  = 4212 (Fd, Pad, Int64Low, Int64High),
+	SYS_stat64                       = 4213 (PtrIn, PtrOut),
+	SYS_lstat64                      = 4214 (PtrIn, PtrOut),
+	SYS_fstat64                      = 4215 (Fd, PtrOut),
+	SYS_pivot_root                   = 4216 (PtrIn, PtrIn),
+	SYS_mincore                      = 4217 (Addr, Len, PtrOut),
+	SYS_madvise                      = 4218 (Addr, Len, Flags),
+	SYS_getdents64                   = 4219 (Fd, PtrOut, Len),
+	SYS_fcntl64                      = 4220 (Fd, Flags, Int),
+	SYS_gettid                       = 4222 (),
+	SYS_readahead                    = 4223 (Fd, Pad, Int64Low, Int64High, Len),
+	SYS_setxattr                     = 4224 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_lsetxattr                    = 4225 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_fsetxattr                    = 4226 (Fd, PtrIn, PtrIn, Len, Flags),
+	SYS_getxattr                     = 4227 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_lgetxattr                    = 4228 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_fgetxattr                    = 4229 (Fd, PtrIn, PtrOut, Len),
+	SYS_listxattr                    = 4230 (PtrIn, PtrOut, Len),
+	SYS_llistxattr                   = 4231 (PtrIn, PtrOut, Len),
+	SYS_flistxattr                   = 4232 (Fd, PtrOut, Len),
+	SYS_removexattr                  = 4233 (PtrIn, PtrIn),
+	SYS_lremovexattr                 = 4234 (PtrIn, PtrIn),
+	SYS_fremovexattr                 = 4235 (Fd, PtrIn),
+	SYS_tkill                        = 4236 (Int, Int),
+	SYS_sendfile64                   = 4237 (Fd, Fd, PtrInOut, Len),
+	SYS_futex                        = 4238 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_setaffinity            = 4239 (Int, Len, PtrIn),
+	SYS_sched_getaffinity            = 4240 (Int, Len, PtrOut),
+	SYS_io_setup                     = 4241 (Len, PtrOut),
+	SYS_io_destroy                   = 4242 (Int),
+	SYS_io_getevents                 = 4243 (Int, Len, Len, PtrOut, PtrIn),
+	SYS_io_submit                    = 4244 (Int, Len, PtrIn),
+	SYS_io_cancel                    = 4245 (Int, PtrIn, PtrOut),
+	SYS_exit_group                   = 4246 (Int),
+	SYS_lookup_dcookie               = 4247 (Int64Low, Int64High, PtrOut, Len),
+	SYS_epoll_create                 = 4248 (Int),
+	SYS_epoll_ctl                    = 4249 (Fd, Flags, Fd, PtrIn),
+	SYS_epoll_wait                   = 4250 (Fd, PtrOut, Len, Int),
+	SYS_set_tid_address              = 4252 (Addr),
+	SYS_statfs64                     = 4255 (PtrIn, Len, PtrOut),
+	SYS_fstatfs64                    = 4256 (Fd, Len, PtrOut),
+	SYS_timer_create                 = 4257 (Int, PtrIn, PtrOut),
+	SYS_timer_settime                = 4258 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime                = 4259 (Int, PtrOut),
+	SYS_timer_getoverrun             = 4260 (Int),
+	SYS_timer_delete                 = 4261 (Int),
+	SYS_clock_settime                = 4262 (Int, PtrIn),
+	SYS_clock_gettime                = 4263 (Int, PtrOut),
+	SYS_clock_getres                 = 4264 (Int, PtrOut),
+	SYS_clock_nanosleep              = 4265 (Int, Flags, PtrIn, PtrOut),
+	SYS_tgkill                       = 4266 (Int, Int, Int),
+	SYS_utimes                       = 4267 (PtrIn, PtrIn),
+	SYS_mbind                        = 4268 (Addr, Len, Flags, PtrIn, Len, Flags),
+	SYS_get_mempolicy                = 4269 (PtrOut, PtrOut, Len, Addr, Flags),
+	SYS_set_mempolicy                = 4270 (Flags, PtrIn, Len),
+	SYS_mq_open                      = 4271 (PtrIn, Flags, Flags, PtrIn),
+	SYS_mq_unlink                    = 4272 (PtrIn),
+	SYS_mq_timedsend                 = 4273 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive              = 4274 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_mq_notify                    = 4275 (Fd, PtrIn),
+	SYS_mq_getsetattr                = 4276 (Fd, PtrIn, PtrOut),
+	SYS_waitid                       = 4278 (Int, Int, PtrOut, Flags, PtrOut),
+	SYS_add_key                      = 4280 (PtrIn, PtrIn, PtrIn, Len, Int),
+	SYS_request_key                  = 4281 (PtrIn, PtrIn, PtrIn, Int),
+	SYS_keyctl                       = 4282 (Flags, Int, Int, Int, Int),
+	SYS_set_thread_area              = 4283 (PtrInOut),
+	SYS_inotify_init                 = 4284 (),
+	SYS_inotify_add_watch            = 4285 (Fd, PtrIn, Flags),
+	SYS_inotify_rm_watch             = 4286 (Fd, Int),
+	SYS_migrate_pages                = 4287 (Int, Len, PtrIn, PtrIn),
+	SYS_openat                       = 4288 (Fd, PtrIn, Flags, Flags),
+	SYS_mkdirat                      = 4289 (Fd, PtrIn, Flags),
+	SYS_mknodat                      = 4290 (Fd, PtrIn, Flags, Int),
+	SYS_fchownat                     = 4291 (Fd, PtrIn, Int, Int, Flags),
+	SYS_futimesat                    = 4292 (Fd, PtrIn, PtrIn),
+	SYS_fstatat64                    = 4293 (Fd, PtrIn, PtrOut, Flags),
+	SYS_unlinkat                     = 4294 (Fd, PtrIn, Flags),
+	SYS_renameat                     = 4295 (Fd, PtrIn, Fd, PtrIn),
+	SYS_linkat                       = 4296 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_symlinkat                    = 4297 (PtrIn, Fd, PtrIn),
+	SYS_readlinkat                   = 4298 (Fd, PtrIn, PtrOut, Len),
+	SYS_fchmodat                     = 4299 (Fd, PtrIn, Flags),
+	SYS_faccessat                    = 4300 (Fd, PtrIn, Flags),
+	SYS_pselect6                     = 4301 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll                        = 4302 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_unshare                      = 4303 (Flags),
+	SYS_splice                       = 4304 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_tee                          = 4306 (Fd, Fd, Len, Flags),
+	SYS_vmsplice                     = 4307 (Fd, PtrIn, Len, Flags),
+	SYS_move_pages                   = 4308 (Int, Len, PtrIn, PtrIn, PtrOut, Flags),
+	SYS_set_robust_list              = 4309 (Addr, Len),
+	SYS_get_robust_list              = 4310 (Int, PtrOut, PtrOut),
+	SYS_kexec_load                   = 4311 (Addr, Len, PtrIn, Flags),
+	SYS_getcpu                       = 4312 (PtrOut, PtrOut, PtrOut),
+	SYS_epoll_pwait                  = 4313 (Fd, PtrOut, Len, Int, PtrIn, Len),
+	SYS_ioprio_set                   = 4314 (Int, Int, Int),
+	SYS_ioprio_get                   = 4315 (Int, Int),
+	SYS_utimensat                    = 4316 (Fd, PtrIn, PtrIn, Flags),
+	SYS_signalfd                     = 4317 (Fd, PtrIn, Len),
+	SYS_eventfd                      = 4319 (Int),
+	SYS_fallocate                    = 4320 (Fd, Flags, Int64Low, Int64High, Int64Low, Int64High),
+	SYS_timerfd_create               = 4321 (Int, Flags),
+	SYS_timerfd_gettime              = 4322 (Fd, PtrOut),
+	SYS_timerfd_settime              = 4323 (Fd, Flags, PtrIn, PtrOut),
+	SYS_signalfd4                    = 4324 (Fd, PtrIn, Len, Flags),
+	SYS_eventfd2                     = 4325 (Int, Flags),
+	SYS_epoll_create1                = 4326 (Flags),
+	SYS_dup3                         = 4327 (Fd, Fd, Flags),
+	SYS_pipe2                        = 4328 (PtrOut, Flags),
+	SYS_inotify_init1                = 4329 (Flags),
+	SYS_preadv                       = 4330 (Fd, PtrOut, Len, Int64Low, Int64High),
+	SYS_pwritev                      = 4331 (Fd, PtrIn, Len, Int64Low, Int64High),
+	SYS_rt_tgsigqueueinfo            = 4332 (Int, Int, Int, PtrIn),
+	SYS_perf_event_open              = 4333 (PtrInOut, Int, Int, Fd, Flags),
+	SYS_accept4                      = 4334 (Fd, PtrOut, PtrInOut, Flags),
+	SYS_recvmmsg                     = 4335 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_fanotify_init                = 4336 (Flags, Flags),
+	SYS_fanotify_mark                = 4337 (Fd, Flags, Int64Low, Int64High, Fd, PtrIn),
+	SYS_prlimit64                    = 4338 (Int, Int, PtrIn, PtrOut),
+	SYS_name_to_handle_at            = 4339 (Fd, PtrIn, PtrInOut, PtrOut, Flags),
+	SYS_open_by_handle_at            = 4340 (Fd, PtrIn, Flags),
+	SYS_clock_adjtime                = 4341 (Int, PtrInOut),
+	SYS_syncfs                       = 4342 (Fd),
+	SYS_sendmmsg                     = 4343 (Fd, PtrInOut, Len, Flags),
+	SYS_setns                        = 4344 (Fd, Flags),
+	SYS_process_vm_readv             = 4345 (Int, PtrOut, Len, PtrIn, Len, Flags),
+	SYS_process_vm_writev            = 4346 (Int, PtrIn, Len, PtrIn, Len, Flags),
+	SYS_kcmp                         = 4347 (Int, Int, Int, Int, Int),
+	SYS_finit_module                 = 4348 (Fd, PtrIn, Flags),
+	SYS_sched_setattr                = 4349 (Int, PtrIn, Flags),
+	SYS_sched_getattr                = 4350 (Int, PtrOut, Len, Flags),
+	SYS_renameat2                    = 4351 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_seccomp                      = 4352 (Flags, Flags, PtrInOut),
+	SYS_getrandom                    = 4353 (PtrOut, Len, Flags),
+	SYS_memfd_create                 = 4354 (PtrIn, Flags),
+	SYS_bpf                          = 4355 (Flags, PtrInOut, Len),
+	SYS_execveat                     = 4356 (Fd, PtrIn, PtrIn, PtrIn, Flags),
+	SYS_userfaultfd                  = 4357 (Flags),
+	SYS_membarrier                   = 4358 (Flags, Flags, Int),
+	SYS_mlock2                       = 4359 (Addr, Len, Flags),
+	SYS_copy_file_range              = 4360 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_preadv2                      = 4361 (Fd, PtrOut, Len, Int64Low, Int64High, Flags),
+	SYS_pwritev2                     = 4362 (Fd, PtrIn, Len, Int64Low, Int64High, Flags),
+	SYS_pkey_mprotect                = 4363 (Addr, Len, Flags, Int),
+	SYS_pkey_alloc                   = 4364 (Flags, Flags),
+	SYS_pkey_free                    = 4365 (Int),
+	SYS_statx                        = 4366 (Fd, PtrIn, Flags, Flags, PtrOut),
+	SYS_rseq                         = 4367 (PtrInOut, Len, Flags, Int),
+	SYS_io_pgetevents                = 4368 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_semget                       = 4393 (Int, Len, Flags),
+	SYS_semctl                       = 4394 (Int, Int, Flags, Int),
+	SYS_shmget                       = 4395 (Int, Len, Flags),
+	SYS_shmctl                       = 4396 (Int, Flags, PtrInOut),
+	SYS_shmat                        = 4397 (Int, Addr, Flags),
+	SYS_shmdt                        = 4398 (Addr),
+	SYS_msgget                       = 4399 (Int, Flags),
+	SYS_msgsnd                       = 4400 (Int, PtrIn, Len, Flags),
+	SYS_msgrcv                       = 4401 (Int, PtrOut, Len, Int, Flags),
+	SYS_msgctl                       = 4402 (Int, Flags, PtrInOut),
+	SYS_clock_gettime64              = 4403 (Int, PtrOut),
+	SYS_clock_settime64              = 4404 (Int, PtrIn),
+	SYS_clock_adjtime64              = 4405 (Int, PtrInOut),
+	SYS_clock_getres_time64          = 4406 (Int, PtrOut),
+	SYS_clock_nanosleep_time64       = 4407 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime64              = 4408 (Int, PtrOut),
+	SYS_timer_settime64              = 4409 (Int, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime64            = 4410 (Fd, PtrOut),
+	SYS_timerfd_settime64            = 4411 (Fd, Flags, PtrIn, PtrOut),
+	SYS_utimensat_time64             = 4412 (Fd, PtrIn, PtrIn, Flags),
+	SYS_pselect6_time64              = 4413 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll_time64                 = 4414 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_io_pgetevents_time64         = 4416 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_recvmmsg_time64              = 4417 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_mq_timedsend_time64          = 4418 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive_time64       = 4419 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_semtimedop_time64            = 4420 (Int, PtrIn, Len, PtrIn),
+	SYS_rt_sigtimedwait_time64       = 4421 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_futex_time64                 = 4422 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_rr_get_interval_time64 = 4423 (Int, PtrOut),
+	SYS_pidfd_send_signal            = 4424 (Fd, Int, PtrIn, Flags),
+	SYS_io_uring_setup               = 4425 (Len, PtrInOut),
+	SYS_io_uring_enter               = 4426 (Fd, Len, Len, Flags, PtrIn, Len),
+	SYS_io_uring_register            = 4427 (Fd, Flags, PtrInOut, Len),
+	SYS_open_tree                    = 4428 (Fd, PtrIn, Flags),
+	SYS_move_mount                   = 4429 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_fsopen                       = 4430 (PtrIn, Flags),
+	SYS_fsconfig                     = 4431 (Fd, Flags, PtrIn, PtrIn, Int),
+	SYS_fsmount                      = 4432 (Fd, Flags, Flags),
+	SYS_fspick                       = 4433 (Fd, PtrIn, Flags),
+	SYS_pidfd_open                   = 4434 (Int, Flags),
+	SYS_clone3                       = 4435 (PtrIn, Len),
+	SYS_close_range                  = 4436 (Fd, Fd, Flags),
+	SYS_openat2                      = 4437 (Fd, PtrIn, PtrIn, Len),
+	SYS_pidfd_getfd                  = 4438 (Fd, Fd, Flags),
+	SYS_faccessat2                   = 4439 (Fd, PtrIn, Flags, Flags),
+	SYS_process_madvise              = 4440 (Fd, PtrIn, Len, Flags, Flags),
+	SYS_epoll_pwait2                 = 4441 (Fd, PtrOut, Len, PtrIn, PtrIn, Len),
+	SYS_mount_setattr                = 4442 (Fd, PtrIn, Flags, PtrIn, Len),
+	SYS_quotactl_fd                  = 4443 (Fd, Flags, Int, PtrInOut),
+	SYS_landlock_create_ruleset      = 4444 (PtrIn, Len, Flags),
+	SYS_landlock_add_rule            = 4445 (Fd, Int, PtrIn, Flags),
+	SYS_landlock_restrict_self       = 4446 (Fd, Flags),
+	SYS_process_mrelease             = 4448 (Fd, Flags),
+	SYS_futex_waitv                  = 4449 (PtrIn, Len, Flags, PtrIn, Int),
+	SYS_set_mempolicy_home_node      = 4450 (Addr, Len, Int, Flags),
+}
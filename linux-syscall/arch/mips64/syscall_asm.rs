@@ -0,0 +1,317 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+/// Linux syscall result for the `mips64` architecture.
+///
+/// The kernel reports failure by setting register `a3` (`$7`) to a non-zero
+/// value, in which case `v0` (`$2`) holds a positive error number.
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[must_use]
+pub struct Result {
+	value: u64,
+	error: bool,
+}
+
+impl Result {
+	#[inline]
+	pub const fn new(register_v0: u64, register_a3: u64) -> Self {
+		Self {
+			value: register_v0,
+			error: register_a3 != 0,
+		}
+	}
+
+	#[inline]
+	pub const fn as_u64_unchecked(self) -> u64 {
+		self.value
+	}
+
+	#[inline]
+	pub const fn as_usize_unchecked(self) -> usize {
+		self.value as usize
+	}
+}
+
+error_flag_result64!(Result);
+
+// The kernel may clobber `v1` (`$3`), `a4` through `a7` (`$8`-`$11`), `t0`
+// through `t3` (`$12`-`$15`), `t8` and `t9` (`$24`, `$25`).
+//
+// The kernel may also clobber `hi` and `lo`, but `asm!` can't name them on
+// MIPS. This relies on the compiler not keeping values in `hi` or `lo` across
+// an `asm!` block, which is a known gap; the `arch` module documentation
+// describes MIPS support as best-effort for this reason.
+#[cfg(not(doc))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_with_options {
+	([$($opt:ident),*] $nr:expr $(,)?) => {{
+		let mut out_v0;
+		let mut out_a3;
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			lateout("$2") out_v0,
+			lateout("$7") out_a3,
+			lateout("$3") _,
+			lateout("$8") _,
+			lateout("$9") _,
+			lateout("$10") _,
+			lateout("$11") _,
+			lateout("$12") _,
+			lateout("$13") _,
+			lateout("$14") _,
+			lateout("$15") _,
+			lateout("$24") _,
+			lateout("$25") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::mips64::Result::new(out_v0, out_a3)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr $(,)?) => {{
+		let mut out_v0;
+		let mut out_a3;
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			lateout("$2") out_v0,
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			lateout("$7") out_a3,
+			lateout("$3") _,
+			lateout("$8") _,
+			lateout("$9") _,
+			lateout("$10") _,
+			lateout("$11") _,
+			lateout("$12") _,
+			lateout("$13") _,
+			lateout("$14") _,
+			lateout("$15") _,
+			lateout("$24") _,
+			lateout("$25") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::mips64::Result::new(out_v0, out_a3)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		let mut out_v0;
+		let mut out_a3;
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			lateout("$2") out_v0,
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$5") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			lateout("$7") out_a3,
+			lateout("$3") _,
+			lateout("$8") _,
+			lateout("$9") _,
+			lateout("$10") _,
+			lateout("$11") _,
+			lateout("$12") _,
+			lateout("$13") _,
+			lateout("$14") _,
+			lateout("$15") _,
+			lateout("$24") _,
+			lateout("$25") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::mips64::Result::new(out_v0, out_a3)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		let mut out_v0;
+		let mut out_a3;
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			lateout("$2") out_v0,
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$5") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$6") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			lateout("$7") out_a3,
+			lateout("$3") _,
+			lateout("$8") _,
+			lateout("$9") _,
+			lateout("$10") _,
+			lateout("$11") _,
+			lateout("$12") _,
+			lateout("$13") _,
+			lateout("$14") _,
+			lateout("$15") _,
+			lateout("$24") _,
+			lateout("$25") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::mips64::Result::new(out_v0, out_a3)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		let mut out_v0;
+		let mut out_a3;
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			lateout("$2") out_v0,
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$5") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$6") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$7") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			lateout("$7") out_a3,
+			lateout("$3") _,
+			lateout("$8") _,
+			lateout("$9") _,
+			lateout("$10") _,
+			lateout("$11") _,
+			lateout("$12") _,
+			lateout("$13") _,
+			lateout("$14") _,
+			lateout("$15") _,
+			lateout("$24") _,
+			lateout("$25") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::mips64::Result::new(out_v0, out_a3)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		let mut out_v0;
+		let mut out_a3;
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			lateout("$2") out_v0,
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$5") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$6") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$7") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("$8") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			lateout("$7") out_a3,
+			lateout("$8") _,
+			lateout("$3") _,
+			lateout("$9") _,
+			lateout("$10") _,
+			lateout("$11") _,
+			lateout("$12") _,
+			lateout("$13") _,
+			lateout("$14") _,
+			lateout("$15") _,
+			lateout("$24") _,
+			lateout("$25") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::mips64::Result::new(out_v0, out_a3)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		let mut out_v0;
+		let mut out_a3;
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			lateout("$2") out_v0,
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$5") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$6") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$7") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("$8") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("$9") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			lateout("$7") out_a3,
+			lateout("$8") _,
+			lateout("$9") _,
+			lateout("$3") _,
+			lateout("$10") _,
+			lateout("$11") _,
+			lateout("$12") _,
+			lateout("$13") _,
+			lateout("$14") _,
+			lateout("$15") _,
+			lateout("$24") _,
+			lateout("$25") _,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::mips64::Result::new(out_v0, out_a3)
+	}};
+}
+
+#[cfg(not(doc))]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($nr:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$5") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$5") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$6") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$5") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$6") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$7") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$5") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$6") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$7") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("$8") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("$2") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("$4") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("$5") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("$6") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("$7") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("$8") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("$9") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			options(noreturn, nostack),
+		)
+	}};
+}
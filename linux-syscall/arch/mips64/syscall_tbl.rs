@@ -0,0 +1,358 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+#![allow(non_upper_case_globals)]
+
+syscall_constants! {
+	SYS_read                    = 5000 (Fd, PtrOut, Len),
+	SYS_write                   = 5001 (Fd, PtrIn, Len),
+	SYS_open                    = 5002 (PtrIn, Flags, Flags),
+	SYS_close                   = 5003 (Fd),
+	SYS_stat                    = 5004 (PtrIn, PtrOut),
+	SYS_fstat                   = 5005 (Fd, PtrOut),
+	SYS_lstat                   = 5006 (PtrIn, PtrOut),
+	SYS_poll                    = 5007 (PtrInOut, Len, Int),
+	SYS_lseek                   = 5008 (Fd, Int64, Int),
+	SYS_mmap                    = 5009 (Addr, Len, Flags, Flags, Fd, Int64),
+	SYS_mprotect                = 5010 (Addr, Len, Flags),
+	SYS_munmap                  = 5011 (Addr, Len),
+	SYS_brk                     = 5012 (Addr),
+	SYS_rt_sigaction            = 5013 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigprocmask          = 5014 (Int, PtrIn, PtrOut, Len),
+	SYS_ioctl                   = 5015 (Fd, Flags, Int),
+	SYS_pread64                 = 5016 (Fd, PtrOut, Len, Int64),
+	SYS_pwrite64                = 5017 (Fd, PtrIn, Len, Int64),
+	SYS_readv                   = 5018 (Fd, PtrOut, Len),
+	SYS_writev                  = 5019 (Fd, PtrIn, Len),
+	SYS_access                  = 5020 (PtrIn, Flags),
+	SYS_pipe                    = 5021 (PtrOut),
+	SYS__newselect              = 5022 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut),
+	SYS_sched_yield             = 5023 (),
+	SYS_mremap                  = 5024 (Addr, Len, Len, Flags, Addr),
+	SYS_msync                   = 5025 (Addr, Len, Flags),
+	SYS_mincore                 = 5026 (Addr, Len, PtrOut),
+	SYS_madvise                 = 5027 (Addr, Len, Flags),
+	SYS_shmget                  = 5028 (Int, Len, Flags),
+	SYS_shmat                   = 5029 (Int, Addr, Flags),
+	SYS_shmctl                  = 5030 (Int, Flags, PtrInOut),
+	SYS_dup                     = 5031 (Fd),
+	SYS_dup2                    = 5032 (Fd, Fd),
+	SYS_pause                   = 5033 (),
+	SYS_nanosleep               = 5034 (PtrIn, PtrOut),
+	SYS_getitimer               = 5035 (Int, PtrOut),
+	SYS_setitimer               = 5036 (Int, PtrIn, PtrOut),
+	SYS_alarm                   = 5037 (Int),
+	SYS_getpid                  = 5038 (),
+	SYS_sendfile                = 5039 (Fd, Fd, PtrInOut, Len),
+	SYS_socket                  = 5040 (Int, Flags, Int),
+	SYS_connect                 = 5041 (Fd, PtrIn, Len),
+	SYS_accept                  = 5042 (Fd, PtrOut, PtrInOut),
+	SYS_sendto                  = 5043 (Fd, PtrIn, Len, Flags, PtrIn, Len),
+	SYS_recvfrom                = 5044 (Fd, PtrOut, Len, Flags, PtrOut, PtrInOut),
+	SYS_sendmsg                 = 5045 (Fd, PtrIn, Flags),
+	SYS_recvmsg                 = 5046 (Fd, PtrInOut, Flags),
+	SYS_shutdown                = 5047 (Fd, Int),
+	SYS_bind                    = 5048 (Fd, PtrIn, Len),
+	SYS_listen                  = 5049 (Fd, Int),
+	SYS_getsockname             = 5050 (Fd, PtrOut, PtrInOut),
+	SYS_getpeername             = 5051 (Fd, PtrOut, PtrInOut),
+	SYS_socketpair              = 5052 (Int, Flags, Int, PtrOut),
+	SYS_setsockopt              = 5053 (Fd, Int, Int, PtrIn, Len),
+	SYS_getsockopt              = 5054 (Fd, Int, Int, PtrOut, PtrInOut),
+	SYS_clone                   = 5055 (Flags, Addr, PtrOut, Addr, PtrOut),
+	SYS_fork                    = 5056 (),
+	SYS_execve                  = 5057 (PtrIn, PtrIn, PtrIn),
+	SYS_exit                    = 5058 (Int),
+	SYS_wait4                   = 5059 (Int, PtrOut, Flags, PtrOut),
+	SYS_kill                    = 5060 (Int, Int),
+	SYS_uname                   = 5061 (PtrOut),
+	SYS_semget                  = 5062 (Int, Len, Flags),
+	SYS_semop                   = 5063 (Int, PtrIn, Len),
+	SYS_semctl                  = 5064 (Int, Int, Flags, Int),
+	SYS_shmdt                   = 5065 (Addr),
+	SYS_msgget                  = 5066 (Int, Flags),
+	SYS_msgsnd                  = 5067 (Int, PtrIn, Len, Flags),
+	SYS_msgrcv                  = 5068 (Int, PtrOut, Len, Int, Flags),
+	SYS_msgctl                  = 5069 (Int, Flags, PtrInOut),
+	SYS_fcntl                   = 5070 (Fd, Flags, Int),
+	SYS_flock                   = 5071 (Fd, Flags),
+	SYS_fsync                   = 5072 (Fd),
+	SYS_fdatasync               = 5073 (Fd),
+	SYS_truncate                = 5074 (PtrIn, Int64),
+	SYS_ftruncate               = 5075 (Fd, Int64),
+	SYS_getdents                = 5076 (Fd, PtrOut, Len),
+	SYS_getcwd                  = 5077 (PtrOut, Len),
+	SYS_chdir                   = 5078 (PtrIn),
+	SYS_fchdir                  = 5079 (Fd),
+	SYS_rename                  = 5080 (PtrIn, PtrIn),
+	SYS_mkdir                   = 5081 (PtrIn, Flags),
+	SYS_rmdir                   = 5082 (PtrIn),
+	SYS_creat                   = 5083 (PtrIn, Flags),
+	SYS_link                    = 5084 (PtrIn, PtrIn),
+	SYS_unlink                  = 5085 (PtrIn),
+	SYS_symlink                 = 5086 (PtrIn, PtrIn),
+	SYS_readlink                = 5087 (PtrIn, PtrOut, Len),
+	SYS_chmod                   = 5088 (PtrIn, Flags),
+	SYS_fchmod                  = 5089 (Fd, Flags),
+	SYS_chown                   = 5090 (PtrIn, Int, Int),
+	SYS_fchown                  = 5091 (Fd, Int, Int),
+	SYS_lchown                  = 5092 (PtrIn, Int, Int),
+	SYS_umask                   = 5093 (Flags),
+	SYS_gettimeofday            = 5094 (PtrOut, PtrOut),
+	SYS_getrlimit               = 5095 (Int, PtrOut),
+	SYS_getrusage               = 5096 (Int, PtrOut),
+	SYS_sysinfo                 = 5097 (PtrOut),
+	SYS_times                   = 5098 (PtrOut),
+	SYS_ptrace                  = 5099 (Flags, Int, Addr, Int),
+	SYS_getuid                  = 5100 (),
+	SYS_syslog                  = 5101 (Flags, PtrOut, Len),
+	SYS_getgid                  = 5102 (),
+	SYS_setuid                  = 5103 (Int),
+	SYS_setgid                  = 5104 (Int),
+	SYS_geteuid                 = 5105 (),
+	SYS_getegid                 = 5106 (),
+	SYS_setpgid                 = 5107 (Int, Int),
+	SYS_getppid                 = 5108 (),
+	SYS_getpgrp                 = 5109 (),
+	SYS_setsid                  = 5110 (),
+	SYS_setreuid                = 5111 (Int, Int),
+	SYS_setregid                = 5112 (Int, Int),
+	SYS_getgroups               = 5113 (Len, PtrOut),
+	SYS_setgroups               = 5114 (Len, PtrIn),
+	SYS_setresuid               = 5115 (Int, Int, Int),
+	SYS_getresuid               = 5116 (PtrOut, PtrOut, PtrOut),
+	SYS_setresgid               = 5117 (Int, Int, Int),
+	SYS_getresgid               = 5118 (PtrOut, PtrOut, PtrOut),
+	SYS_getpgid                 = 5119 (Int),
+	SYS_setfsuid                = 5120 (Int),
+	SYS_setfsgid                = 5121 (Int),
+	SYS_getsid                  = 5122 (Int),
+	SYS_capget                  = 5123 (PtrInOut, PtrOut),
+	SYS_capset                  = 5124 (PtrIn, PtrIn),
+	SYS_rt_sigpending           = 5125 (PtrOut, Len),
+	SYS_rt_sigtimedwait         = 5126 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_rt_sigqueueinfo         = 5127 (Int, Int, PtrIn),
+	SYS_rt_sigsuspend           = 5128 (PtrIn, Len),
+	SYS_sigaltstack             = 5129 (PtrIn, PtrOut),
+	SYS_utime                   = 5130 (PtrIn, PtrIn),
+	SYS_mknod                   = 5131 (PtrIn, Flags, Int),
+	SYS_personality             = 5132 (Flags),
+	SYS_ustat                   = 5133 (Int, PtrOut),
+	SYS_statfs                  = 5134 (PtrIn, PtrOut),
+	SYS_fstatfs                 = 5135 (Fd, PtrOut),
+	SYS_getpriority             = 5137 (Int, Int),
+	SYS_setpriority             = 5138 (Int, Int, Int),
+	SYS_sched_setparam          = 5139 (Int, PtrIn),
+	SYS_sched_getparam          = 5140 (Int, PtrOut),
+	SYS_sched_setscheduler      = 5141 (Int, Int, PtrIn),
+	SYS_sched_getscheduler      = 5142 (Int),
+	SYS_sched_get_priority_max  = 5143 (Int),
+	SYS_sched_get_priority_min  = 5144 (Int),
+	SYS_sched_rr_get_interval   = 5145 (Int, PtrOut),
+	SYS_mlock                   = 5146 (Addr, Len),
+	SYS_munlock                 = 5147 (Addr, Len),
+	SYS_mlockall                = 5148 (Flags),
+	SYS_munlockall              = 5149 (),
+	SYS_vhangup                 = 5150 (),
+	SYS_pivot_root              = 5151 (PtrIn, PtrIn),
+	SYS_prctl                   = 5153 (Flags, Int, Int, Int, Int),
+	SYS_adjtimex                = 5154 (PtrInOut),
+	SYS_setrlimit               = 5155 (Int, PtrIn),
+	SYS_chroot                  = 5156 (PtrIn),
+	SYS_sync                    = 5157 (),
+	SYS_acct                    = 5158 (PtrIn),
+	SYS_settimeofday            = 5159 (PtrIn, PtrIn),
+	SYS_mount                   = 5160 (PtrIn, PtrIn, PtrIn, Flags, PtrIn),
+	SYS_umount2                 = 5161 (PtrIn, Flags),
+	SYS_swapon                  = 5162 (PtrIn, Flags),
+	SYS_swapoff                 = 5163 (PtrIn),
+	SYS_reboot                  = 5164 (Int, Int, Flags, PtrIn),
+	SYS_sethostname             = 5165 (PtrIn, Len),
+	SYS_setdomainname           = 5166 (PtrIn, Len),
+	SYS_init_module             = 5168 (PtrIn, Len, PtrIn),
+	SYS_delete_module           = 5169 (PtrIn, Flags),
+	SYS_quotactl                = 5172 (Flags, PtrIn, Int, PtrInOut),
+	SYS_gettid                  = 5178 (),
+	SYS_readahead               = 5179 (Fd, Int64, Len),
+	SYS_setxattr                = 5180 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_lsetxattr               = 5181 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_fsetxattr               = 5182 (Fd, PtrIn, PtrIn, Len, Flags),
+	SYS_getxattr                = 5183 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_lgetxattr               = 5184 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_fgetxattr               = 5185 (Fd, PtrIn, PtrOut, Len),
+	SYS_listxattr               = 5186 (PtrIn, PtrOut, Len),
+	SYS_llistxattr              = 5187 (PtrIn, PtrOut, Len),
+	SYS_flistxattr              = 5188 (Fd, PtrOut, Len),
+	SYS_removexattr             = 5189 (PtrIn, PtrIn),
+	SYS_lremovexattr            = 5190 (PtrIn, PtrIn),
+	SYS_fremovexattr            = 5191 (Fd, PtrIn),
+	SYS_tkill                   = 5192 (Int, Int),
+	SYS_futex                   = 5194 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_setaffinity       = 5195 (Int, Len, PtrIn),
+	SYS_sched_getaffinity       = 5196 (Int, Len, PtrOut),
+	SYS_cacheflush              = 5197 (Addr, Len, Flags),
+	SYS_cachectl                = 5198 (Addr, Len, Flags),
+	SYS_sysmips                 = 5199 (Flags, Int, Int),
+	SYS_io_setup                = 5200 (Len, PtrOut),
+	SYS_io_destroy              = 5201 (Int),
+	SYS_io_getevents            = 5202 (Int, Len, Len, PtrOut, PtrIn),
+	SYS_io_submit               = 5203 (Int, Len, PtrIn),
+	SYS_io_cancel               = 5204 (Int, PtrIn, PtrOut),
+	SYS_exit_group              = 5205 (Int),
+	SYS_lookup_dcookie          = 5206 (Int64, PtrOut, Len),
+	SYS_epoll_create            = 5207 (Int),
+	SYS_epoll_ctl               = 5208 (Fd, Flags, Fd, PtrIn),
+	SYS_epoll_wait              = 5209 (Fd, PtrOut, Len, Int),
+	SYS_rt_sigreturn            = 5211 (),
+	SYS_set_tid_address         = 5212 (Addr),
+	SYS_semtimedop              = 5214 (Int, PtrIn, Len, PtrIn),
+	SYS_fadvise64               = 5215 (Fd, Int64, Len, Int),
+	SYS_timer_create            = 5216 (Int, PtrIn, PtrOut),
+	SYS_timer_settime           = 5217 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime           = 5218 (Int, PtrOut),
+	SYS_timer_getoverrun        = 5219 (Int),
+	SYS_timer_delete            = 5220 (Int),
+	SYS_clock_settime           = 5221 (Int, PtrIn),
+	SYS_clock_gettime           = 5222 (Int, PtrOut),
+	SYS_clock_getres            = 5223 (Int, PtrOut),
+	SYS_clock_nanosleep         = 5224 (Int, Flags, PtrIn, PtrOut),
+	SYS_tgkill                  = 5225 (Int, Int, Int),
+	SYS_utimes                  = 5226 (PtrIn, PtrIn),
+	SYS_mbind                   = 5227 (Addr, Len, Flags, PtrIn, Len, Flags),
+	SYS_get_mempolicy           = 5228 (PtrOut, PtrOut, Len, Addr, Flags),
+	SYS_set_mempolicy           = 5229 (Flags, PtrIn, Len),
+	SYS_mq_open                 = 5230 (PtrIn, Flags, Flags, PtrIn),
+	SYS_mq_unlink               = 5231 (PtrIn),
+	SYS_mq_timedsend            = 5232 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive         = 5233 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_mq_notify               = 5234 (Fd, PtrIn),
+	SYS_mq_getsetattr           = 5235 (Fd, PtrIn, PtrOut),
+	SYS_waitid                  = 5237 (Int, Int, PtrOut, Flags, PtrOut),
+	SYS_add_key                 = 5239 (PtrIn, PtrIn, PtrIn, Len, Int),
+	SYS_request_key             = 5240 (PtrIn, PtrIn, PtrIn, Int),
+	SYS_keyctl                  = 5241 (Flags, Int, Int, Int, Int),
+	SYS_set_thread_area         = 5242 (PtrInOut),
+	SYS_inotify_init            = 5243 (),
+	SYS_inotify_add_watch       = 5244 (Fd, PtrIn, Flags),
+	SYS_inotify_rm_watch        = 5245 (Fd, Int),
+	SYS_migrate_pages           = 5246 (Int, Len, PtrIn, PtrIn),
+	SYS_openat                  = 5247 (Fd, PtrIn, Flags, Flags),
+	SYS_mkdirat                 = 5248 (Fd, PtrIn, Flags),
+	SYS_mknodat                 = 5249 (Fd, PtrIn, Flags, Int),
+	SYS_fchownat                = 5250 (Fd, PtrIn, Int, Int, Flags),
+	SYS_futimesat               = 5251 (Fd, PtrIn, PtrIn),
+	SYS_newfstatat              = 5252 (Fd, PtrIn, PtrOut, Flags),
+	SYS_unlinkat                = 5253 (Fd, PtrIn, Flags),
+	SYS_renameat                = 5254 (Fd, PtrIn, Fd, PtrIn),
+	SYS_linkat                  = 5255 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_symlinkat               = 5256 (PtrIn, Fd, PtrIn),
+	SYS_readlinkat              = 5257 (Fd, PtrIn, PtrOut, Len),
+	SYS_fchmodat                = 5258 (Fd, PtrIn, Flags),
+	SYS_faccessat               = 5259 (Fd, PtrIn, Flags),
+	SYS_pselect6                = 5260 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll                   = 5261 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_unshare                 = 5262 (Flags),
+	SYS_splice                  = 5263 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_sync_file_range         = 5264 (Fd, Int64, Int64, Flags),
+	SYS_tee                     = 5265 (Fd, Fd, Len, Flags),
+	SYS_vmsplice                = 5266 (Fd, PtrIn, Len, Flags),
+	SYS_move_pages              = 5267 (Int, Len, PtrIn, PtrIn, PtrOut, Flags),
+	SYS_set_robust_list         = 5268 (Addr, Len),
+	SYS_get_robust_list         = 5269 (Int, PtrOut, PtrOut),
+	SYS_kexec_load              = 5270 (Addr, Len, PtrIn, Flags),
+	SYS_getcpu                  = 5271 (PtrOut, PtrOut, PtrOut),
+	SYS_epoll_pwait             = 5272 (Fd, PtrOut, Len, Int, PtrIn, Len),
+	SYS_ioprio_set              = 5273 (Int, Int, Int),
+	SYS_ioprio_get              = 5274 (Int, Int),
+	SYS_utimensat               = 5275 (Fd, PtrIn, PtrIn, Flags),
+	SYS_signalfd                = 5276 (Fd, PtrIn, Len),
+	SYS_eventfd                 = 5278 (Int),
+	SYS_fallocate               = 5279 (Fd, Flags, Int64, Int64),
+	SYS_timerfd_create          = 5280 (Int, Flags),
+	SYS_timerfd_gettime         = 5281 (Fd, PtrOut),
+	SYS_timerfd_settime         = 5282 (Fd, Flags, PtrIn, PtrOut),
+	SYS_signalfd4               = 5283 (Fd, PtrIn, Len, Flags),
+	SYS_eventfd2                = 5284 (Int, Flags),
+	SYS_epoll_create1           = 5285 (Flags),
+	SYS_dup3                    = 5286 (Fd, Fd, Flags),
+	SYS_pipe2                   = 5287 (PtrOut, Flags),
+	SYS_inotify_init1           = 5288 (Flags),
+	SYS_preadv                  = 5289 (Fd, PtrOut, Len, Int64, Int),
+	SYS_pwritev                 = 5290 (Fd, PtrIn, Len, Int64, Int),
+	SYS_rt_tgsigqueueinfo       = 5291 (Int, Int, Int, PtrIn),
+	SYS_perf_event_open         = 5292 (PtrInOut, Int, Int, Fd, Flags),
+	SYS_accept4                 = 5293 (Fd, PtrOut, PtrInOut, Flags),
+	SYS_recvmmsg                = 5294 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_fanotify_init           = 5295 (Flags, Flags),
+	SYS_fanotify_mark           = 5296 (Fd, Flags, Int64, Fd, PtrIn),
+	SYS_prlimit64               = 5297 (Int, Int, PtrIn, PtrOut),
+	SYS_name_to_handle_at       = 5298 (Fd, PtrIn, PtrInOut, PtrOut, Flags),
+	SYS_open_by_handle_at       = 5299 (Fd, PtrIn, Flags),
+	SYS_clock_adjtime           = 5300 (Int, PtrInOut),
+	SYS_syncfs                  = 5301 (Fd),
+	SYS_sendmmsg                = 5302 (Fd, PtrInOut, Len, Flags),
+	SYS_setns                   = 5303 (Fd, Flags),
+	SYS_process_vm_readv        = 5304 (Int, PtrOut, Len, PtrIn, Len, Flags),
+	SYS_process_vm_writev       = 5305 (Int, PtrIn, Len, PtrIn, Len, Flags),
+	SYS_kcmp                    = 5306 (Int, Int, Int, Int, Int),
+	SYS_finit_module            = 5307 (Fd, PtrIn, Flags),
+	SYS_getdents64              = 5308 (Fd, PtrOut, Len),
+	SYS_sched_setattr           = 5309 (Int, PtrIn, Flags),
+	SYS_sched_getattr           = 5310 (Int, PtrOut, Len, Flags),
+	SYS_renameat2               = 5311 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_seccomp                 = 5312 (Flags, Flags, PtrInOut),
+	SYS_getrandom               = 5313 (PtrOut, Len, Flags),
+	SYS_memfd_create            = 5314 (PtrIn, Flags),
+	SYS_bpf                     = 5315 (Flags, PtrInOut, Len),
+	SYS_execveat                = 5316 (Fd, PtrIn, PtrIn, PtrIn, Flags),
+	SYS_userfaultfd             = 5317 (Flags),
+	SYS_membarrier              = 5318 (Flags, Flags, Int),
+	SYS_mlock2                  = 5319 (Addr, Len, Flags),
+	SYS_copy_file_range         = 5320 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_preadv2                 = 5321 (Fd, PtrOut, Len, Int64, Int, Flags),
+	SYS_pwritev2                = 5322 (Fd, PtrIn, Len, Int64, Int, Flags),
+	SYS_pkey_mprotect           = 5323 (Addr, Len, Flags, Int),
+	SYS_pkey_alloc              = 5324 (Flags, Flags),
+	SYS_pkey_free               = 5325 (Int),
+	SYS_statx                   = 5326 (Fd, PtrIn, Flags, Flags, PtrOut),
+	SYS_rseq                    = 5327 (PtrInOut, Len, Flags, Int),
+	SYS_io_pgetevents           = 5328 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_pidfd_send_signal       = 5424 (Fd, Int, PtrIn, Flags),
+	SYS_io_uring_setup          = 5425 (Len, PtrInOut),
+	SYS_io_uring_enter          = 5426 (Fd, Len, Len, Flags, PtrIn, Len),
+	SYS_io_uring_register       = 5427 (Fd, Flags, PtrInOut, Len),
+	SYS_open_tree               = 5428 (Fd, PtrIn, Flags),
+	SYS_move_mount              = 5429 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_fsopen                  = 5430 (PtrIn, Flags),
+	SYS_fsconfig                = 5431 (Fd, Flags, PtrIn, PtrIn, Int),
+	SYS_fsmount                 = 5432 (Fd, Flags, Flags),
+	SYS_fspick                  = 5433 (Fd, PtrIn, Flags),
+	SYS_pidfd_open              = 5434 (Int, Flags),
+	SYS_clone3                  = 5435 (PtrIn, Len),
+	SYS_close_range             = 5436 (Fd, Fd, Flags),
+	SYS_openat2                 = 5437 (Fd, PtrIn, PtrIn, Len),
+	SYS_pidfd_getfd             = 5438 (Fd, Fd, Flags),
+	SYS_faccessat2              = 5439 (Fd, PtrIn, Flags, Flags),
+	SYS_process_madvise         = 5440 (Fd, PtrIn, Len, Flags, Flags),
+	SYS_epoll_pwait2            = 5441 (Fd, PtrOut, Len, PtrIn, PtrIn, Len),
+	SYS_mount_setattr           = 5442 (Fd, PtrIn, Flags, PtrIn, Len),
+	SYS_quotactl_fd             = 5443 (Fd, Flags, Int, PtrInOut),
+	SYS_landlock_create_ruleset = 5444 (PtrIn, Len, Flags),
+	SYS_landlock_add_rule       = 5445 (Fd, Int, PtrIn, Flags),
+	SYS_landlock_restrict_self  = 5446 (Fd, Flags),
+	SYS_process_mrelease        = 5448 (Fd, Flags),
+	SYS_futex_waitv             = 5449 (PtrIn, Len, Flags, PtrIn, Int),
+	SYS_set_mempolicy_home_node = 5450 (Addr, Len, Int, Flags),
+}
@@ -0,0 +1,338 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+/// Linux syscall result for the `powerpc64` architecture.
+///
+/// The kernel reports failure by setting the summary overflow bit of condition
+/// register field 0 (`CR0.SO`), in which case `r3` holds a positive error
+/// number.
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[must_use]
+pub struct Result {
+	value: u64,
+	error: bool,
+}
+
+const CR0_SO: u32 = 1 << 28;
+
+impl Result {
+	#[inline]
+	pub const fn new(register_r3: u64, register_cr: u32) -> Self {
+		Self {
+			value: register_r3,
+			error: register_cr & CR0_SO != 0,
+		}
+	}
+
+	#[inline]
+	pub const fn as_u64_unchecked(self) -> u64 {
+		self.value
+	}
+
+	#[inline]
+	pub const fn as_usize_unchecked(self) -> usize {
+		self.value as usize
+	}
+}
+
+error_flag_result64!(Result);
+
+// The kernel may clobber the volatile registers `r0` and `r4` through `r12`,
+// as well as `cr0`, `ctr` and `xer`.
+//
+// The kernel reports errors through `cr0`, so `preserves_flags` can't be used.
+#[cfg(not(doc))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_with_options {
+	([$($opt:ident),*] $nr:expr $(,)?) => {{
+		let mut out_r3;
+		let mut out_cr;
+		core::arch::asm!(
+			"sc",
+			"mfcr {cr}",
+			cr = lateout(reg) out_cr,
+			in("r0") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			lateout("r0") _,
+			lateout("r3") out_r3,
+			lateout("r4") _,
+			lateout("r5") _,
+			lateout("r6") _,
+			lateout("r7") _,
+			lateout("r8") _,
+			lateout("r9") _,
+			lateout("r10") _,
+			lateout("r11") _,
+			lateout("r12") _,
+			lateout("cr0") _,
+			lateout("ctr") _,
+			lateout("xer") _,
+			options(nostack, $($opt),*),
+		);
+		$crate::arch::powerpc64::Result::new(out_r3, out_cr)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr $(,)?) => {{
+		let mut out_r3;
+		let mut out_cr;
+		core::arch::asm!(
+			"sc",
+			"mfcr {cr}",
+			cr = lateout(reg) out_cr,
+			in("r0") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			lateout("r0") _,
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			lateout("r3") out_r3,
+			lateout("r4") _,
+			lateout("r5") _,
+			lateout("r6") _,
+			lateout("r7") _,
+			lateout("r8") _,
+			lateout("r9") _,
+			lateout("r10") _,
+			lateout("r11") _,
+			lateout("r12") _,
+			lateout("cr0") _,
+			lateout("ctr") _,
+			lateout("xer") _,
+			options(nostack, $($opt),*),
+		);
+		$crate::arch::powerpc64::Result::new(out_r3, out_cr)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		let mut out_r3;
+		let mut out_cr;
+		core::arch::asm!(
+			"sc",
+			"mfcr {cr}",
+			cr = lateout(reg) out_cr,
+			in("r0") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			lateout("r0") _,
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			lateout("r3") out_r3,
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			lateout("r4") _,
+			lateout("r5") _,
+			lateout("r6") _,
+			lateout("r7") _,
+			lateout("r8") _,
+			lateout("r9") _,
+			lateout("r10") _,
+			lateout("r11") _,
+			lateout("r12") _,
+			lateout("cr0") _,
+			lateout("ctr") _,
+			lateout("xer") _,
+			options(nostack, $($opt),*),
+		);
+		$crate::arch::powerpc64::Result::new(out_r3, out_cr)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		let mut out_r3;
+		let mut out_cr;
+		core::arch::asm!(
+			"sc",
+			"mfcr {cr}",
+			cr = lateout(reg) out_cr,
+			in("r0") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			lateout("r0") _,
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			lateout("r3") out_r3,
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			lateout("r4") _,
+			in("r5") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			lateout("r5") _,
+			lateout("r6") _,
+			lateout("r7") _,
+			lateout("r8") _,
+			lateout("r9") _,
+			lateout("r10") _,
+			lateout("r11") _,
+			lateout("r12") _,
+			lateout("cr0") _,
+			lateout("ctr") _,
+			lateout("xer") _,
+			options(nostack, $($opt),*),
+		);
+		$crate::arch::powerpc64::Result::new(out_r3, out_cr)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		let mut out_r3;
+		let mut out_cr;
+		core::arch::asm!(
+			"sc",
+			"mfcr {cr}",
+			cr = lateout(reg) out_cr,
+			in("r0") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			lateout("r0") _,
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			lateout("r3") out_r3,
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			lateout("r4") _,
+			in("r5") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			lateout("r5") _,
+			in("r6") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			lateout("r6") _,
+			lateout("r7") _,
+			lateout("r8") _,
+			lateout("r9") _,
+			lateout("r10") _,
+			lateout("r11") _,
+			lateout("r12") _,
+			lateout("cr0") _,
+			lateout("ctr") _,
+			lateout("xer") _,
+			options(nostack, $($opt),*),
+		);
+		$crate::arch::powerpc64::Result::new(out_r3, out_cr)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		let mut out_r3;
+		let mut out_cr;
+		core::arch::asm!(
+			"sc",
+			"mfcr {cr}",
+			cr = lateout(reg) out_cr,
+			in("r0") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			lateout("r0") _,
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			lateout("r3") out_r3,
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			lateout("r4") _,
+			in("r5") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			lateout("r5") _,
+			in("r6") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			lateout("r6") _,
+			in("r7") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			lateout("r7") _,
+			lateout("r8") _,
+			lateout("r9") _,
+			lateout("r10") _,
+			lateout("r11") _,
+			lateout("r12") _,
+			lateout("cr0") _,
+			lateout("ctr") _,
+			lateout("xer") _,
+			options(nostack, $($opt),*),
+		);
+		$crate::arch::powerpc64::Result::new(out_r3, out_cr)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		let mut out_r3;
+		let mut out_cr;
+		core::arch::asm!(
+			"sc",
+			"mfcr {cr}",
+			cr = lateout(reg) out_cr,
+			in("r0") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			lateout("r0") _,
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			lateout("r3") out_r3,
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			lateout("r4") _,
+			in("r5") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			lateout("r5") _,
+			in("r6") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			lateout("r6") _,
+			in("r7") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			lateout("r7") _,
+			in("r8") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			lateout("r8") _,
+			lateout("r9") _,
+			lateout("r10") _,
+			lateout("r11") _,
+			lateout("r12") _,
+			lateout("cr0") _,
+			lateout("ctr") _,
+			lateout("xer") _,
+			options(nostack, $($opt),*),
+		);
+		$crate::arch::powerpc64::Result::new(out_r3, out_cr)
+	}};
+}
+
+#[cfg(not(doc))]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($nr:expr $(,)?) => {{
+		core::arch::asm!(
+			"sc",
+			in("r0") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		core::arch::asm!(
+			"sc",
+			in("r0") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		core::arch::asm!(
+			"sc",
+			in("r0") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		core::arch::asm!(
+			"sc",
+			in("r0") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r5") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		core::arch::asm!(
+			"sc",
+			in("r0") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r5") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r6") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		core::arch::asm!(
+			"sc",
+			in("r0") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r5") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r6") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r7") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		core::arch::asm!(
+			"sc",
+			in("r0") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("r3") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("r4") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("r5") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r6") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r7") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("r8") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			options(noreturn, nostack),
+		)
+	}};
+}
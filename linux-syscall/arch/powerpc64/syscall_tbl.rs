@@ -0,0 +1,377 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+#![allow(non_upper_case_globals)]
+
+syscall_constants! {
+	SYS_exit                    = 1   (Int),
+	SYS_fork                    = 2   (),
+	SYS_read                    = 3   (Fd, PtrOut, Len),
+	SYS_write                   = 4   (Fd, PtrIn, Len),
+	SYS_open                    = 5   (PtrIn, Flags, Flags),
+	SYS_close                   = 6   (Fd),
+	SYS_waitpid                 = 7   (Int, PtrOut, Flags),
+	SYS_creat                   = 8   (PtrIn, Flags),
+	SYS_link                    = 9   (PtrIn, PtrIn),
+	SYS_unlink                  = 10  (PtrIn),
+	SYS_execve                  = 11  (PtrIn, PtrIn, PtrIn),
+	SYS_chdir                   = 12  (PtrIn),
+	SYS_time                    = 13  (PtrOut),
+	SYS_mknod                   = 14  (PtrIn, Flags, Int),
+	SYS_chmod                   = 15  (PtrIn, Flags),
+	SYS_lchown                  = 16  (PtrIn, Int, Int),
+	SYS_lseek                   = 19  (Fd, Int64, Int),
+	SYS_getpid                  = 20  (),
+	SYS_mount                   = 21  (PtrIn, PtrIn, PtrIn, Flags, PtrIn),
+	SYS_umount                  = 22  (PtrIn),
+	SYS_setuid                  = 23  (Int),
+	SYS_getuid                  = 24  (),
+	SYS_ptrace                  = 26  (Flags, Int, Addr, Int),
+	SYS_alarm                   = 27  (Int),
+	SYS_pause                   = 29  (),
+	SYS_utime                   = 30  (PtrIn, PtrIn),
+	SYS_access                  = 33  (PtrIn, Flags),
+	SYS_nice                    = 34  (Int),
+	SYS_sync                    = 36  (),
+	SYS_kill                    = 37  (Int, Int),
+	SYS_rename                  = 38  (PtrIn, PtrIn),
+	SYS_mkdir                   = 39  (PtrIn, Flags),
+	SYS_rmdir                   = 40  (PtrIn),
+	SYS_dup                     = 41  (Fd),
+	SYS_pipe                    = 42  (PtrOut),
+	SYS_times                   = 43  (PtrOut),
+	SYS_brk                     = 45  (Addr),
+	SYS_setgid                  = 46  (Int),
+	SYS_getgid                  = 47  (),
+	SYS_signal                  = 48  (Int, Addr),
+	SYS_geteuid                 = 49  (),
+	SYS_getegid                 = 50  (),
+	SYS_acct                    = 51  (PtrIn),
+	SYS_umount2                 = 52  (PtrIn, Flags),
+	SYS_ioctl                   = 54  (Fd, Flags, Int),
+	SYS_fcntl                   = 55  (Fd, Flags, Int),
+	SYS_setpgid                 = 57  (Int, Int),
+	SYS_umask                   = 60  (Flags),
+	SYS_chroot                  = 61  (PtrIn),
+	SYS_ustat                   = 62  (Int, PtrOut),
+	SYS_dup2                    = 63  (Fd, Fd),
+	SYS_getppid                 = 64  (),
+	SYS_getpgrp                 = 65  (),
+	SYS_setsid                  = 66  (),
+	SYS_sgetmask                = 68  (),
+	SYS_ssetmask                = 69  (Flags),
+	SYS_setreuid                = 70  (Int, Int),
+	SYS_setregid                = 71  (Int, Int),
+	SYS_sethostname             = 74  (PtrIn, Len),
+	SYS_setrlimit               = 75  (Int, PtrIn),
+	SYS_getrusage               = 77  (Int, PtrOut),
+	SYS_gettimeofday            = 78  (PtrOut, PtrOut),
+	SYS_settimeofday            = 79  (PtrIn, PtrIn),
+	SYS_getgroups               = 80  (Len, PtrOut),
+	SYS_setgroups               = 81  (Len, PtrIn),
+	SYS_symlink                 = 83  (PtrIn, PtrIn),
+	SYS_readlink                = 85  (PtrIn, PtrOut, Len),
+	SYS_swapon                  = 87  (PtrIn, Flags),
+	SYS_reboot                  = 88  (Int, Int, Flags, PtrIn),
+	SYS_mmap                    = 90  (Addr, Len, Flags, Flags, Fd, Int64),
+	SYS_munmap                  = 91  (Addr, Len),
+	SYS_truncate                = 92  (PtrIn, Int64),
+	SYS_ftruncate               = 93  (Fd, Int64),
+	SYS_fchmod                  = 94  (Fd, Flags),
+	SYS_fchown                  = 95  (Fd, Int, Int),
+	SYS_getpriority             = 96  (Int, Int),
+	SYS_setpriority             = 97  (Int, Int, Int),
+	SYS_statfs                  = 99  (PtrIn, PtrOut),
+	SYS_fstatfs                 = 100 (Fd, PtrOut),
+	SYS_socketcall              = 102 (Flags, PtrIn),
+	SYS_syslog                  = 103 (Flags, PtrOut, Len),
+	SYS_setitimer               = 104 (Int, PtrIn, PtrOut),
+	SYS_getitimer               = 105 (Int, PtrOut),
+	SYS_stat                    = 106 (PtrIn, PtrOut),
+	SYS_lstat                   = 107 (PtrIn, PtrOut),
+	SYS_fstat                   = 108 (Fd, PtrOut),
+	SYS_vhangup                 = 111 (),
+	SYS_wait4                   = 114 (Int, PtrOut, Flags, PtrOut),
+	SYS_swapoff                 = 115 (PtrIn),
+	SYS_sysinfo                 = 116 (PtrOut),
+	SYS_ipc                     = 117 (Flags, Int, Int, Int, PtrInOut, Int),
+	SYS_fsync                   = 118 (Fd),
+	SYS_clone                   = 120 (Flags, Addr, PtrOut, Addr, PtrOut),
+	SYS_setdomainname           = 121 (PtrIn, Len),
+	SYS_uname                   = 122 (PtrOut),
+	SYS_adjtimex                = 124 (PtrInOut),
+	SYS_mprotect                = 125 (Addr, Len, Flags),
+	SYS_init_module             = 128 (PtrIn, Len, PtrIn),
+	SYS_delete_module           = 129 (PtrIn, Flags),
+	SYS_quotactl                = 131 (Flags, PtrIn, Int, PtrInOut),
+	SYS_getpgid                 = 132 (Int),
+	SYS_fchdir                  = 133 (Fd),
+	SYS_personality             = 136 (Flags),
+	SYS_setfsuid                = 138 (Int),
+	SYS_setfsgid                = 139 (Int),
+	SYS__llseek                 = 140 (Fd, Int, Int, PtrOut, Int),
+	SYS_getdents                = 141 (Fd, PtrOut, Len),
+	SYS__newselect              = 142 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut),
+	SYS_flock                   = 143 (Fd, Flags),
+	SYS_msync                   = 144 (Addr, Len, Flags),
+	SYS_readv                   = 145 (Fd, PtrOut, Len),
+	SYS_writev                  = 146 (Fd, PtrIn, Len),
+	SYS_getsid                  = 147 (Int),
+	SYS_fdatasync               = 148 (Fd),
+	SYS_mlock                   = 150 (Addr, Len),
+	SYS_munlock                 = 151 (Addr, Len),
+	SYS_mlockall                = 152 (Flags),
+	SYS_munlockall              = 153 (),
+	SYS_sched_setparam          = 154 (Int, PtrIn),
+	SYS_sched_getparam          = 155 (Int, PtrOut),
+	SYS_sched_setscheduler      = 156 (Int, Int, PtrIn),
+	SYS_sched_getscheduler      = 157 (Int),
+	SYS_sched_yield             = 158 (),
+	SYS_sched_get_priority_max  = 159 (Int),
+	SYS_sched_get_priority_min  = 160 (Int),
+	SYS_sched_rr_get_interval   = 161 (Int, PtrOut),
+	SYS_nanosleep               = 162 (PtrIn, PtrOut),
+	SYS_mremap                  = 163 (Addr, Len, Len, Flags, Addr),
+	SYS_setresuid               = 164 (Int, Int, Int),
+	SYS_getresuid               = 165 (PtrOut, PtrOut, PtrOut),
+	SYS_poll                    = 167 (PtrInOut, Len, Int),
+	SYS_setresgid               = 169 (Int, Int, Int),
+	SYS_getresgid               = 170 (PtrOut, PtrOut, PtrOut),
+	SYS_prctl                   = 171 (Flags, Int, Int, Int, Int),
+	SYS_rt_sigreturn            = 172 (),
+	SYS_rt_sigaction            = 173 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigprocmask          = 174 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigpending           = 175 (PtrOut, Len),
+	SYS_rt_sigtimedwait         = 176 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_rt_sigqueueinfo         = 177 (Int, Int, PtrIn),
+	SYS_rt_sigsuspend           = 178 (PtrIn, Len),
+	SYS_pread64                 = 179 (Fd, PtrOut, Len, Int64),
+	SYS_pwrite64                = 180 (Fd, PtrIn, Len, Int64),
+	SYS_chown                   = 181 (PtrIn, Int, Int),
+	SYS_getcwd                  = 182 (PtrOut, Len),
+	SYS_capget                  = 183 (PtrInOut, PtrOut),
+	SYS_capset                  = 184 (PtrIn, PtrIn),
+	SYS_sigaltstack             = 185 (PtrIn, PtrOut),
+	SYS_sendfile                = 186 (Fd, Fd, PtrInOut, Len),
+	SYS_vfork                   = 189 (),
+	SYS_ugetrlimit              = 190 (Int, PtrOut),
+	SYS_readahead               = 191 (Fd, Int64, Len),
+	SYS_pciconfig_read          = 198 (Int, Int, Int, Len, PtrOut),
+	SYS_pciconfig_write         = 199 (Int, Int, Int, Len, PtrIn),
+	SYS_pciconfig_iobase        = 200 (Int, Int, Int),
+	SYS_getdents64              = 202 (Fd, PtrOut, Len),
+	SYS_pivot_root              = 203 (PtrIn, PtrIn),
+	SYS_madvise                 = 205 (Addr, Len, Flags),
+	SYS_mincore                 = 206 (Addr, Len, PtrOut),
+	SYS_gettid                  = 207 (),
+	SYS_tkill                   = 208 (Int, Int),
+	SYS_setxattr                = 209 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_lsetxattr               = 210 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_fsetxattr               = 211 (Fd, PtrIn, PtrIn, Len, Flags),
+	SYS_getxattr                = 212 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_lgetxattr               = 213 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_fgetxattr               = 214 (Fd, PtrIn, PtrOut, Len),
+	SYS_listxattr               = 215 (PtrIn, PtrOut, Len),
+	SYS_llistxattr              = 216 (PtrIn, PtrOut, Len),
+	SYS_flistxattr              = 217 (Fd, PtrOut, Len),
+	SYS_removexattr             = 218 (PtrIn, PtrIn),
+	SYS_lremovexattr            = 219 (PtrIn, PtrIn),
+	SYS_fremovexattr            = 220 (Fd, PtrIn),
+	SYS_futex                   = 221 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_setaffinity       = 222 (Int, Len, PtrIn),
+	SYS_sched_getaffinity       = 223 (Int, Len, PtrOut),
+	SYS_io_setup                = 227 (Len, PtrOut),
+	SYS_io_destroy              = 228 (Int),
+	SYS_io_getevents            = 229 (Int, Len, Len, PtrOut, PtrIn),
+	SYS_io_submit               = 230 (Int, Len, PtrIn),
+	SYS_io_cancel               = 231 (Int, PtrIn, PtrOut),
+	SYS_set_tid_address         = 232 (Addr),
+	SYS_fadvise64               = 233 (Fd, Int64, Len, Int),
+	SYS_exit_group              = 234 (Int),
+	SYS_lookup_dcookie          = 235 (Int64, PtrOut, Len),
+	SYS_epoll_create            = 236 (Int),
+	SYS_epoll_ctl               = 237 (Fd, Flags, Fd, PtrIn),
+	SYS_epoll_wait              = 238 (Fd, PtrOut, Len, Int),
+	SYS_timer_create            = 240 (Int, PtrIn, PtrOut),
+	SYS_timer_settime           = 241 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime           = 242 (Int, PtrOut),
+	SYS_timer_getoverrun        = 243 (Int),
+	SYS_timer_delete            = 244 (Int),
+	SYS_clock_settime           = 245 (Int, PtrIn),
+	SYS_clock_gettime           = 246 (Int, PtrOut),
+	SYS_clock_getres            = 247 (Int, PtrOut),
+	SYS_clock_nanosleep         = 248 (Int, Flags, PtrIn, PtrOut),
+	SYS_swapcontext             = 249 (PtrOut, PtrIn, Len),
+	SYS_tgkill                  = 250 (Int, Int, Int),
+	SYS_utimes                  = 251 (PtrIn, PtrIn),
+	SYS_statfs64                = 252 (PtrIn, Len, PtrOut),
+	SYS_fstatfs64               = 253 (Fd, Len, PtrOut),
+	SYS_rtas                    = 255 (PtrInOut),
+	SYS_migrate_pages           = 258 (Int, Len, PtrIn, PtrIn),
+	SYS_mbind                   = 259 (Addr, Len, Flags, PtrIn, Len, Flags),
+	SYS_get_mempolicy           = 260 (PtrOut, PtrOut, Len, Addr, Flags),
+	SYS_set_mempolicy           = 261 (Flags, PtrIn, Len),
+	SYS_mq_open                 = 262 (PtrIn, Flags, Flags, PtrIn),
+	SYS_mq_unlink               = 263 (PtrIn),
+	SYS_mq_timedsend            = 264 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive         = 265 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_mq_notify               = 266 (Fd, PtrIn),
+	SYS_mq_getsetattr           = 267 (Fd, PtrIn, PtrOut),
+	SYS_kexec_load              = 268 (Addr, Len, PtrIn, Flags),
+	SYS_add_key                 = 269 (PtrIn, PtrIn, PtrIn, Len, Int),
+	SYS_request_key             = 270 (PtrIn, PtrIn, PtrIn, Int),
+	SYS_keyctl                  = 271 (Flags, Int, Int, Int, Int),
+	SYS_waitid                  = 272 (Int, Int, PtrOut, Flags, PtrOut),
+	SYS_ioprio_set              = 273 (Int, Int, Int),
+	SYS_ioprio_get              = 274 (Int, Int),
+	SYS_inotify_init            = 275 (),
+	SYS_inotify_add_watch       = 276 (Fd, PtrIn, Flags),
+	SYS_inotify_rm_watch        = 277 (Fd, Int),
+	SYS_spu_run                 = 278 (Fd, PtrInOut, PtrOut),
+	SYS_spu_create              = 279 (PtrIn, Flags, Flags, Fd),
+	SYS_pselect6                = 280 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll                   = 281 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_unshare                 = 282 (Flags),
+	SYS_splice                  = 283 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_tee                     = 284 (Fd, Fd, Len, Flags),
+	SYS_vmsplice                = 285 (Fd, PtrIn, Len, Flags),
+	SYS_openat                  = 286 (Fd, PtrIn, Flags, Flags),
+	SYS_mkdirat                 = 287 (Fd, PtrIn, Flags),
+	SYS_mknodat                 = 288 (Fd, PtrIn, Flags, Int),
+	SYS_fchownat                = 289 (Fd, PtrIn, Int, Int, Flags),
+	SYS_futimesat               = 290 (Fd, PtrIn, PtrIn),
+	SYS_newfstatat              = 291 (Fd, PtrIn, PtrOut, Flags),
+	SYS_unlinkat                = 292 (Fd, PtrIn, Flags),
+	SYS_renameat                = 293 (Fd, PtrIn, Fd, PtrIn),
+	SYS_linkat                  = 294 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_symlinkat               = 295 (PtrIn, Fd, PtrIn),
+	SYS_readlinkat              = 296 (Fd, PtrIn, PtrOut, Len),
+	SYS_fchmodat                = 297 (Fd, PtrIn, Flags),
+	SYS_faccessat               = 298 (Fd, PtrIn, Flags),
+	SYS_get_robust_list         = 299 (Int, PtrOut, PtrOut),
+	SYS_set_robust_list         = 300 (Addr, Len),
+	SYS_move_pages              = 301 (Int, Len, PtrIn, PtrIn, PtrOut, Flags),
+	SYS_getcpu                  = 302 (PtrOut, PtrOut, PtrOut),
+	SYS_epoll_pwait             = 303 (Fd, PtrOut, Len, Int, PtrIn, Len),
+	SYS_utimensat               = 304 (Fd, PtrIn, PtrIn, Flags),
+	SYS_signalfd                = 305 (Fd, PtrIn, Len),
+	SYS_timerfd_create          = 306 (Int, Flags),
+	SYS_eventfd                 = 307 (Int),
+	SYS_sync_file_range2        = 308 (Fd, Flags, Int64, Int64),
+	SYS_fallocate               = 309 (Fd, Flags, Int64, Int64),
+	SYS_subpage_prot            = 310 (Addr, Len, PtrIn),
+	SYS_timerfd_settime         = 311 (Fd, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime         = 312 (Fd, PtrOut),
+	SYS_signalfd4               = 313 (Fd, PtrIn, Len, Flags),
+	SYS_eventfd2                = 314 (Int, Flags),
+	SYS_epoll_create1           = 315 (Flags),
+	SYS_dup3                    = 316 (Fd, Fd, Flags),
+	SYS_pipe2                   = 317 (PtrOut, Flags),
+	SYS_inotify_init1           = 318 (Flags),
+	SYS_perf_event_open         = 319 (PtrInOut, Int, Int, Fd, Flags),
+	SYS_preadv                  = 320 (Fd, PtrOut, Len, Int64, Int),
+	SYS_pwritev                 = 321 (Fd, PtrIn, Len, Int64, Int),
+	SYS_rt_tgsigqueueinfo       = 322 (Int, Int, Int, PtrIn),
+	SYS_fanotify_init           = 323 (Flags, Flags),
+	SYS_fanotify_mark           = 324 (Fd, Flags, Int64, Fd, PtrIn),
+	SYS_prlimit64               = 325 (Int, Int, PtrIn, PtrOut),
+	SYS_socket                  = 326 (Int, Flags, Int),
+	SYS_bind                    = 327 (Fd, PtrIn, Len),
+	SYS_connect                 = 328 (Fd, PtrIn, Len),
+	SYS_listen                  = 329 (Fd, Int),
+	SYS_accept                  = 330 (Fd, PtrOut, PtrInOut),
+	SYS_getsockname             = 331 (Fd, PtrOut, PtrInOut),
+	SYS_getpeername             = 332 (Fd, PtrOut, PtrInOut),
+	SYS_socketpair              = 333 (Int, Flags, Int, PtrOut),
+	SYS_send                    = 334 (Fd, PtrIn, Len, Flags),
+	SYS_sendto                  = 335 (Fd, PtrIn, Len, Flags, PtrIn, Len),
+	SYS_recv                    = 336 (Fd, PtrOut, Len, Flags),
+	SYS_recvfrom                = 337 (Fd, PtrOut, Len, Flags, PtrOut, PtrInOut),
+	SYS_shutdown                = 338 (Fd, Int),
+	SYS_setsockopt              = 339 (Fd, Int, Int, PtrIn, Len),
+	SYS_getsockopt              = 340 (Fd, Int, Int, PtrOut, PtrInOut),
+	SYS_sendmsg                 = 341 (Fd, PtrIn, Flags),
+	SYS_recvmsg                 = 342 (Fd, PtrInOut, Flags),
+	SYS_recvmmsg                = 343 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_accept4                 = 344 (Fd, PtrOut, PtrInOut, Flags),
+	SYS_name_to_handle_at       = 345 (Fd, PtrIn, PtrInOut, PtrOut, Flags),
+	SYS_open_by_handle_at       = 346 (Fd, PtrIn, Flags),
+	SYS_clock_adjtime           = 347 (Int, PtrInOut),
+	SYS_syncfs                  = 348 (Fd),
+	SYS_sendmmsg                = 349 (Fd, PtrInOut, Len, Flags),
+	SYS_setns                   = 350 (Fd, Flags),
+	SYS_process_vm_readv        = 351 (Int, PtrOut, Len, PtrIn, Len, Flags),
+	SYS_process_vm_writev       = 352 (Int, PtrIn, Len, PtrIn, Len, Flags),
+	SYS_finit_module            = 353 (Fd, PtrIn, Flags),
+	SYS_kcmp                    = 354 (Int, Int, Int, Int, Int),
+	SYS_sched_setattr           = 355 (Int, PtrIn, Flags),
+	SYS_sched_getattr           = 356 (Int, PtrOut, Len, Flags),
+	SYS_renameat2               = 357 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_seccomp                 = 358 (Flags, Flags, PtrInOut),
+	SYS_getrandom               = 359 (PtrOut, Len, Flags),
+	SYS_memfd_create            = 360 (PtrIn, Flags),
+	SYS_bpf                     = 361 (Flags, PtrInOut, Len),
+	SYS_execveat                = 362 (Fd, PtrIn, PtrIn, PtrIn, Flags),
+	SYS_switch_endian           = 363 (),
+	SYS_userfaultfd             = 364 (Flags),
+	SYS_membarrier              = 365 (Flags, Flags, Int),
+	SYS_mlock2                  = 378 (Addr, Len, Flags),
+	SYS_copy_file_range         = 379 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_preadv2                 = 380 (Fd, PtrOut, Len, Int64, Int, Flags),
+	SYS_pwritev2                = 381 (Fd, PtrIn, Len, Int64, Int, Flags),
+	SYS_kexec_file_load         = 382 (Fd, Fd, Len, PtrIn, Flags),
+	SYS_statx                   = 383 (Fd, PtrIn, Flags, Flags, PtrOut),
+	SYS_pkey_alloc              = 384 (Flags, Flags),
+	SYS_pkey_free               = 385 (Int),
+	SYS_pkey_mprotect           = 386 (Addr, Len, Flags, Int),
+	SYS_rseq                    = 387 (PtrInOut, Len, Flags, Int),
+	SYS_io_pgetevents           = 388 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_semget                  = 393 (Int, Len, Flags),
+	SYS_semctl                  = 394 (Int, Int, Flags, Int),
+	SYS_shmget                  = 395 (Int, Len, Flags),
+	SYS_shmctl                  = 396 (Int, Flags, PtrInOut),
+	SYS_shmat                   = 397 (Int, Addr, Flags),
+	SYS_shmdt                   = 398 (Addr),
+	SYS_msgget                  = 399 (Int, Flags),
+	SYS_msgsnd                  = 400 (Int, PtrIn, Len, Flags),
+	SYS_msgrcv                  = 401 (Int, PtrOut, Len, Int, Flags),
+	SYS_msgctl                  = 402 (Int, Flags, PtrInOut),
+	SYS_pidfd_send_signal       = 424 (Fd, Int, PtrIn, Flags),
+	SYS_io_uring_setup          = 425 (Len, PtrInOut),
+	SYS_io_uring_enter          = 426 (Fd, Len, Len, Flags, PtrIn, Len),
+	SYS_io_uring_register       = 427 (Fd, Flags, PtrInOut, Len),
+	SYS_open_tree               = 428 (Fd, PtrIn, Flags),
+	SYS_move_mount              = 429 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_fsopen                  = 430 (PtrIn, Flags),
+	SYS_fsconfig                = 431 (Fd, Flags, PtrIn, PtrIn, Int),
+	SYS_fsmount                 = 432 (Fd, Flags, Flags),
+	SYS_fspick                  = 433 (Fd, PtrIn, Flags),
+	SYS_pidfd_open              = 434 (Int, Flags),
+	SYS_clone3                  = 435 (PtrIn, Len),
+	SYS_close_range             = 436 (Fd, Fd, Flags),
+	SYS_openat2                 = 437 (Fd, PtrIn, PtrIn, Len),
+	SYS_pidfd_getfd             = 438 (Fd, Fd, Flags),
+	SYS_faccessat2              = 439 (Fd, PtrIn, Flags, Flags),
+	SYS_process_madvise         = 440 (Fd, PtrIn, Len, Flags, Flags),
+	SYS_epoll_pwait2            = 441 (Fd, PtrOut, Len, PtrIn, PtrIn, Len),
+	SYS_mount_setattr           = 442 (Fd, PtrIn, Flags, PtrIn, Len),
+	SYS_quotactl_fd             = 443 (Fd, Flags, Int, PtrInOut),
+	SYS_landlock_create_ruleset = 444 (PtrIn, Len, Flags),
+	SYS_landlock_add_rule       = 445 (Fd, Int, PtrIn, Flags),
+	SYS_landlock_restrict_self  = 446 (Fd, Flags),
+	SYS_process_mrelease        = 448 (Fd, Flags),
+	SYS_futex_waitv             = 449 (PtrIn, Len, Flags, PtrIn, Int),
+	SYS_set_mempolicy_home_node = 450 (Addr, Len, Int, Flags),
+}
@@ -0,0 +1,210 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+/// Linux syscall result for the `riscv32` architecture.
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[must_use]
+pub struct Result(u32);
+
+impl Result {
+	#[inline]
+	pub const fn new(register_a0: u32) -> Self {
+		Self(register_a0)
+	}
+
+	#[inline]
+	pub const fn as_u32_unchecked(self) -> u32 {
+		self.0
+	}
+
+	#[inline]
+	pub const fn as_usize_unchecked(self) -> usize {
+		self.0 as usize
+	}
+}
+
+single_register_result32!(Result);
+
+#[cfg(not(doc))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_with_options {
+	([$($opt:ident),*] $nr:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			lateout("a0") out_a0,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::riscv32::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			lateout("a0") out_a0,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::riscv32::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			lateout("a0") out_a0,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::riscv32::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			lateout("a0") out_a0,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::riscv32::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			lateout("a0") out_a0,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::riscv32::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			lateout("a0") out_a0,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::riscv32::Result::new(out_a0)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		let mut out_a0;
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("a5") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			lateout("a0") out_a0,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::riscv32::Result::new(out_a0)
+	}};
+}
+
+#[cfg(not(doc))]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($nr:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("a5") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			options(noreturn, nostack),
+		)
+	}};
+}
@@ -0,0 +1,312 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+#![allow(non_upper_case_globals)]
+
+syscall_constants! {
+	SYS_io_setup                     = 0   (Len, PtrOut),
+	SYS_io_destroy                   = 1   (Int),
+	SYS_io_submit                    = 2   (Int, Len, PtrIn),
+	SYS_io_cancel                    = 3   (Int, PtrIn, PtrOut),
+	SYS_setxattr                     = 5   (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_lsetxattr                    = 6   (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_fsetxattr                    = 7   (Fd, PtrIn, PtrIn, Len, Flags),
+	SYS_getxattr                     = 8   (PtrIn, PtrIn, PtrOut, Len),
+	SYS_lgetxattr                    = 9   (PtrIn, PtrIn, PtrOut, Len),
+	SYS_fgetxattr                    = 10  (Fd, PtrIn, PtrOut, Len),
+	SYS_listxattr                    = 11  (PtrIn, PtrOut, Len),
+	SYS_llistxattr                   = 12  (PtrIn, PtrOut, Len),
+	SYS_flistxattr                   = 13  (Fd, PtrOut, Len),
+	SYS_removexattr                  = 14  (PtrIn, PtrIn),
+	SYS_lremovexattr                 = 15  (PtrIn, PtrIn),
+	SYS_fremovexattr                 = 16  (Fd, PtrIn),
+	SYS_getcwd                       = 17  (PtrOut, Len),
+	SYS_lookup_dcookie               = 18  (Int64Low, Int64High, PtrOut, Len),
+	SYS_eventfd2                     = 19  (Int, Flags),
+	SYS_epoll_create1                = 20  (Flags),
+	SYS_epoll_ctl                    = 21  (Fd, Flags, Fd, PtrIn),
+	SYS_epoll_pwait                  = 22  (Fd, PtrOut, Len, Int, PtrIn, Len),
+	SYS_dup                          = 23  (Fd),
+	SYS_dup3                         = 24  (Fd, Fd, Flags),
+	SYS_fcntl64                      = 25  (Fd, Flags, Int),
+	SYS_inotify_init1                = 26  (Flags),
+	SYS_inotify_add_watch            = 27  (Fd, PtrIn, Flags),
+	SYS_inotify_rm_watch             = 28  (Fd, Int),
+	SYS_ioctl                        = 29  (Fd, Flags, Int),
+	SYS_ioprio_set                   = 30  (Int, Int, Int),
+	SYS_ioprio_get                   = 31  (Int, Int),
+	SYS_flock                        = 32  (Fd, Flags),
+	SYS_mknodat                      = 33  (Fd, PtrIn, Flags, Int),
+	SYS_mkdirat                      = 34  (Fd, PtrIn, Flags),
+	SYS_unlinkat                     = 35  (Fd, PtrIn, Flags),
+	SYS_symlinkat                    = 36  (PtrIn, Fd, PtrIn),
+	SYS_linkat                       = 37  (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_umount2                      = 39  (PtrIn, Flags),
+	SYS_mount                        = 40  (PtrIn, PtrIn, PtrIn, Flags, PtrIn),
+	SYS_pivot_root                   = 41  (PtrIn, PtrIn),
+	SYS_statfs64                     = 43  (PtrIn, Len, PtrOut),
+	SYS_fstatfs64                    = 44  (Fd, Len, PtrOut),
+	SYS_truncate64                   = 45  (PtrIn, Int64Low, Int64High),
+	SYS_ftruncate64                  = 46  (Fd, Int64Low, Int64High),
+	SYS_fallocate                    = 47  (Fd, Flags, Int64Low, Int64High, Int64Low, Int64High),
+	SYS_faccessat                    = 48  (Fd, PtrIn, Flags),
+	SYS_chdir                        = 49  (PtrIn),
+	SYS_fchdir                       = 50  (Fd),
+	SYS_chroot                       = 51  (PtrIn),
+	SYS_fchmod                       = 52  (Fd, Flags),
+	SYS_fchmodat                     = 53  (Fd, PtrIn, Flags),
+	SYS_fchownat                     = 54  (Fd, PtrIn, Int, Int, Flags),
+	SYS_fchown                       = 55  (Fd, Int, Int),
+	SYS_openat                       = 56  (Fd, PtrIn, Flags, Flags),
+	SYS_close                        = 57  (Fd),
+	SYS_vhangup                      = 58  (),
+	SYS_pipe2                        = 59  (PtrOut, Flags),
+	SYS_quotactl                     = 60  (Flags, PtrIn, Int, PtrInOut),
+	SYS_getdents64                   = 61  (Fd, PtrOut, Len),
+	SYS_llseek                       = 62  (Fd, Int64High, Int64Low, PtrOut, Int),
+	SYS_read                         = 63  (Fd, PtrOut, Len),
+	SYS_write                        = 64  (Fd, PtrIn, Len),
+	SYS_readv                        = 65  (Fd, PtrOut, Len),
+	SYS_writev                       = 66  (Fd, PtrIn, Len),
+	SYS_pread64                      = 67  (Fd, PtrOut, Len, Int64Low, Int64High),
+	SYS_pwrite64                     = 68  (Fd, PtrIn, Len, Int64Low, Int64High),
+	SYS_preadv                       = 69  (Fd, PtrOut, Len, Int64Low, Int64High),
+	SYS_pwritev                      = 70  (Fd, PtrIn, Len, Int64Low, Int64High),
+	SYS_sendfile64                   = 71  (Fd, Fd, PtrInOut, Len),
+	SYS_signalfd4                    = 74  (Fd, PtrIn, Len, Flags),
+	SYS_vmsplice                     = 75  (Fd, PtrIn, Len, Flags),
+	SYS_splice                       = 76  (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_tee                          = 77  (Fd, Fd, Len, Flags),
+	SYS_readlinkat                   = 78  (Fd, PtrIn, PtrOut, Len),
+	SYS_sync                         = 81  (),
+	SYS_fsync                        = 82  (Fd),
+	SYS_fdatasync                    = 83  (Fd),
+	SYS_sync_file_range              = 84  (Fd, Int64Low, Int64High, Int64Low, Int64High, Flags),
+	SYS_timerfd_create               = 85  (Int, Flags),
+	SYS_acct                         = 89  (PtrIn),
+	SYS_capget                       = 90  (PtrInOut, PtrOut),
+	SYS_capset                       = 91  (PtrIn, PtrIn),
+	SYS_personality                  = 92  (Flags),
+	SYS_exit                         = 93  (Int),
+	SYS_exit_group                   = 94  (Int),
+	SYS_waitid                       = 95  (Int, Int, PtrOut, Flags, PtrOut),
+	SYS_set_tid_address              = 96  (Addr),
+	SYS_unshare                      = 97  (Flags),
+	SYS_set_robust_list              = 99  (Addr, Len),
+	SYS_get_robust_list              = 100 (Int, PtrOut, PtrOut),
+	SYS_getitimer                    = 102 (Int, PtrOut),
+	SYS_setitimer                    = 103 (Int, PtrIn, PtrOut),
+	SYS_kexec_load                   = 104 (Addr, Len, PtrIn, Flags),
+	SYS_init_module                  = 105 (PtrIn, Len, PtrIn),
+	SYS_delete_module                = 106 (PtrIn, Flags),
+	SYS_timer_create                 = 107 (Int, PtrIn, PtrOut),
+	SYS_timer_getoverrun             = 109 (Int),
+	SYS_timer_delete                 = 111 (Int),
+	SYS_syslog                       = 116 (Flags, PtrOut, Len),
+	SYS_ptrace                       = 117 (Flags, Int, Addr, Int),
+	SYS_sched_setparam               = 118 (Int, PtrIn),
+	SYS_sched_setscheduler           = 119 (Int, Int, PtrIn),
+	SYS_sched_getscheduler           = 120 (Int),
+	SYS_sched_getparam               = 121 (Int, PtrOut),
+	SYS_sched_setaffinity            = 122 (Int, Len, PtrIn),
+	SYS_sched_getaffinity            = 123 (Int, Len, PtrOut),
+	SYS_sched_yield                  = 124 (),
+	SYS_sched_get_priority_max       = 125 (Int),
+	SYS_sched_get_priority_min       = 126 (Int),
+	SYS_kill                         = 129 (Int, Int),
+	SYS_tkill                        = 130 (Int, Int),
+	SYS_tgkill                       = 131 (Int, Int, Int),
+	SYS_sigaltstack                  = 132 (PtrIn, PtrOut),
+	SYS_rt_sigsuspend                = 133 (PtrIn, Len),
+	SYS_rt_sigaction                 = 134 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigprocmask               = 135 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigpending                = 136 (PtrOut, Len),
+	SYS_rt_sigqueueinfo              = 138 (Int, Int, PtrIn),
+	SYS_rt_sigreturn                 = 139 (),
+	SYS_setpriority                  = 140 (Int, Int, Int),
+	SYS_getpriority                  = 141 (Int, Int),
+	SYS_reboot                       = 142 (Int, Int, Flags, PtrIn),
+	SYS_setregid                     = 143 (Int, Int),
+	SYS_setgid                       = 144 (Int),
+	SYS_setreuid                     = 145 (Int, Int),
+	SYS_setuid                       = 146 (Int),
+	SYS_setresuid                    = 147 (Int, Int, Int),
+	SYS_getresuid                    = 148 (PtrOut, PtrOut, PtrOut),
+	SYS_setresgid                    = 149 (Int, Int, Int),
+	SYS_getresgid                    = 150 (PtrOut, PtrOut, PtrOut),
+	SYS_setfsuid                     = 151 (Int),
+	SYS_setfsgid                     = 152 (Int),
+	SYS_times                        = 153 (PtrOut),
+	SYS_setpgid                      = 154 (Int, Int),
+	SYS_getpgid                      = 155 (Int),
+	SYS_getsid                       = 156 (Int),
+	SYS_setsid                       = 157 (),
+	SYS_getgroups                    = 158 (Len, PtrOut),
+	SYS_setgroups                    = 159 (Len, PtrIn),
+	SYS_uname                        = 160 (PtrOut),
+	SYS_sethostname                  = 161 (PtrIn, Len),
+	SYS_setdomainname                = 162 (PtrIn, Len),
+	SYS_getrusage                    = 165 (Int, PtrOut),
+	SYS_umask                        = 166 (Flags),
+	SYS_prctl                        = 167 (Flags, Int, Int, Int, Int),
+	SYS_getcpu                       = 168 (PtrOut, PtrOut, PtrOut),
+	SYS_getpid                       = 172 (),
+	SYS_getppid                      = 173 (),
+	SYS_getuid                       = 174 (),
+	SYS_geteuid                      = 175 (),
+	SYS_getgid                       = 176 (),
+	SYS_getegid                      = 177 (),
+	SYS_gettid                       = 178 (),
+	SYS_sysinfo                      = 179 (PtrOut),
+	SYS_mq_open                      = 180 (PtrIn, Flags, Flags, PtrIn),
+	SYS_mq_unlink                    = 181 (PtrIn),
+	SYS_mq_notify                    = 184 (Fd, PtrIn),
+	SYS_mq_getsetattr                = 185 (Fd, PtrIn, PtrOut),
+	SYS_msgget                       = 186 (Int, Flags),
+	SYS_msgctl                       = 187 (Int, Flags, PtrInOut),
+	SYS_msgrcv                       = 188 (Int, PtrOut, Len, Int, Flags),
+	SYS_msgsnd                       = 189 (Int, PtrIn, Len, Flags),
+	SYS_semget                       = 190 (Int, Len, Flags),
+	SYS_semctl                       = 191 (Int, Int, Flags, Int),
+	SYS_semop                        = 193 (Int, PtrIn, Len),
+	SYS_shmget                       = 194 (Int, Len, Flags),
+	SYS_shmctl                       = 195 (Int, Flags, PtrInOut),
+	SYS_shmat                        = 196 (Int, Addr, Flags),
+	SYS_shmdt                        = 197 (Addr),
+	SYS_socket                       = 198 (Int, Flags, Int),
+	SYS_socketpair                   = 199 (Int, Flags, Int, PtrOut),
+	SYS_bind                         = 200 (Fd, PtrIn, Len),
+	SYS_listen                       = 201 (Fd, Int),
+	SYS_accept                       = 202 (Fd, PtrOut, PtrInOut),
+	SYS_connect                      = 203 (Fd, PtrIn, Len),
+	SYS_getsockname                  = 204 (Fd, PtrOut, PtrInOut),
+	SYS_getpeername                  = 205 (Fd, PtrOut, PtrInOut),
+	SYS_sendto                       = 206 (Fd, PtrIn, Len, Flags, PtrIn, Len),
+	SYS_recvfrom                     = 207 (Fd, PtrOut, Len, Flags, PtrOut, PtrInOut),
+	SYS_setsockopt                   = 208 (Fd, Int, Int, PtrIn, Len),
+	SYS_getsockopt                   = 209 (Fd, Int, Int, PtrOut, PtrInOut),
+	SYS_shutdown                     = 210 (Fd, Int),
+	SYS_sendmsg                      = 211 (Fd, PtrIn, Flags),
+	SYS_recvmsg                      = 212 (Fd, PtrInOut, Flags),
+	SYS_readahead                    = 213 (Fd, Int64Low, Int64High, Len),
+	SYS_brk                          = 214 (Addr),
+	SYS_munmap                       = 215 (Addr, Len),
+	SYS_mremap                       = 216 (Addr, Len, Len, Flags, Addr),
+	SYS_add_key                      = 217 (PtrIn, PtrIn, PtrIn, Len, Int),
+	SYS_request_key                  = 218 (PtrIn, PtrIn, PtrIn, Int),
+	SYS_keyctl                       = 219 (Flags, Int, Int, Int, Int),
+	SYS_clone                        = 220 (Flags, Addr, PtrOut, Addr, PtrOut),
+	SYS_execve                       = 221 (PtrIn, PtrIn, PtrIn),
+	SYS_mmap2                        = 222 (Addr, Len, Flags, Flags, Fd, Int),
+	SYS_fadvise64_64                 = 223 (Fd, Int64Low, Int64High, Int64Low, Int64High, Int),
+	SYS_swapon                       = 224 (PtrIn, Flags),
+	SYS_swapoff                      = 225 (PtrIn),
+	SYS_mprotect                     = 226 (Addr, Len, Flags),
+	SYS_msync                        = 227 (Addr, Len, Flags),
+	SYS_mlock                        = 228 (Addr, Len),
+	SYS_munlock                      = 229 (Addr, Len),
+	SYS_mlockall                     = 230 (Flags),
+	SYS_munlockall                   = 231 (),
+	SYS_mincore                      = 232 (Addr, Len, PtrOut),
+	SYS_madvise                      = 233 (Addr, Len, Flags),
+	SYS_mbind                        = 235 (Addr, Len, Flags, PtrIn, Len, Flags),
+	SYS_get_mempolicy                = 236 (PtrOut, PtrOut, Len, Addr, Flags),
+	SYS_set_mempolicy                = 237 (Flags, PtrIn, Len),
+	SYS_migrate_pages                = 238 (Int, Len, PtrIn, PtrIn),
+	SYS_move_pages                   = 239 (Int, Len, PtrIn, PtrIn, PtrOut, Flags),
+	SYS_rt_tgsigqueueinfo            = 240 (Int, Int, Int, PtrIn),
+	SYS_perf_event_open              = 241 (PtrInOut, Int, Int, Fd, Flags),
+	SYS_accept4                      = 242 (Fd, PtrOut, PtrInOut, Flags),
+	SYS_riscv_flush_icache           = 259 (Addr, Addr, Flags),
+	SYS_prlimit64                    = 261 (Int, Int, PtrIn, PtrOut),
+	SYS_fanotify_init                = 262 (Flags, Flags),
+	SYS_fanotify_mark                = 263 (Fd, Flags, Int64Low, Int64High, Fd, PtrIn),
+	SYS_name_to_handle_at            = 264 (Fd, PtrIn, PtrInOut, PtrOut, Flags),
+	SYS_open_by_handle_at            = 265 (Fd, PtrIn, Flags),
+	SYS_syncfs                       = 267 (Fd),
+	SYS_setns                        = 268 (Fd, Flags),
+	SYS_sendmmsg                     = 269 (Fd, PtrInOut, Len, Flags),
+	SYS_process_vm_readv             = 270 (Int, PtrOut, Len, PtrIn, Len, Flags),
+	SYS_process_vm_writev            = 271 (Int, PtrIn, Len, PtrIn, Len, Flags),
+	SYS_kcmp                         = 272 (Int, Int, Int, Int, Int),
+	SYS_finit_module                 = 273 (Fd, PtrIn, Flags),
+	SYS_sched_setattr                = 274 (Int, PtrIn, Flags),
+	SYS_sched_getattr                = 275 (Int, PtrOut, Len, Flags),
+	SYS_renameat2                    = 276 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_seccomp                      = 277 (Flags, Flags, PtrInOut),
+	SYS_getrandom                    = 278 (PtrOut, Len, Flags),
+	SYS_memfd_create                 = 279 (PtrIn, Flags),
+	SYS_bpf                          = 280 (Flags, PtrInOut, Len),
+	SYS_execveat                     = 281 (Fd, PtrIn, PtrIn, PtrIn, Flags),
+	SYS_userfaultfd                  = 282 (Flags),
+	SYS_membarrier                   = 283 (Flags, Flags, Int),
+	SYS_mlock2                       = 284 (Addr, Len, Flags),
+	SYS_copy_file_range              = 285 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_preadv2                      = 286 (Fd, PtrOut, Len, Int64Low, Int64High, Flags),
+	SYS_pwritev2                     = 287 (Fd, PtrIn, Len, Int64Low, Int64High, Flags),
+	SYS_pkey_mprotect                = 288 (Addr, Len, Flags, Int),
+	SYS_pkey_alloc                   = 289 (Flags, Flags),
+	SYS_pkey_free                    = 290 (Int),
+	SYS_statx                        = 291 (Fd, PtrIn, Flags, Flags, PtrOut),
+	SYS_rseq                         = 293 (PtrInOut, Len, Flags, Int),
+	SYS_kexec_file_load              = 294 (Fd, Fd, Len, PtrIn, Flags),
+	SYS_clock_gettime64              = 403 (Int, PtrOut),
+	SYS_clock_settime64              = 404 (Int, PtrIn),
+	SYS_clock_adjtime64              = 405 (Int, PtrInOut),
+	SYS_clock_getres_time64          = 406 (Int, PtrOut),
+	SYS_clock_nanosleep_time64       = 407 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime64              = 408 (Int, PtrOut),
+	SYS_timer_settime64              = 409 (Int, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime64            = 410 (Fd, PtrOut),
+	SYS_timerfd_settime64            = 411 (Fd, Flags, PtrIn, PtrOut),
+	SYS_utimensat_time64             = 412 (Fd, PtrIn, PtrIn, Flags),
+	SYS_pselect6_time64              = 413 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll_time64                 = 414 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_io_pgetevents_time64         = 416 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_recvmmsg_time64              = 417 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_mq_timedsend_time64          = 418 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive_time64       = 419 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_semtimedop_time64            = 420 (Int, PtrIn, Len, PtrIn),
+	SYS_rt_sigtimedwait_time64       = 421 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_futex_time64                 = 422 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_rr_get_interval_time64 = 423 (Int, PtrOut),
+	SYS_pidfd_send_signal            = 424 (Fd, Int, PtrIn, Flags),
+	SYS_io_uring_setup               = 425 (Len, PtrInOut),
+	SYS_io_uring_enter               = 426 (Fd, Len, Len, Flags, PtrIn, Len),
+	SYS_io_uring_register            = 427 (Fd, Flags, PtrInOut, Len),
+	SYS_open_tree                    = 428 (Fd, PtrIn, Flags),
+	SYS_move_mount                   = 429 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_fsopen                       = 430 (PtrIn, Flags),
+	SYS_fsconfig                     = 431 (Fd, Flags, PtrIn, PtrIn, Int),
+	SYS_fsmount                      = 432 (Fd, Flags, Flags),
+	SYS_fspick                       = 433 (Fd, PtrIn, Flags),
+	SYS_pidfd_open                   = 434 (Int, Flags),
+	SYS_clone3                       = 435 (PtrIn, Len),
+	SYS_close_range                  = 436 (Fd, Fd, Flags),
+	SYS_openat2                      = 437 (Fd, PtrIn, PtrIn, Len),
+	SYS_pidfd_getfd                  = 438 (Fd, Fd, Flags),
+	SYS_faccessat2                   = 439 (Fd, PtrIn, Flags, Flags),
+	SYS_process_madvise              = 440 (Fd, PtrIn, Len, Flags, Flags),
+	SYS_epoll_pwait2                 = 441 (Fd, PtrOut, Len, PtrIn, PtrIn, Len),
+	SYS_mount_setattr                = 442 (Fd, PtrIn, Flags, PtrIn, Len),
+	SYS_quotactl_fd                  = 443 (Fd, Flags, Int, PtrInOut),
+	SYS_landlock_create_ruleset      = 444 (PtrIn, Len, Flags),
+	SYS_landlock_add_rule            = 445 (Fd, Int, PtrIn, Flags),
+	SYS_landlock_restrict_self       = 446 (Fd, Flags),
+	SYS_memfd_secret                 = 447 (Flags),
+	SYS_process_mrelease             = 448 (Fd, Flags),
+	SYS_futex_waitv                  = 449 (PtrIn, Len, Flags, PtrIn, Int),
+	SYS_set_mempolicy_home_node      = 450 (Addr, Len, Int, Flags),
+}
@@ -38,97 +38,173 @@ impl Result {
 single_register_result64!(Result);
 
 #[cfg(not(doc))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! syscall {
-	($nr:expr $(,)?) => {{
+macro_rules! __syscall_with_options {
+	([$($opt:ident),*] $nr:expr $(,)?) => {{
 		let mut out_a0;
 		core::arch::asm!(
 			"ecall",
-			in("a7") u32::from(Into::<$crate::Syscall>::into($nr)),
+			in("a7") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
 			lateout("a0") out_a0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::riscv64::Result::new(out_a0)
 	}};
-	($nr:expr, $a1:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr $(,)?) => {{
 		let mut out_a0;
 		core::arch::asm!(
 			"ecall",
-			in("a7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("a0") $a1,
+			in("a7") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
 			lateout("a0") out_a0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::riscv64::Result::new(out_a0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr $(,)?) => {{
 		let mut out_a0;
 		core::arch::asm!(
 			"ecall",
-			in("a7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("a0") $a1,
-			in("a1") $a2,
+			in("a7") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
 			lateout("a0") out_a0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::riscv64::Result::new(out_a0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
 		let mut out_a0;
 		core::arch::asm!(
 			"ecall",
-			in("a7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("a0") $a1,
-			in("a1") $a2,
-			in("a2") $a3,
+			in("a7") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
 			lateout("a0") out_a0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::riscv64::Result::new(out_a0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
 		let mut out_a0;
 		core::arch::asm!(
 			"ecall",
-			in("a7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("a0") $a1,
-			in("a1") $a2,
-			in("a2") $a3,
-			in("a3") $a4,
+			in("a7") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
 			lateout("a0") out_a0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::riscv64::Result::new(out_a0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
 		let mut out_a0;
 		core::arch::asm!(
 			"ecall",
-			in("a7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("a0") $a1,
-			in("a1") $a2,
-			in("a2") $a3,
-			in("a3") $a4,
-			in("a4") $a5,
+			in("a7") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
 			lateout("a0") out_a0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::riscv64::Result::new(out_a0)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
 		let mut out_a0;
 		core::arch::asm!(
 			"ecall",
-			in("a7") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("a0") $a1,
-			in("a1") $a2,
-			in("a2") $a3,
-			in("a3") $a4,
-			in("a4") $a5,
-			in("a5") $a6,
+			in("a7") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("a5") $crate::IntoSyscallArg::into_syscall_arg($a6),
 			lateout("a0") out_a0,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::riscv64::Result::new(out_a0)
 	}};
 }
+
+#[cfg(not(doc))]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($nr:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		core::arch::asm!(
+			"ecall",
+			in("a7") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("a0") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("a1") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("a2") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("a3") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("a4") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("a5") $crate::IntoSyscallArg::into_syscall_arg($a6),
+			options(noreturn, nostack),
+		)
+	}};
+}
@@ -0,0 +1,371 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+#![allow(non_upper_case_globals)]
+
+syscall_constants! {
+	SYS_exit                    = 1   (Int),
+	SYS_fork                    = 2   (),
+	SYS_read                    = 3   (Fd, PtrOut, Len),
+	SYS_write                   = 4   (Fd, PtrIn, Len),
+	SYS_open                    = 5   (PtrIn, Flags, Flags),
+	SYS_close                   = 6   (Fd),
+	SYS_creat                   = 8   (PtrIn, Flags),
+	SYS_link                    = 9   (PtrIn, PtrIn),
+	SYS_unlink                  = 10  (PtrIn),
+	SYS_execve                  = 11  (PtrIn, PtrIn, PtrIn),
+	SYS_chdir                   = 12  (PtrIn),
+	SYS_mknod                   = 14  (PtrIn, Flags, Int),
+	SYS_chmod                   = 15  (PtrIn, Flags),
+	SYS_lseek                   = 19  (Fd, Int64, Int),
+	SYS_getpid                  = 20  (),
+	SYS_mount                   = 21  (PtrIn, PtrIn, PtrIn, Flags, PtrIn),
+	SYS_umount                  = 22  (PtrIn),
+	SYS_ptrace                  = 26  (Flags, Int, Addr, Int),
+	SYS_alarm                   = 27  (Int),
+	SYS_pause                   = 29  (),
+	SYS_utime                   = 30  (PtrIn, PtrIn),
+	SYS_access                  = 33  (PtrIn, Flags),
+	SYS_nice                    = 34  (Int),
+	SYS_sync                    = 36  (),
+	SYS_kill                    = 37  (Int, Int),
+	SYS_rename                  = 38  (PtrIn, PtrIn),
+	SYS_mkdir                   = 39  (PtrIn, Flags),
+	SYS_rmdir                   = 40  (PtrIn),
+	SYS_dup                     = 41  (Fd),
+	SYS_pipe                    = 42  (PtrOut),
+	SYS_times                   = 43  (PtrOut),
+	SYS_brk                     = 45  (Addr),
+	SYS_signal                  = 48  (Int, Addr),
+	SYS_acct                    = 51  (PtrIn),
+	SYS_umount2                 = 52  (PtrIn, Flags),
+	SYS_ioctl                   = 54  (Fd, Flags, Int),
+	SYS_fcntl                   = 55  (Fd, Flags, Int),
+	SYS_setpgid                 = 57  (Int, Int),
+	SYS_umask                   = 60  (Flags),
+	SYS_chroot                  = 61  (PtrIn),
+	SYS_ustat                   = 62  (Int, PtrOut),
+	SYS_dup2                    = 63  (Fd, Fd),
+	SYS_getppid                 = 64  (),
+	SYS_getpgrp                 = 65  (),
+	SYS_setsid                  = 66  (),
+	SYS_sigaction               = 67  (Int, PtrIn, PtrOut),
+	SYS_sigsuspend              = 72  (Int, Int, Flags),
+	SYS_sigpending              = 73  (PtrOut),
+	SYS_sethostname             = 74  (PtrIn, Len),
+	SYS_setrlimit               = 75  (Int, PtrIn),
+	SYS_getrusage               = 77  (Int, PtrOut),
+	SYS_gettimeofday            = 78  (PtrOut, PtrOut),
+	SYS_settimeofday            = 79  (PtrIn, PtrIn),
+	SYS_symlink                 = 83  (PtrIn, PtrIn),
+	SYS_readlink                = 85  (PtrIn, PtrOut, Len),
+	SYS_swapon                  = 87  (PtrIn, Flags),
+	SYS_reboot                  = 88  (Int, Int, Flags, PtrIn),
+	SYS_mmap                    = 90  (PtrIn),
+	SYS_munmap                  = 91  (Addr, Len),
+	SYS_truncate                = 92  (PtrIn, Int64),
+	SYS_ftruncate               = 93  (Fd, Int64),
+	SYS_fchmod                  = 94  (Fd, Flags),
+	SYS_getpriority             = 96  (Int, Int),
+	SYS_setpriority             = 97  (Int, Int, Int),
+	SYS_statfs                  = 99  (PtrIn, PtrOut),
+	SYS_fstatfs                 = 100 (Fd, PtrOut),
+	SYS_socketcall              = 102 (Flags, PtrIn),
+	SYS_syslog                  = 103 (Flags, PtrOut, Len),
+	SYS_setitimer               = 104 (Int, PtrIn, PtrOut),
+	SYS_getitimer               = 105 (Int, PtrOut),
+	SYS_stat                    = 106 (PtrIn, PtrOut),
+	SYS_lstat                   = 107 (PtrIn, PtrOut),
+	SYS_fstat                   = 108 (Fd, PtrOut),
+	SYS_lookup_dcookie          = 110 (Int64, PtrOut, Len),
+	SYS_vhangup                 = 111 (),
+	SYS_wait4                   = 114 (Int, PtrOut, Flags, PtrOut),
+	SYS_swapoff                 = 115 (PtrIn),
+	SYS_sysinfo                 = 116 (PtrOut),
+	SYS_ipc                     = 117 (Flags, Int, Int, Int, PtrInOut, Int),
+	SYS_fsync                   = 118 (Fd),
+	SYS_sigreturn               = 119 (),
+	SYS_clone                   = 120 (Addr, Flags, PtrOut, PtrOut, Addr),
+	SYS_setdomainname           = 121 (PtrIn, Len),
+	SYS_uname                   = 122 (PtrOut),
+	SYS_adjtimex                = 124 (PtrInOut),
+	SYS_mprotect                = 125 (Addr, Len, Flags),
+	SYS_sigprocmask             = 126 (Int, PtrIn, PtrOut),
+	SYS_init_module             = 128 (PtrIn, Len, PtrIn),
+	SYS_delete_module           = 129 (PtrIn, Flags),
+	SYS_quotactl                = 131 (Flags, PtrIn, Int, PtrInOut),
+	SYS_getpgid                 = 132 (Int),
+	SYS_fchdir                  = 133 (Fd),
+	SYS_personality             = 136 (Flags),
+	SYS_getdents                = 141 (Fd, PtrOut, Len),
+	SYS_select                  = 142 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut),
+	SYS_flock                   = 143 (Fd, Flags),
+	SYS_msync                   = 144 (Addr, Len, Flags),
+	SYS_readv                   = 145 (Fd, PtrOut, Len),
+	SYS_writev                  = 146 (Fd, PtrIn, Len),
+	SYS_getsid                  = 147 (Int),
+	SYS_fdatasync               = 148 (Fd),
+	SYS_mlock                   = 150 (Addr, Len),
+	SYS_munlock                 = 151 (Addr, Len),
+	SYS_mlockall                = 152 (Flags),
+	SYS_munlockall              = 153 (),
+	SYS_sched_setparam          = 154 (Int, PtrIn),
+	SYS_sched_getparam          = 155 (Int, PtrOut),
+	SYS_sched_setscheduler      = 156 (Int, Int, PtrIn),
+	SYS_sched_getscheduler      = 157 (Int),
+	SYS_sched_yield             = 158 (),
+	SYS_sched_get_priority_max  = 159 (Int),
+	SYS_sched_get_priority_min  = 160 (Int),
+	SYS_sched_rr_get_interval   = 161 (Int, PtrOut),
+	SYS_nanosleep               = 162 (PtrIn, PtrOut),
+	SYS_mremap                  = 163 (Addr, Len, Len, Flags, Addr),
+	SYS_poll                    = 168 (PtrInOut, Len, Int),
+	SYS_prctl                   = 172 (Flags, Int, Int, Int, Int),
+	SYS_rt_sigreturn            = 173 (),
+	SYS_rt_sigaction            = 174 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigprocmask          = 175 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigpending           = 176 (PtrOut, Len),
+	SYS_rt_sigtimedwait         = 177 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_rt_sigqueueinfo         = 178 (Int, Int, PtrIn),
+	SYS_rt_sigsuspend           = 179 (PtrIn, Len),
+	SYS_pread64                 = 180 (Fd, PtrOut, Len, Int64),
+	SYS_pwrite64                = 181 (Fd, PtrIn, Len, Int64),
+	SYS_getcwd                  = 183 (PtrOut, Len),
+	SYS_capget                  = 184 (PtrInOut, PtrOut),
+	SYS_capset                  = 185 (PtrIn, PtrIn),
+	SYS_sigaltstack             = 186 (PtrIn, PtrOut),
+	SYS_sendfile                = 187 (Fd, Fd, PtrInOut, Len),
+	SYS_vfork                   = 190 (),
+	SYS_getrlimit               = 191 (Int, PtrOut),
+	SYS_lchown                  = 198 (PtrIn, Int, Int),
+	SYS_getuid                  = 199 (),
+	SYS_getgid                  = 200 (),
+	SYS_geteuid                 = 201 (),
+	SYS_getegid                 = 202 (),
+	SYS_setreuid                = 203 (Int, Int),
+	SYS_setregid                = 204 (Int, Int),
+	SYS_getgroups               = 205 (Len, PtrOut),
+	SYS_setgroups               = 206 (Len, PtrIn),
+	SYS_fchown                  = 207 (Fd, Int, Int),
+	SYS_setresuid               = 208 (Int, Int, Int),
+	SYS_getresuid               = 209 (PtrOut, PtrOut, PtrOut),
+	SYS_setresgid               = 210 (Int, Int, Int),
+	SYS_getresgid               = 211 (PtrOut, PtrOut, PtrOut),
+	SYS_chown                   = 212 (PtrIn, Int, Int),
+	SYS_setuid                  = 213 (Int),
+	SYS_setgid                  = 214 (Int),
+	SYS_setfsuid                = 215 (Int),
+	SYS_setfsgid                = 216 (Int),
+	SYS_pivot_root              = 217 (PtrIn, PtrIn),
+	SYS_mincore                 = 218 (Addr, Len, PtrOut),
+	SYS_madvise                 = 219 (Addr, Len, Flags),
+	SYS_getdents64              = 220 (Fd, PtrOut, Len),
+	SYS_readahead               = 222 (Fd, Int64, Len),
+	SYS_setxattr                = 224 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_lsetxattr               = 225 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_fsetxattr               = 226 (Fd, PtrIn, PtrIn, Len, Flags),
+	SYS_getxattr                = 227 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_lgetxattr               = 228 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_fgetxattr               = 229 (Fd, PtrIn, PtrOut, Len),
+	SYS_listxattr               = 230 (PtrIn, PtrOut, Len),
+	SYS_llistxattr              = 231 (PtrIn, PtrOut, Len),
+	SYS_flistxattr              = 232 (Fd, PtrOut, Len),
+	SYS_removexattr             = 233 (PtrIn, PtrIn),
+	SYS_lremovexattr            = 234 (PtrIn, PtrIn),
+	SYS_fremovexattr            = 235 (Fd, PtrIn),
+	SYS_gettid                  = 236 (),
+	SYS_tkill                   = 237 (Int, Int),
+	SYS_futex                   = 238 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_setaffinity       = 239 (Int, Len, PtrIn),
+	SYS_sched_getaffinity       = 240 (Int, Len, PtrOut),
+	SYS_tgkill                  = 241 (Int, Int, Int),
+	SYS_io_setup                = 243 (Len, PtrOut),
+	SYS_io_destroy              = 244 (Int),
+	SYS_io_getevents            = 245 (Int, Len, Len, PtrOut, PtrIn),
+	SYS_io_submit               = 246 (Int, Len, PtrIn),
+	SYS_io_cancel               = 247 (Int, PtrIn, PtrOut),
+	SYS_exit_group              = 248 (Int),
+	SYS_epoll_create            = 249 (Int),
+	SYS_epoll_ctl               = 250 (Fd, Flags, Fd, PtrIn),
+	SYS_epoll_wait              = 251 (Fd, PtrOut, Len, Int),
+	SYS_set_tid_address         = 252 (Addr),
+	SYS_fadvise64               = 253 (Fd, Int64, Len, Int),
+	SYS_timer_create            = 254 (Int, PtrIn, PtrOut),
+	SYS_timer_settime           = 255 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime           = 256 (Int, PtrOut),
+	SYS_timer_getoverrun        = 257 (Int),
+	SYS_timer_delete            = 258 (Int),
+	SYS_clock_settime           = 259 (Int, PtrIn),
+	SYS_clock_gettime           = 260 (Int, PtrOut),
+	SYS_clock_getres            = 261 (Int, PtrOut),
+	SYS_clock_nanosleep         = 262 (Int, Flags, PtrIn, PtrOut),
+	SYS_statfs64                = 265 (PtrIn, Len, PtrOut),
+	SYS_fstatfs64               = 266 (Fd, Len, PtrOut),
+	SYS_mbind                   = 268 (Addr, Len, Flags, PtrIn, Len, Flags),
+	SYS_get_mempolicy           = 269 (PtrOut, PtrOut, Len, Addr, Flags),
+	SYS_set_mempolicy           = 270 (Flags, PtrIn, Len),
+	SYS_mq_open                 = 271 (PtrIn, Flags, Flags, PtrIn),
+	SYS_mq_unlink               = 272 (PtrIn),
+	SYS_mq_timedsend            = 273 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive         = 274 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_mq_notify               = 275 (Fd, PtrIn),
+	SYS_mq_getsetattr           = 276 (Fd, PtrIn, PtrOut),
+	SYS_kexec_load              = 277 (Addr, Len, PtrIn, Flags),
+	SYS_add_key                 = 278 (PtrIn, PtrIn, PtrIn, Len, Int),
+	SYS_request_key             = 279 (PtrIn, PtrIn, PtrIn, Int),
+	SYS_keyctl                  = 280 (Flags, Int, Int, Int, Int),
+	SYS_waitid                  = 281 (Int, Int, PtrOut, Flags, PtrOut),
+	SYS_ioprio_set              = 282 (Int, Int, Int),
+	SYS_ioprio_get              = 283 (Int, Int),
+	SYS_inotify_init            = 284 (),
+	SYS_inotify_add_watch       = 285 (Fd, PtrIn, Flags),
+	SYS_inotify_rm_watch        = 286 (Fd, Int),
+	SYS_migrate_pages           = 287 (Int, Len, PtrIn, PtrIn),
+	SYS_openat                  = 288 (Fd, PtrIn, Flags, Flags),
+	SYS_mkdirat                 = 289 (Fd, PtrIn, Flags),
+	SYS_mknodat                 = 290 (Fd, PtrIn, Flags, Int),
+	SYS_fchownat                = 291 (Fd, PtrIn, Int, Int, Flags),
+	SYS_futimesat               = 292 (Fd, PtrIn, PtrIn),
+	SYS_newfstatat              = 293 (Fd, PtrIn, PtrOut, Flags),
+	SYS_unlinkat                = 294 (Fd, PtrIn, Flags),
+	SYS_renameat                = 295 (Fd, PtrIn, Fd, PtrIn),
+	SYS_linkat                  = 296 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_symlinkat               = 297 (PtrIn, Fd, PtrIn),
+	SYS_readlinkat              = 298 (Fd, PtrIn, PtrOut, Len),
+	SYS_fchmodat                = 299 (Fd, PtrIn, Flags),
+	SYS_faccessat               = 300 (Fd, PtrIn, Flags),
+	SYS_pselect6                = 301 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll                   = 302 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_unshare                 = 303 (Flags),
+	SYS_set_robust_list         = 304 (Addr, Len),
+	SYS_get_robust_list         = 305 (Int, PtrOut, PtrOut),
+	SYS_splice                  = 306 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_sync_file_range         = 307 (Fd, Int64, Int64, Flags),
+	SYS_tee                     = 308 (Fd, Fd, Len, Flags),
+	SYS_vmsplice                = 309 (Fd, PtrIn, Len, Flags),
+	SYS_move_pages              = 310 (Int, Len, PtrIn, PtrIn, PtrOut, Flags),
+	SYS_getcpu                  = 311 (PtrOut, PtrOut, PtrOut),
+	SYS_epoll_pwait             = 312 (Fd, PtrOut, Len, Int, PtrIn, Len),
+	SYS_utimes                  = 313 (PtrIn, PtrIn),
+	SYS_fallocate               = 314 (Fd, Flags, Int64, Int64),
+	SYS_utimensat               = 315 (Fd, PtrIn, PtrIn, Flags),
+	SYS_signalfd                = 316 (Fd, PtrIn, Len),
+	SYS_eventfd                 = 318 (Int),
+	SYS_timerfd_create          = 319 (Int, Flags),
+	SYS_timerfd_settime         = 320 (Fd, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime         = 321 (Fd, PtrOut),
+	SYS_signalfd4               = 322 (Fd, PtrIn, Len, Flags),
+	SYS_eventfd2                = 323 (Int, Flags),
+	SYS_inotify_init1           = 324 (Flags),
+	SYS_pipe2                   = 325 (PtrOut, Flags),
+	SYS_dup3                    = 326 (Fd, Fd, Flags),
+	SYS_epoll_create1           = 327 (Flags),
+	SYS_preadv                  = 328 (Fd, PtrOut, Len, Int64, Int),
+	SYS_pwritev                 = 329 (Fd, PtrIn, Len, Int64, Int),
+	SYS_rt_tgsigqueueinfo       = 330 (Int, Int, Int, PtrIn),
+	SYS_perf_event_open         = 331 (PtrInOut, Int, Int, Fd, Flags),
+	SYS_fanotify_init           = 332 (Flags, Flags),
+	SYS_fanotify_mark           = 333 (Fd, Flags, Int64, Fd, PtrIn),
+	SYS_prlimit64               = 334 (Int, Int, PtrIn, PtrOut),
+	SYS_name_to_handle_at       = 335 (Fd, PtrIn, PtrInOut, PtrOut, Flags),
+	SYS_open_by_handle_at       = 336 (Fd, PtrIn, Flags),
+	SYS_clock_adjtime           = 337 (Int, PtrInOut),
+	SYS_syncfs                  = 338 (Fd),
+	SYS_setns                   = 339 (Fd, Flags),
+	SYS_process_vm_readv        = 340 (Int, PtrOut, Len, PtrIn, Len, Flags),
+	SYS_process_vm_writev       = 341 (Int, PtrIn, Len, PtrIn, Len, Flags),
+	SYS_s390_runtime_instr      = 342 (Flags, Int),
+	SYS_kcmp                    = 343 (Int, Int, Int, Int, Int),
+	SYS_finit_module            = 344 (Fd, PtrIn, Flags),
+	SYS_sched_setattr           = 345 (Int, PtrIn, Flags),
+	SYS_sched_getattr           = 346 (Int, PtrOut, Len, Flags),
+	SYS_renameat2               = 347 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_seccomp                 = 348 (Flags, Flags, PtrInOut),
+	SYS_getrandom               = 349 (PtrOut, Len, Flags),
+	SYS_memfd_create            = 350 (PtrIn, Flags),
+	SYS_bpf                     = 351 (Flags, PtrInOut, Len),
+	SYS_s390_pci_mmio_write     = 352 (Addr, PtrIn, Len),
+	SYS_s390_pci_mmio_read      = 353 (Addr, PtrOut, Len),
+	SYS_execveat                = 354 (Fd, PtrIn, PtrIn, PtrIn, Flags),
+	SYS_userfaultfd             = 355 (Flags),
+	SYS_membarrier              = 356 (Flags, Flags, Int),
+	SYS_recvmmsg                = 357 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_sendmmsg                = 358 (Fd, PtrInOut, Len, Flags),
+	SYS_socket                  = 359 (Int, Flags, Int),
+	SYS_socketpair              = 360 (Int, Flags, Int, PtrOut),
+	SYS_bind                    = 361 (Fd, PtrIn, Len),
+	SYS_connect                 = 362 (Fd, PtrIn, Len),
+	SYS_listen                  = 363 (Fd, Int),
+	SYS_accept4                 = 364 (Fd, PtrOut, PtrInOut, Flags),
+	SYS_getsockopt              = 365 (Fd, Int, Int, PtrOut, PtrInOut),
+	SYS_setsockopt              = 366 (Fd, Int, Int, PtrIn, Len),
+	SYS_getsockname             = 367 (Fd, PtrOut, PtrInOut),
+	SYS_getpeername             = 368 (Fd, PtrOut, PtrInOut),
+	SYS_sendto                  = 369 (Fd, PtrIn, Len, Flags, PtrIn, Len),
+	SYS_sendmsg                 = 370 (Fd, PtrIn, Flags),
+	SYS_recvfrom                = 371 (Fd, PtrOut, Len, Flags, PtrOut, PtrInOut),
+	SYS_recvmsg                 = 372 (Fd, PtrInOut, Flags),
+	SYS_shutdown                = 373 (Fd, Int),
+	SYS_mlock2                  = 374 (Addr, Len, Flags),
+	SYS_copy_file_range         = 375 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_preadv2                 = 376 (Fd, PtrOut, Len, Int64, Int, Flags),
+	SYS_pwritev2                = 377 (Fd, PtrIn, Len, Int64, Int, Flags),
+	SYS_s390_guarded_storage    = 378 (Flags, PtrInOut),
+	SYS_statx                   = 379 (Fd, PtrIn, Flags, Flags, PtrOut),
+	SYS_s390_sthyi              = 380 (Int, PtrOut, PtrOut, Flags),
+	SYS_kexec_file_load         = 381 (Fd, Fd, Len, PtrIn, Flags),
+	SYS_io_pgetevents           = 382 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_rseq                    = 383 (PtrInOut, Len, Flags, Int),
+	SYS_pkey_mprotect           = 384 (Addr, Len, Flags, Int),
+	SYS_pkey_alloc              = 385 (Flags, Flags),
+	SYS_pkey_free               = 386 (Int),
+	SYS_semget                  = 393 (Int, Len, Flags),
+	SYS_semctl                  = 394 (Int, Int, Flags, Int),
+	SYS_shmget                  = 395 (Int, Len, Flags),
+	SYS_shmctl                  = 396 (Int, Flags, PtrInOut),
+	SYS_shmat                   = 397 (Int, Addr, Flags),
+	SYS_shmdt                   = 398 (Addr),
+	SYS_msgget                  = 399 (Int, Flags),
+	SYS_msgsnd                  = 400 (Int, PtrIn, Len, Flags),
+	SYS_msgrcv                  = 401 (Int, PtrOut, Len, Int, Flags),
+	SYS_msgctl                  = 402 (Int, Flags, PtrInOut),
+	SYS_pidfd_send_signal       = 424 (Fd, Int, PtrIn, Flags),
+	SYS_io_uring_setup          = 425 (Len, PtrInOut),
+	SYS_io_uring_enter          = 426 (Fd, Len, Len, Flags, PtrIn, Len),
+	SYS_io_uring_register       = 427 (Fd, Flags, PtrInOut, Len),
+	SYS_open_tree               = 428 (Fd, PtrIn, Flags),
+	SYS_move_mount              = 429 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_fsopen                  = 430 (PtrIn, Flags),
+	SYS_fsconfig                = 431 (Fd, Flags, PtrIn, PtrIn, Int),
+	SYS_fsmount                 = 432 (Fd, Flags, Flags),
+	SYS_fspick                  = 433 (Fd, PtrIn, Flags),
+	SYS_pidfd_open              = 434 (Int, Flags),
+	SYS_clone3                  = 435 (PtrIn, Len),
+	SYS_close_range             = 436 (Fd, Fd, Flags),
+	SYS_openat2                 = 437 (Fd, PtrIn, PtrIn, Len),
+	SYS_pidfd_getfd             = 438 (Fd, Fd, Flags),
+	SYS_faccessat2              = 439 (Fd, PtrIn, Flags, Flags),
+	SYS_process_madvise         = 440 (Fd, PtrIn, Len, Flags, Flags),
+	SYS_epoll_pwait2            = 441 (Fd, PtrOut, Len, PtrIn, PtrIn, Len),
+	SYS_mount_setattr           = 442 (Fd, PtrIn, Flags, PtrIn, Len),
+	SYS_quotactl_fd             = 443 (Fd, Flags, Int, PtrInOut),
+	SYS_landlock_create_ruleset = 444 (PtrIn, Len, Flags),
+	SYS_landlock_add_rule       = 445 (Fd, Int, PtrIn, Flags),
+	SYS_landlock_restrict_self  = 446 (Fd, Flags),
+	SYS_memfd_secret            = 447 (Flags),
+	SYS_process_mrelease        = 448 (Fd, Flags),
+	SYS_futex_waitv             = 449 (PtrIn, Len, Flags, PtrIn, Int),
+	SYS_set_mempolicy_home_node = 450 (Addr, Len, Int, Flags),
+}
@@ -0,0 +1,226 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+/// Linux syscall result for the `x86_64` architecture (`x32` ABI).
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[must_use]
+pub struct Result(u64);
+
+impl Result {
+	#[inline]
+	pub const fn new(register_rax: u64) -> Self {
+		Self(register_rax)
+	}
+
+	#[inline]
+	pub const fn as_u64_unchecked(self) -> u64 {
+		self.0
+	}
+
+	#[inline]
+	pub const fn as_usize_unchecked(self) -> usize {
+		self.0 as usize
+	}
+}
+
+single_register_result64!(Result);
+
+// The `x32` ABI passes syscall arguments in 64-bit registers, so arguments are
+// converted to `u64` (see `ArgRegister`) before being passed to the kernel.
+#[cfg(not(doc))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_with_options {
+	([$($opt:ident),*] $nr:expr $(,)?) => {{
+		let mut out_rax;
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			out("rcx") _,
+			out("r11") _,
+			lateout("rax") out_rax,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::x32::Result::new(out_rax)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr $(,)?) => {{
+		let mut out_rax;
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			out("rcx") _,
+			out("r11") _,
+			lateout("rax") out_rax,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::x32::Result::new(out_rax)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		let mut out_rax;
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			out("rcx") _,
+			out("r11") _,
+			lateout("rax") out_rax,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::x32::Result::new(out_rax)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		let mut out_rax;
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			out("rcx") _,
+			out("r11") _,
+			lateout("rax") out_rax,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::x32::Result::new(out_rax)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		let mut out_rax;
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			out("rcx") _,
+			out("r11") _,
+			lateout("rax") out_rax,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::x32::Result::new(out_rax)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		let mut out_rax;
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r8")  $crate::IntoSyscallArg::into_syscall_arg($a5),
+			out("rcx") _,
+			out("r11") _,
+			lateout("rax") out_rax,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::x32::Result::new(out_rax)
+	}};
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		let mut out_rax;
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r8")  $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("r9")  $crate::IntoSyscallArg::into_syscall_arg($a6),
+			out("rcx") _,
+			out("r11") _,
+			lateout("rax") out_rax,
+			options(nostack, preserves_flags, $($opt),*),
+		);
+		$crate::arch::x32::Result::new(out_rax)
+	}};
+}
+
+#[cfg(not(doc))]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($nr:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r8")  $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r8")  $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("r9")  $crate::IntoSyscallArg::into_syscall_arg($a6),
+			options(noreturn, nostack),
+		)
+	}};
+}
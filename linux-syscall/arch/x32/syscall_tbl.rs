@@ -0,0 +1,365 @@
+// Copyright (c) 2022 John Millikin <john@john-millikin.com>
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+// REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+// AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+// INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+// LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+// OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+// PERFORMANCE OF THIS SOFTWARE.
+//
+// SPDX-License-Identifier: 0BSD
+
+#![allow(non_upper_case_globals)]
+
+/// Bit set in the syscall number of every `x32` syscall.
+pub const X32_SYSCALL_BIT: u32 = 0x40000000;
+
+syscall_constants! {
+	SYS_read                    = 0x40000000 (Fd, PtrOut, Len),
+	SYS_write                   = 0x40000001 (Fd, PtrIn, Len),
+	SYS_open                    = 0x40000002 (PtrIn, Flags, Flags),
+	SYS_close                   = 0x40000003 (Fd),
+	SYS_stat                    = 0x40000004 (PtrIn, PtrOut),
+	SYS_fstat                   = 0x40000005 (Fd, PtrOut),
+	SYS_lstat                   = 0x40000006 (PtrIn, PtrOut),
+	SYS_poll                    = 0x40000007 (PtrInOut, Len, Int),
+	SYS_lseek                   = 0x40000008 (Fd, Int64, Int),
+	SYS_mmap                    = 0x40000009 (Addr, Len, Flags, Flags, Fd, Int64),
+	SYS_mprotect                = 0x4000000A (Addr, Len, Flags),
+	SYS_munmap                  = 0x4000000B (Addr, Len),
+	SYS_brk                     = 0x4000000C (Addr),
+	SYS_rt_sigprocmask          = 0x4000000E (Int, PtrIn, PtrOut, Len),
+	SYS_pread64                 = 0x40000011 (Fd, PtrOut, Len, Int64),
+	SYS_pwrite64                = 0x40000012 (Fd, PtrIn, Len, Int64),
+	SYS_access                  = 0x40000015 (PtrIn, Flags),
+	SYS_pipe                    = 0x40000016 (PtrOut),
+	SYS_select                  = 0x40000017 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut),
+	SYS_sched_yield             = 0x40000018 (),
+	SYS_mremap                  = 0x40000019 (Addr, Len, Len, Flags, Addr),
+	SYS_msync                   = 0x4000001A (Addr, Len, Flags),
+	SYS_mincore                 = 0x4000001B (Addr, Len, PtrOut),
+	SYS_madvise                 = 0x4000001C (Addr, Len, Flags),
+	SYS_shmget                  = 0x4000001D (Int, Len, Flags),
+	SYS_shmat                   = 0x4000001E (Int, Addr, Flags),
+	SYS_shmctl                  = 0x4000001F (Int, Flags, PtrInOut),
+	SYS_dup                     = 0x40000020 (Fd),
+	SYS_dup2                    = 0x40000021 (Fd, Fd),
+	SYS_pause                   = 0x40000022 (),
+	SYS_nanosleep               = 0x40000023 (PtrIn, PtrOut),
+	SYS_getitimer               = 0x40000024 (Int, PtrOut),
+	SYS_alarm                   = 0x40000025 (Int),
+	SYS_setitimer               = 0x40000026 (Int, PtrIn, PtrOut),
+	SYS_getpid                  = 0x40000027 (),
+	SYS_sendfile                = 0x40000028 (Fd, Fd, PtrInOut, Len),
+	SYS_socket                  = 0x40000029 (Int, Flags, Int),
+	SYS_connect                 = 0x4000002A (Fd, PtrIn, Len),
+	SYS_accept                  = 0x4000002B (Fd, PtrOut, PtrInOut),
+	SYS_sendto                  = 0x4000002C (Fd, PtrIn, Len, Flags, PtrIn, Len),
+	SYS_shutdown                = 0x40000030 (Fd, Int),
+	SYS_bind                    = 0x40000031 (Fd, PtrIn, Len),
+	SYS_listen                  = 0x40000032 (Fd, Int),
+	SYS_getsockname             = 0x40000033 (Fd, PtrOut, PtrInOut),
+	SYS_getpeername             = 0x40000034 (Fd, PtrOut, PtrInOut),
+	SYS_socketpair              = 0x40000035 (Int, Flags, Int, PtrOut),
+	SYS_clone                   = 0x40000038 (Flags, Addr, PtrOut, PtrOut, Addr),
+	SYS_fork                    = 0x40000039 (),
+	SYS_vfork                   = 0x4000003A (),
+	SYS_exit                    = 0x4000003C (Int),
+	SYS_wait4                   = 0x4000003D (Int, PtrOut, Flags, PtrOut),
+	SYS_kill                    = 0x4000003E (Int, Int),
+	SYS_uname                   = 0x4000003F (PtrOut),
+	SYS_semget                  = 0x40000040 (Int, Len, Flags),
+	SYS_semop                   = 0x40000041 (Int, PtrIn, Len),
+	SYS_semctl                  = 0x40000042 (Int, Int, Flags, Int),
+	SYS_shmdt                   = 0x40000043 (Addr),
+	SYS_msgget                  = 0x40000044 (Int, Flags),
+	SYS_msgsnd                  = 0x40000045 (Int, PtrIn, Len, Flags),
+	SYS_msgrcv                  = 0x40000046 (Int, PtrOut, Len, Int, Flags),
+	SYS_msgctl                  = 0x40000047 (Int, Flags, PtrInOut),
+	SYS_fcntl                   = 0x40000048 (Fd, Flags, Int),
+	SYS_flock                   = 0x40000049 (Fd, Flags),
+	SYS_fsync                   = 0x4000004A (Fd),
+	SYS_fdatasync               = 0x4000004B (Fd),
+	SYS_truncate                = 0x4000004C (PtrIn, Int64),
+	SYS_ftruncate               = 0x4000004D (Fd, Int64),
+	SYS_getdents                = 0x4000004E (Fd, PtrOut, Len),
+	SYS_getcwd                  = 0x4000004F (PtrOut, Len),
+	SYS_chdir                   = 0x40000050 (PtrIn),
+	SYS_fchdir                  = 0x40000051 (Fd),
+	SYS_rename                  = 0x40000052 (PtrIn, PtrIn),
+	SYS_mkdir                   = 0x40000053 (PtrIn, Flags),
+	SYS_rmdir                   = 0x40000054 (PtrIn),
+	SYS_creat                   = 0x40000055 (PtrIn, Flags),
+	SYS_link                    = 0x40000056 (PtrIn, PtrIn),
+	SYS_unlink                  = 0x40000057 (PtrIn),
+	SYS_symlink                 = 0x40000058 (PtrIn, PtrIn),
+	SYS_readlink                = 0x40000059 (PtrIn, PtrOut, Len),
+	SYS_chmod                   = 0x4000005A (PtrIn, Flags),
+	SYS_fchmod                  = 0x4000005B (Fd, Flags),
+	SYS_chown                   = 0x4000005C (PtrIn, Int, Int),
+	SYS_fchown                  = 0x4000005D (Fd, Int, Int),
+	SYS_lchown                  = 0x4000005E (PtrIn, Int, Int),
+	SYS_umask                   = 0x4000005F (Flags),
+	SYS_gettimeofday            = 0x40000060 (PtrOut, PtrOut),
+	SYS_getrlimit               = 0x40000061 (Int, PtrOut),
+	SYS_getrusage               = 0x40000062 (Int, PtrOut),
+	SYS_sysinfo                 = 0x40000063 (PtrOut),
+	SYS_times                   = 0x40000064 (PtrOut),
+	SYS_getuid                  = 0x40000066 (),
+	SYS_syslog                  = 0x40000067 (Flags, PtrOut, Len),
+	SYS_getgid                  = 0x40000068 (),
+	SYS_setuid                  = 0x40000069 (Int),
+	SYS_setgid                  = 0x4000006A (Int),
+	SYS_geteuid                 = 0x4000006B (),
+	SYS_getegid                 = 0x4000006C (),
+	SYS_setpgid                 = 0x4000006D (Int, Int),
+	SYS_getppid                 = 0x4000006E (),
+	SYS_getpgrp                 = 0x4000006F (),
+	SYS_setsid                  = 0x40000070 (),
+	SYS_setreuid                = 0x40000071 (Int, Int),
+	SYS_setregid                = 0x40000072 (Int, Int),
+	SYS_getgroups               = 0x40000073 (Len, PtrOut),
+	SYS_setgroups               = 0x40000074 (Len, PtrIn),
+	SYS_setresuid               = 0x40000075 (Int, Int, Int),
+	SYS_getresuid               = 0x40000076 (PtrOut, PtrOut, PtrOut),
+	SYS_setresgid               = 0x40000077 (Int, Int, Int),
+	SYS_getresgid               = 0x40000078 (PtrOut, PtrOut, PtrOut),
+	SYS_getpgid                 = 0x40000079 (Int),
+	SYS_setfsuid                = 0x4000007A (Int),
+	SYS_setfsgid                = 0x4000007B (Int),
+	SYS_getsid                  = 0x4000007C (Int),
+	SYS_capget                  = 0x4000007D (PtrInOut, PtrOut),
+	SYS_capset                  = 0x4000007E (PtrIn, PtrIn),
+	SYS_rt_sigsuspend           = 0x40000082 (PtrIn, Len),
+	SYS_utime                   = 0x40000084 (PtrIn, PtrIn),
+	SYS_mknod                   = 0x40000085 (PtrIn, Flags, Int),
+	SYS_personality             = 0x40000087 (Flags),
+	SYS_ustat                   = 0x40000088 (Int, PtrOut),
+	SYS_statfs                  = 0x40000089 (PtrIn, PtrOut),
+	SYS_fstatfs                 = 0x4000008A (Fd, PtrOut),
+	SYS_getpriority             = 0x4000008C (Int, Int),
+	SYS_setpriority             = 0x4000008D (Int, Int, Int),
+	SYS_sched_setparam          = 0x4000008E (Int, PtrIn),
+	SYS_sched_getparam          = 0x4000008F (Int, PtrOut),
+	SYS_sched_setscheduler      = 0x40000090 (Int, Int, PtrIn),
+	SYS_sched_getscheduler      = 0x40000091 (Int),
+	SYS_sched_get_priority_max  = 0x40000092 (Int),
+	SYS_sched_get_priority_min  = 0x40000093 (Int),
+	SYS_sched_rr_get_interval   = 0x40000094 (Int, PtrOut),
+	SYS_mlock                   = 0x40000095 (Addr, Len),
+	SYS_munlock                 = 0x40000096 (Addr, Len),
+	SYS_mlockall                = 0x40000097 (Flags),
+	SYS_munlockall              = 0x40000098 (),
+	SYS_vhangup                 = 0x40000099 (),
+	SYS_modify_ldt              = 0x4000009A (Flags, PtrInOut, Len),
+	SYS_pivot_root              = 0x4000009B (PtrIn, PtrIn),
+	SYS_prctl                   = 0x4000009D (Flags, Int, Int, Int, Int),
+	SYS_arch_prctl              = 0x4000009E (Flags, Addr),
+	SYS_adjtimex                = 0x4000009F (PtrInOut),
+	SYS_setrlimit               = 0x400000A0 (Int, PtrIn),
+	SYS_chroot                  = 0x400000A1 (PtrIn),
+	SYS_sync                    = 0x400000A2 (),
+	SYS_acct                    = 0x400000A3 (PtrIn),
+	SYS_settimeofday            = 0x400000A4 (PtrIn, PtrIn),
+	SYS_mount                   = 0x400000A5 (PtrIn, PtrIn, PtrIn, Flags, PtrIn),
+	SYS_umount2                 = 0x400000A6 (PtrIn, Flags),
+	SYS_swapon                  = 0x400000A7 (PtrIn, Flags),
+	SYS_swapoff                 = 0x400000A8 (PtrIn),
+	SYS_reboot                  = 0x400000A9 (Int, Int, Flags, PtrIn),
+	SYS_sethostname             = 0x400000AA (PtrIn, Len),
+	SYS_setdomainname           = 0x400000AB (PtrIn, Len),
+	SYS_iopl                    = 0x400000AC (Int),
+	SYS_ioperm                  = 0x400000AD (Int, Len, Int),
+	SYS_init_module             = 0x400000AF (PtrIn, Len, PtrIn),
+	SYS_delete_module           = 0x400000B0 (PtrIn, Flags),
+	SYS_quotactl                = 0x400000B3 (Flags, PtrIn, Int, PtrInOut),
+	SYS_gettid                  = 0x400000BA (),
+	SYS_readahead               = 0x400000BB (Fd, Int64, Len),
+	SYS_setxattr                = 0x400000BC (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_lsetxattr               = 0x400000BD (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_fsetxattr               = 0x400000BE (Fd, PtrIn, PtrIn, Len, Flags),
+	SYS_getxattr                = 0x400000BF (PtrIn, PtrIn, PtrOut, Len),
+	SYS_lgetxattr               = 0x400000C0 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_fgetxattr               = 0x400000C1 (Fd, PtrIn, PtrOut, Len),
+	SYS_listxattr               = 0x400000C2 (PtrIn, PtrOut, Len),
+	SYS_llistxattr              = 0x400000C3 (PtrIn, PtrOut, Len),
+	SYS_flistxattr              = 0x400000C4 (Fd, PtrOut, Len),
+	SYS_removexattr             = 0x400000C5 (PtrIn, PtrIn),
+	SYS_lremovexattr            = 0x400000C6 (PtrIn, PtrIn),
+	SYS_fremovexattr            = 0x400000C7 (Fd, PtrIn),
+	SYS_tkill                   = 0x400000C8 (Int, Int),
+	SYS_time                    = 0x400000C9 (PtrOut),
+	SYS_futex                   = 0x400000CA (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_setaffinity       = 0x400000CB (Int, Len, PtrIn),
+	SYS_sched_getaffinity       = 0x400000CC (Int, Len, PtrOut),
+	SYS_io_destroy              = 0x400000CF (Int),
+	SYS_io_getevents            = 0x400000D0 (Int, Len, Len, PtrOut, PtrIn),
+	SYS_io_cancel               = 0x400000D2 (Int, PtrIn, PtrOut),
+	SYS_lookup_dcookie          = 0x400000D4 (Int64, PtrOut, Len),
+	SYS_epoll_create            = 0x400000D5 (Int),
+	SYS_getdents64              = 0x400000D9 (Fd, PtrOut, Len),
+	SYS_set_tid_address         = 0x400000DA (Addr),
+	SYS_semtimedop              = 0x400000DC (Int, PtrIn, Len, PtrIn),
+	SYS_fadvise64               = 0x400000DD (Fd, Int64, Len, Int),
+	SYS_timer_settime           = 0x400000DF (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime           = 0x400000E0 (Int, PtrOut),
+	SYS_timer_getoverrun        = 0x400000E1 (Int),
+	SYS_timer_delete            = 0x400000E2 (Int),
+	SYS_clock_settime           = 0x400000E3 (Int, PtrIn),
+	SYS_clock_gettime           = 0x400000E4 (Int, PtrOut),
+	SYS_clock_getres            = 0x400000E5 (Int, PtrOut),
+	SYS_clock_nanosleep         = 0x400000E6 (Int, Flags, PtrIn, PtrOut),
+	SYS_exit_group              = 0x400000E7 (Int),
+	SYS_epoll_wait              = 0x400000E8 (Fd, PtrOut, Len, Int),
+	SYS_epoll_ctl               = 0x400000E9 (Fd, Flags, Fd, PtrIn),
+	SYS_tgkill                  = 0x400000EA (Int, Int, Int),
+	SYS_utimes                  = 0x400000EB (PtrIn, PtrIn),
+	SYS_mbind                   = 0x400000ED (Addr, Len, Flags, PtrIn, Len, Flags),
+	SYS_set_mempolicy           = 0x400000EE (Flags, PtrIn, Len),
+	SYS_get_mempolicy           = 0x400000EF (PtrOut, PtrOut, Len, Addr, Flags),
+	SYS_mq_open                 = 0x400000F0 (PtrIn, Flags, Flags, PtrIn),
+	SYS_mq_unlink               = 0x400000F1 (PtrIn),
+	SYS_mq_timedsend            = 0x400000F2 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive         = 0x400000F3 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_mq_getsetattr           = 0x400000F5 (Fd, PtrIn, PtrOut),
+	SYS_add_key                 = 0x400000F8 (PtrIn, PtrIn, PtrIn, Len, Int),
+	SYS_request_key             = 0x400000F9 (PtrIn, PtrIn, PtrIn, Int),
+	SYS_keyctl                  = 0x400000FA (Flags, Int, Int, Int, Int),
+	SYS_ioprio_set              = 0x400000FB (Int, Int, Int),
+	SYS_ioprio_get              = 0x400000FC (Int, Int),
+	SYS_inotify_init            = 0x400000FD (),
+	SYS_inotify_add_watch       = 0x400000FE (Fd, PtrIn, Flags),
+	SYS_inotify_rm_watch        = 0x400000FF (Fd, Int),
+	SYS_migrate_pages           = 0x40000100 (Int, Len, PtrIn, PtrIn),
+	SYS_openat                  = 0x40000101 (Fd, PtrIn, Flags, Flags),
+	SYS_mkdirat                 = 0x40000102 (Fd, PtrIn, Flags),
+	SYS_mknodat                 = 0x40000103 (Fd, PtrIn, Flags, Int),
+	SYS_fchownat                = 0x40000104 (Fd, PtrIn, Int, Int, Flags),
+	SYS_futimesat               = 0x40000105 (Fd, PtrIn, PtrIn),
+	SYS_newfstatat              = 0x40000106 (Fd, PtrIn, PtrOut, Flags),
+	SYS_unlinkat                = 0x40000107 (Fd, PtrIn, Flags),
+	SYS_renameat                = 0x40000108 (Fd, PtrIn, Fd, PtrIn),
+	SYS_linkat                  = 0x40000109 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_symlinkat               = 0x4000010A (PtrIn, Fd, PtrIn),
+	SYS_readlinkat              = 0x4000010B (Fd, PtrIn, PtrOut, Len),
+	SYS_fchmodat                = 0x4000010C (Fd, PtrIn, Flags),
+	SYS_faccessat               = 0x4000010D (Fd, PtrIn, Flags),
+	SYS_pselect6                = 0x4000010E (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll                   = 0x4000010F (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_unshare                 = 0x40000110 (Flags),
+	SYS_splice                  = 0x40000113 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_tee                     = 0x40000114 (Fd, Fd, Len, Flags),
+	SYS_sync_file_range         = 0x40000115 (Fd, Int64, Int64, Flags),
+	SYS_utimensat               = 0x40000118 (Fd, PtrIn, PtrIn, Flags),
+	SYS_epoll_pwait             = 0x40000119 (Fd, PtrOut, Len, Int, PtrIn, Len),
+	SYS_signalfd                = 0x4000011A (Fd, PtrIn, Len),
+	SYS_timerfd_create          = 0x4000011B (Int, Flags),
+	SYS_eventfd                 = 0x4000011C (Int),
+	SYS_fallocate               = 0x4000011D (Fd, Flags, Int64, Int64),
+	SYS_timerfd_settime         = 0x4000011E (Fd, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime         = 0x4000011F (Fd, PtrOut),
+	SYS_accept4                 = 0x40000120 (Fd, PtrOut, PtrInOut, Flags),
+	SYS_signalfd4               = 0x40000121 (Fd, PtrIn, Len, Flags),
+	SYS_eventfd2                = 0x40000122 (Int, Flags),
+	SYS_epoll_create1           = 0x40000123 (Flags),
+	SYS_dup3                    = 0x40000124 (Fd, Fd, Flags),
+	SYS_pipe2                   = 0x40000125 (PtrOut, Flags),
+	SYS_inotify_init1           = 0x40000126 (Flags),
+	SYS_perf_event_open         = 0x4000012A (PtrInOut, Int, Int, Fd, Flags),
+	SYS_fanotify_init           = 0x4000012C (Flags, Flags),
+	SYS_fanotify_mark           = 0x4000012D (Fd, Flags, Int64, Fd, PtrIn),
+	SYS_prlimit64               = 0x4000012E (Int, Int, PtrIn, PtrOut),
+	SYS_name_to_handle_at       = 0x4000012F (Fd, PtrIn, PtrInOut, PtrOut, Flags),
+	SYS_open_by_handle_at       = 0x40000130 (Fd, PtrIn, Flags),
+	SYS_clock_adjtime           = 0x40000131 (Int, PtrInOut),
+	SYS_syncfs                  = 0x40000132 (Fd),
+	SYS_setns                   = 0x40000134 (Fd, Flags),
+	SYS_getcpu                  = 0x40000135 (PtrOut, PtrOut, PtrOut),
+	SYS_kcmp                    = 0x40000138 (Int, Int, Int, Int, Int),
+	SYS_finit_module            = 0x40000139 (Fd, PtrIn, Flags),
+	SYS_sched_setattr           = 0x4000013A (Int, PtrIn, Flags),
+	SYS_sched_getattr           = 0x4000013B (Int, PtrOut, Len, Flags),
+	SYS_renameat2               = 0x4000013C (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_seccomp                 = 0x4000013D (Flags, Flags, PtrInOut),
+	SYS_getrandom               = 0x4000013E (PtrOut, Len, Flags),
+	SYS_memfd_create            = 0x4000013F (PtrIn, Flags),
+	SYS_kexec_file_load         = 0x40000140 (Fd, Fd, Len, PtrIn, Flags),
+	SYS_bpf                     = 0x40000141 (Flags, PtrInOut, Len),
+	SYS_userfaultfd             = 0x40000143 (Flags),
+	SYS_membarrier              = 0x40000144 (Flags, Flags, Int),
+	SYS_mlock2                  = 0x40000145 (Addr, Len, Flags),
+	SYS_copy_file_range         = 0x40000146 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_pkey_mprotect           = 0x40000149 (Addr, Len, Flags, Int),
+	SYS_pkey_alloc              = 0x4000014A (Flags, Flags),
+	SYS_pkey_free               = 0x4000014B (Int),
+	SYS_statx                   = 0x4000014C (Fd, PtrIn, Flags, Flags, PtrOut),
+	SYS_io_pgetevents           = 0x4000014D (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_rseq                    = 0x4000014E (PtrInOut, Len, Flags, Int),
+	SYS_pidfd_send_signal       = 0x400001A8 (Fd, Int, PtrIn, Flags),
+	SYS_io_uring_setup          = 0x400001A9 (Len, PtrInOut),
+	SYS_io_uring_enter          = 0x400001AA (Fd, Len, Len, Flags, PtrIn, Len),
+	SYS_io_uring_register       = 0x400001AB (Fd, Flags, PtrInOut, Len),
+	SYS_open_tree               = 0x400001AC (Fd, PtrIn, Flags),
+	SYS_move_mount              = 0x400001AD (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_fsopen                  = 0x400001AE (PtrIn, Flags),
+	SYS_fsconfig                = 0x400001AF (Fd, Flags, PtrIn, PtrIn, Int),
+	SYS_fsmount                 = 0x400001B0 (Fd, Flags, Flags),
+	SYS_fspick                  = 0x400001B1 (Fd, PtrIn, Flags),
+	SYS_pidfd_open              = 0x400001B2 (Int, Flags),
+	SYS_clone3                  = 0x400001B3 (PtrIn, Len),
+	SYS_close_range             = 0x400001B4 (Fd, Fd, Flags),
+	SYS_openat2                 = 0x400001B5 (Fd, PtrIn, PtrIn, Len),
+	SYS_pidfd_getfd             = 0x400001B6 (Fd, Fd, Flags),
+	SYS_faccessat2              = 0x400001B7 (Fd, PtrIn, Flags, Flags),
+	SYS_process_madvise         = 0x400001B8 (Fd, PtrIn, Len, Flags, Flags),
+	SYS_epoll_pwait2            = 0x400001B9 (Fd, PtrOut, Len, PtrIn, PtrIn, Len),
+	SYS_mount_setattr           = 0x400001BA (Fd, PtrIn, Flags, PtrIn, Len),
+	SYS_quotactl_fd             = 0x400001BB (Fd, Flags, Int, PtrInOut),
+	SYS_landlock_create_ruleset = 0x400001BC (PtrIn, Len, Flags),
+	SYS_landlock_add_rule       = 0x400001BD (Fd, Int, PtrIn, Flags),
+	SYS_landlock_restrict_self  = 0x400001BE (Fd, Flags),
+	SYS_memfd_secret            = 0x400001BF (Flags),
+	SYS_process_mrelease        = 0x400001C0 (Fd, Flags),
+	SYS_futex_waitv             = 0x400001C1 (PtrIn, Len, Flags, PtrIn, Int),
+	SYS_set_mempolicy_home_node = 0x400001C2 (Addr, Len, Int, Flags),
+	SYS_rt_sigaction            = 0x40000200 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigreturn            = 0x40000201 (),
+	SYS_ioctl                   = 0x40000202 (Fd, Flags, Int),
+	SYS_readv                   = 0x40000203 (Fd, PtrOut, Len),
+	SYS_writev                  = 0x40000204 (Fd, PtrIn, Len),
+	SYS_recvfrom                = 0x40000205 (Fd, PtrOut, Len, Flags, PtrOut, PtrInOut),
+	SYS_sendmsg                 = 0x40000206 (Fd, PtrIn, Flags),
+	SYS_recvmsg                 = 0x40000207 (Fd, PtrInOut, Flags),
+	SYS_execve                  = 0x40000208 (PtrIn, PtrIn, PtrIn),
+	SYS_ptrace                  = 0x40000209 (Flags, Int, Addr, Int),
+	SYS_rt_sigpending           = 0x4000020A (PtrOut, Len),
+	SYS_rt_sigtimedwait         = 0x4000020B (PtrIn, PtrOut, PtrIn, Len),
+	SYS_rt_sigqueueinfo         = 0x4000020C (Int, Int, PtrIn),
+	SYS_sigaltstack             = 0x4000020D (PtrIn, PtrOut),
+	SYS_timer_create            = 0x4000020E (Int, PtrIn, PtrOut),
+	SYS_mq_notify               = 0x4000020F (Fd, PtrIn),
+	SYS_kexec_load              = 0x40000210 (Addr, Len, PtrIn, Flags),
+	SYS_waitid                  = 0x40000211 (Int, Int, PtrOut, Flags, PtrOut),
+	SYS_set_robust_list         = 0x40000212 (Addr, Len),
+	SYS_get_robust_list         = 0x40000213 (Int, PtrOut, PtrOut),
+	SYS_vmsplice                = 0x40000214 (Fd, PtrIn, Len, Flags),
+	SYS_move_pages              = 0x40000215 (Int, Len, PtrIn, PtrIn, PtrOut, Flags),
+	SYS_preadv                  = 0x40000216 (Fd, PtrOut, Len, Int64),
+	SYS_pwritev                 = 0x40000217 (Fd, PtrIn, Len, Int64),
+	SYS_rt_tgsigqueueinfo       = 0x40000218 (Int, Int, Int, PtrIn),
+	SYS_recvmmsg                = 0x40000219 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_sendmmsg                = 0x4000021A (Fd, PtrInOut, Len, Flags),
+	SYS_process_vm_readv        = 0x4000021B (Int, PtrOut, Len, PtrIn, Len, Flags),
+	SYS_process_vm_writev       = 0x4000021C (Int, PtrIn, Len, PtrIn, Len, Flags),
+	SYS_setsockopt              = 0x4000021D (Fd, Int, Int, PtrIn, Len),
+	SYS_getsockopt              = 0x4000021E (Fd, Int, Int, PtrOut, PtrInOut),
+	SYS_io_setup                = 0x4000021F (Len, PtrOut),
+	SYS_io_submit               = 0x40000220 (Int, Len, PtrIn),
+	SYS_execveat                = 0x40000221 (Fd, PtrIn, PtrIn, PtrIn, Flags),
+	SYS_preadv2                 = 0x40000222 (Fd, PtrOut, Len, Int64, Flags),
+	SYS_pwritev2                = 0x40000223 (Fd, PtrIn, Len, Int64, Flags),
+}
@@ -13,6 +13,9 @@
 //
 // SPDX-License-Identifier: 0BSD
 
+#[cfg(any(target_arch = "x86", doc))]
+use core::sync::atomic::{AtomicPtr, Ordering};
+
 /// Linux syscall result for the `x86` architecture.
 #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 #[must_use]
@@ -37,57 +40,86 @@ impl Result {
 
 single_register_result32!(Result);
 
-#[cfg(not(doc))]
+#[cfg(any(target_arch = "x86", doc))]
+static VSYSCALL_ENTRY: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Sets the address of the vDSO `__kernel_vsyscall` function.
+///
+/// The address is available from the `AT_SYSINFO` entry of the auxiliary
+/// vector, for example by calling `getauxval(AT_SYSINFO)`. After it has been
+/// set, syscalls made with [`vsyscall!`](crate::vsyscall!) will enter the
+/// kernel through `__kernel_vsyscall` instead of `int $0x80`.
+///
+/// # Safety
+///
+/// `entry` must be null or the address of `__kernel_vsyscall` in the vDSO of
+/// the current process.
+#[cfg(any(target_arch = "x86", doc))]
+#[inline]
+pub unsafe fn set_vsyscall_entry(entry: *const ()) {
+	VSYSCALL_ENTRY.store(entry as *mut (), Ordering::Relaxed);
+}
+
+/// Returns the address of the vDSO `__kernel_vsyscall` function, or null if
+/// it has not been set by [`set_vsyscall_entry`].
+#[cfg(any(target_arch = "x86", doc))]
+#[inline]
+pub fn vsyscall_entry() -> *const () {
+	VSYSCALL_ENTRY.load(Ordering::Relaxed)
+}
+
+#[cfg(all(target_arch = "x86", not(doc)))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! syscall {
-	($nr:expr $(,)?) => {{
+macro_rules! __syscall_with_options {
+	([$($opt:ident),*] $nr:expr $(,)?) => {{
 		let mut out_eax;
 		core::arch::asm!(
 			"int $0x80",
-			in("eax") u32::from(Into::<$crate::Syscall>::into($nr)),
+			in("eax") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
 			lateout("eax") out_eax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86::Result::new(out_eax)
 	}};
-	($nr:expr, $a1:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr $(,)?) => {{
 		let mut out_eax;
 		core::arch::asm!(
 			"int $0x80",
-			in("eax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("ebx") $a1,
+			in("eax") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
 			lateout("eax") out_eax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86::Result::new(out_eax)
 	}};
-	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr $(,)?) => {{
 		let mut out_eax;
 		core::arch::asm!(
 			"int $0x80",
-			in("eax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("ebx") $a1,
-			in("ecx") $a2,
+			in("eax") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2),
 			lateout("eax") out_eax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86::Result::new(out_eax)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
 		let mut out_eax;
 		core::arch::asm!(
 			"int $0x80",
-			in("eax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("ebx") $a1,
-			in("ecx") $a2,
-			in("edx") $a3,
+			in("eax") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3),
 			lateout("eax") out_eax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86::Result::new(out_eax)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
-		let in_esi = $a4;
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		let in_esi = $crate::IntoSyscallArg::into_syscall_arg($a4);
 		let mut out_eax;
 		core::arch::asm!(
 			"push esi",
@@ -95,16 +127,17 @@ macro_rules! syscall {
 			"int $0x80",
 			"pop esi",
 			in(reg) in_esi,
-			in("eax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("ebx") $a1,
-			in("ecx") $a2,
-			in("edx") $a3,
+			in("eax") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3),
 			lateout("eax") out_eax,
+			options(preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86::Result::new(out_eax)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
-		let in_esi = $a4;
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		let in_esi = $crate::IntoSyscallArg::into_syscall_arg($a4);
 		let mut out_eax;
 		core::arch::asm!(
 			"push esi",
@@ -112,18 +145,19 @@ macro_rules! syscall {
 			"int $0x80",
 			"pop esi",
 			in(reg) in_esi,
-			in("eax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("ebx") $a1,
-			in("ecx") $a2,
-			in("edx") $a3,
-			in("edi") $a5,
+			in("eax") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("edi") $crate::IntoSyscallArg::into_syscall_arg($a5),
 			lateout("eax") out_eax,
+			options(preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86::Result::new(out_eax)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
-		let in_esi = $a4;
-		let in_ebp = $a6;
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		let in_esi = $crate::IntoSyscallArg::into_syscall_arg($a4);
+		let in_ebp = $crate::IntoSyscallArg::into_syscall_arg($a6);
 		let mut out_eax;
 		core::arch::asm!(
 			"push esi",
@@ -135,11 +169,417 @@ macro_rules! syscall {
 			"pop esi",
 			in(reg) in_esi,
 			in(reg) in_ebp,
-			in("eax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("ebx") $a1,
-			in("ecx") $a2,
-			in("edx") $a3,
-			in("edi") $a5,
+			in("eax") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("edi") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			lateout("eax") out_eax,
+			options(preserves_flags, $($opt),*),
+		);
+		$crate::arch::x86::Result::new(out_eax)
+	}};
+}
+
+// The registers `esi` and `ebp` don't need to be restored, but either may have
+// been allocated to the other's input, so the 6-argument form loads them
+// through the stack.
+#[cfg(all(target_arch = "x86", not(doc)))]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($nr:expr $(,)?) => {{
+		core::arch::asm!(
+			"int $0x80",
+			in("eax") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		core::arch::asm!(
+			"int $0x80",
+			in("eax") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		core::arch::asm!(
+			"int $0x80",
+			in("eax") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		core::arch::asm!(
+			"int $0x80",
+			in("eax") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		let in_esi = $crate::IntoSyscallArg::into_syscall_arg($a4);
+		core::arch::asm!(
+			"mov esi, {0}",
+			"int $0x80",
+			in(reg) in_esi,
+			in("eax") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		let in_esi = $crate::IntoSyscallArg::into_syscall_arg($a4);
+		core::arch::asm!(
+			"mov esi, {0}",
+			"int $0x80",
+			in(reg) in_esi,
+			in("eax") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("edi") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		let in_esi = $crate::IntoSyscallArg::into_syscall_arg($a4);
+		let in_ebp = $crate::IntoSyscallArg::into_syscall_arg($a6);
+		core::arch::asm!(
+			"push {1}",
+			"push {0}",
+			"pop esi",
+			"pop ebp",
+			"int $0x80",
+			in(reg) in_esi,
+			in(reg) in_ebp,
+			in("eax") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("ebx") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("edi") $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn),
+		)
+	}};
+}
+
+// Forms with four or more arguments load `esi` inside the template, so the
+// entry point can't be an operand that might be allocated to `esi`. It is
+// passed in `edi` when that register is free, and through memory otherwise.
+#[cfg(all(target_arch = "x86", not(doc)))]
+#[macro_export]
+macro_rules! vsyscall {
+	($nr:expr $(,)?) => {{
+		let nr = u32::from($crate::IntoSyscall::<0>::into_syscall($nr));
+		let entry = $crate::arch::x86::vsyscall_entry();
+		if entry.is_null() {
+			$crate::syscall!(nr)
+		} else {
+			let mut out_eax;
+			core::arch::asm!(
+				"call {entry}",
+				entry = in(reg) entry,
+				in("eax") nr,
+				lateout("eax") out_eax,
+			);
+			$crate::arch::x86::Result::new(out_eax)
+		}
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		let nr = u32::from($crate::IntoSyscall::<1>::into_syscall($nr));
+		let a1 = $crate::IntoSyscallArg::into_syscall_arg($a1);
+		let entry = $crate::arch::x86::vsyscall_entry();
+		if entry.is_null() {
+			$crate::syscall!(nr, a1)
+		} else {
+			let mut out_eax;
+			core::arch::asm!(
+				"call {entry}",
+				entry = in(reg) entry,
+				in("eax") nr,
+				in("ebx") a1,
+				lateout("eax") out_eax,
+			);
+			$crate::arch::x86::Result::new(out_eax)
+		}
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		let nr = u32::from($crate::IntoSyscall::<2>::into_syscall($nr));
+		let a1 = $crate::IntoSyscallArg::into_syscall_arg($a1);
+		let a2 = $crate::IntoSyscallArg::into_syscall_arg($a2);
+		let entry = $crate::arch::x86::vsyscall_entry();
+		if entry.is_null() {
+			$crate::syscall!(nr, a1, a2)
+		} else {
+			let mut out_eax;
+			core::arch::asm!(
+				"call {entry}",
+				entry = in(reg) entry,
+				in("eax") nr,
+				in("ebx") a1,
+				in("ecx") a2,
+				lateout("eax") out_eax,
+			);
+			$crate::arch::x86::Result::new(out_eax)
+		}
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		let nr = u32::from($crate::IntoSyscall::<3>::into_syscall($nr));
+		let a1 = $crate::IntoSyscallArg::into_syscall_arg($a1);
+		let a2 = $crate::IntoSyscallArg::into_syscall_arg($a2);
+		let a3 = $crate::IntoSyscallArg::into_syscall_arg($a3);
+		let entry = $crate::arch::x86::vsyscall_entry();
+		if entry.is_null() {
+			$crate::syscall!(nr, a1, a2, a3)
+		} else {
+			let mut out_eax;
+			core::arch::asm!(
+				"call {entry}",
+				entry = in(reg) entry,
+				in("eax") nr,
+				in("ebx") a1,
+				in("ecx") a2,
+				in("edx") a3,
+				lateout("eax") out_eax,
+			);
+			$crate::arch::x86::Result::new(out_eax)
+		}
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		let nr = u32::from($crate::IntoSyscall::<4>::into_syscall($nr));
+		let a1 = $crate::IntoSyscallArg::into_syscall_arg($a1);
+		let a2 = $crate::IntoSyscallArg::into_syscall_arg($a2);
+		let a3 = $crate::IntoSyscallArg::into_syscall_arg($a3);
+		let a4 = $crate::IntoSyscallArg::into_syscall_arg($a4);
+		let entry = $crate::arch::x86::vsyscall_entry();
+		if entry.is_null() {
+			$crate::syscall!(nr, a1, a2, a3, a4)
+		} else {
+			let mut out_eax;
+			core::arch::asm!(
+				"push esi",
+				"mov esi, {in_esi}",
+				"call edi",
+				"pop esi",
+				in_esi = in(reg) a4,
+				in("edi") entry,
+				in("eax") nr,
+				in("ebx") a1,
+				in("ecx") a2,
+				in("edx") a3,
+				lateout("eax") out_eax,
+			);
+			$crate::arch::x86::Result::new(out_eax)
+		}
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		let nr = u32::from($crate::IntoSyscall::<5>::into_syscall($nr));
+		let a1 = $crate::IntoSyscallArg::into_syscall_arg($a1);
+		let a2 = $crate::IntoSyscallArg::into_syscall_arg($a2);
+		let a3 = $crate::IntoSyscallArg::into_syscall_arg($a3);
+		let a4 = $crate::IntoSyscallArg::into_syscall_arg($a4);
+		let a5 = $crate::IntoSyscallArg::into_syscall_arg($a5);
+		let entry = $crate::arch::x86::vsyscall_entry();
+		if entry.is_null() {
+			$crate::syscall!(nr, a1, a2, a3, a4, a5)
+		} else {
+			let in_esi_entry = [a4, entry as usize];
+			let mut out_eax;
+			core::arch::asm!(
+				"push esi",
+				"push dword ptr [{args} + 4]",
+				"mov esi, [{args}]",
+				"call dword ptr [esp]",
+				"add esp, 4",
+				"pop esi",
+				args = in(reg) in_esi_entry.as_ptr(),
+				in("eax") nr,
+				in("ebx") a1,
+				in("ecx") a2,
+				in("edx") a3,
+				in("edi") a5,
+				lateout("eax") out_eax,
+			);
+			$crate::arch::x86::Result::new(out_eax)
+		}
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		let nr = u32::from($crate::IntoSyscall::<6>::into_syscall($nr));
+		let a1 = $crate::IntoSyscallArg::into_syscall_arg($a1);
+		let a2 = $crate::IntoSyscallArg::into_syscall_arg($a2);
+		let a3 = $crate::IntoSyscallArg::into_syscall_arg($a3);
+		let a4 = $crate::IntoSyscallArg::into_syscall_arg($a4);
+		let a5 = $crate::IntoSyscallArg::into_syscall_arg($a5);
+		let a6 = $crate::IntoSyscallArg::into_syscall_arg($a6);
+		let entry = $crate::arch::x86::vsyscall_entry();
+		if entry.is_null() {
+			$crate::syscall!(nr, a1, a2, a3, a4, a5, a6)
+		} else {
+			let in_esi_ebp_entry = [a4, a6, entry as usize];
+			let mut out_eax;
+			core::arch::asm!(
+				"push esi",
+				"push ebp",
+				"push dword ptr [{args} + 8]",
+				"push dword ptr [{args} + 4]",
+				"mov esi, [{args}]",
+				"pop ebp",
+				"call dword ptr [esp]",
+				"add esp, 4",
+				"pop ebp",
+				"pop esi",
+				args = in(reg) in_esi_ebp_entry.as_ptr(),
+				in("eax") nr,
+				in("ebx") a1,
+				in("ecx") a2,
+				in("edx") a3,
+				in("edi") a5,
+				lateout("eax") out_eax,
+			);
+			$crate::arch::x86::Result::new(out_eax)
+		}
+	}};
+}
+
+#[cfg(all(target_arch = "x86_64", not(doc)))]
+#[macro_export]
+macro_rules! syscall32 {
+	($nr:expr $(,)?) => {{
+		let mut out_eax;
+		core::arch::asm!(
+			"int $0x80",
+			in("eax") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			out("r8") _,
+			out("r9") _,
+			out("r10") _,
+			out("r11") _,
+			lateout("eax") out_eax,
+			options(nostack),
+		);
+		$crate::arch::x86::Result::new(out_eax)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		let mut out_eax;
+		core::arch::asm!(
+			"xchg {a1:r}, rbx",
+			"int $0x80",
+			"xchg {a1:r}, rbx",
+			a1 = inout(reg) $crate::IntoSyscallArg::into_syscall_arg($a1) as u32 => _,
+			in("eax") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			out("r8") _,
+			out("r9") _,
+			out("r10") _,
+			out("r11") _,
+			lateout("eax") out_eax,
+			options(nostack),
+		);
+		$crate::arch::x86::Result::new(out_eax)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		let mut out_eax;
+		core::arch::asm!(
+			"xchg {a1:r}, rbx",
+			"int $0x80",
+			"xchg {a1:r}, rbx",
+			a1 = inout(reg) $crate::IntoSyscallArg::into_syscall_arg($a1) as u32 => _,
+			in("eax") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2) as u32,
+			out("r8") _,
+			out("r9") _,
+			out("r10") _,
+			out("r11") _,
+			lateout("eax") out_eax,
+			options(nostack),
+		);
+		$crate::arch::x86::Result::new(out_eax)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		let mut out_eax;
+		core::arch::asm!(
+			"xchg {a1:r}, rbx",
+			"int $0x80",
+			"xchg {a1:r}, rbx",
+			a1 = inout(reg) $crate::IntoSyscallArg::into_syscall_arg($a1) as u32 => _,
+			in("eax") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2) as u32,
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3) as u32,
+			out("r8") _,
+			out("r9") _,
+			out("r10") _,
+			out("r11") _,
+			lateout("eax") out_eax,
+			options(nostack),
+		);
+		$crate::arch::x86::Result::new(out_eax)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		let mut out_eax;
+		core::arch::asm!(
+			"xchg {a1:r}, rbx",
+			"int $0x80",
+			"xchg {a1:r}, rbx",
+			a1 = inout(reg) $crate::IntoSyscallArg::into_syscall_arg($a1) as u32 => _,
+			in("eax") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2) as u32,
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3) as u32,
+			in("esi") $crate::IntoSyscallArg::into_syscall_arg($a4) as u32,
+			out("r8") _,
+			out("r9") _,
+			out("r10") _,
+			out("r11") _,
+			lateout("eax") out_eax,
+			options(nostack),
+		);
+		$crate::arch::x86::Result::new(out_eax)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		let mut out_eax;
+		core::arch::asm!(
+			"xchg {a1:r}, rbx",
+			"int $0x80",
+			"xchg {a1:r}, rbx",
+			a1 = inout(reg) $crate::IntoSyscallArg::into_syscall_arg($a1) as u32 => _,
+			in("eax") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2) as u32,
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3) as u32,
+			in("esi") $crate::IntoSyscallArg::into_syscall_arg($a4) as u32,
+			in("edi") $crate::IntoSyscallArg::into_syscall_arg($a5) as u32,
+			out("r8") _,
+			out("r9") _,
+			out("r10") _,
+			out("r11") _,
+			lateout("eax") out_eax,
+			options(nostack),
+		);
+		$crate::arch::x86::Result::new(out_eax)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		let in_ebp = $crate::IntoSyscallArg::into_syscall_arg($a6) as u32;
+		let mut out_eax;
+		core::arch::asm!(
+			"xchg {a1:r}, rbx",
+			"push rbp",
+			"mov ebp, {a6:e}",
+			"int $0x80",
+			"pop rbp",
+			"xchg {a1:r}, rbx",
+			a1 = inout(reg) $crate::IntoSyscallArg::into_syscall_arg($a1) as u32 => _,
+			a6 = in(reg) in_ebp,
+			in("eax") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("ecx") $crate::IntoSyscallArg::into_syscall_arg($a2) as u32,
+			in("edx") $crate::IntoSyscallArg::into_syscall_arg($a3) as u32,
+			in("esi") $crate::IntoSyscallArg::into_syscall_arg($a4) as u32,
+			in("edi") $crate::IntoSyscallArg::into_syscall_arg($a5) as u32,
+			out("r8") _,
+			out("r9") _,
+			out("r10") _,
+			out("r11") _,
 			lateout("eax") out_eax,
 		);
 		$crate::arch::x86::Result::new(out_eax)
@@ -16,420 +16,420 @@
 #![allow(non_upper_case_globals)]
 
 syscall_constants! {
-	SYS_exit                         = 1,
-	SYS_fork                         = 2,
-	SYS_read                         = 3,
-	SYS_write                        = 4,
-	SYS_open                         = 5,
-	SYS_close                        = 6,
-	SYS_waitpid                      = 7,
-	SYS_creat                        = 8,
-	SYS_link                         = 9,
-	SYS_unlink                       = 10,
-	SYS_execve                       = 11,
-	SYS_chdir                        = 12,
-	SYS_time                         = 13,
-	SYS_mknod                        = 14,
-	SYS_chmod                        = 15,
-	SYS_lchown                       = 16,
-	SYS_oldstat                      = 18,
-	SYS_lseek                        = 19,
-	SYS_getpid                       = 20,
-	SYS_mount                        = 21,
-	SYS_umount                       = 22,
-	SYS_setuid                       = 23,
-	SYS_getuid                       = 24,
-	SYS_stime                        = 25,
-	SYS_ptrace                       = 26,
-	SYS_alarm                        = 27,
-	SYS_oldfstat                     = 28,
-	SYS_pause                        = 29,
-	SYS_utime                        = 30,
-	SYS_access                       = 33,
-	SYS_nice                         = 34,
-	SYS_sync                         = 36,
-	SYS_kill                         = 37,
-	SYS_rename                       = 38,
-	SYS_mkdir                        = 39,
-	SYS_rmdir                        = 40,
-	SYS_dup                          = 41,
-	SYS_pipe                         = 42,
-	SYS_times                        = 43,
-	SYS_brk                          = 45,
-	SYS_setgid                       = 46,
-	SYS_getgid                       = 47,
-	SYS_signal                       = 48,
-	SYS_geteuid                      = 49,
-	SYS_getegid                      = 50,
-	SYS_acct                         = 51,
-	SYS_umount2                      = 52,
-	SYS_ioctl                        = 54,
-	SYS_fcntl                        = 55,
-	SYS_setpgid                      = 57,
-	SYS_oldolduname                  = 59,
-	SYS_umask                        = 60,
-	SYS_chroot                       = 61,
-	SYS_ustat                        = 62,
-	SYS_dup2                         = 63,
-	SYS_getppid                      = 64,
-	SYS_getpgrp                      = 65,
-	SYS_setsid                       = 66,
-	SYS_sigaction                    = 67,
-	SYS_sgetmask                     = 68,
-	SYS_ssetmask                     = 69,
-	SYS_setreuid                     = 70,
-	SYS_setregid                     = 71,
-	SYS_sigsuspend                   = 72,
-	SYS_sigpending                   = 73,
-	SYS_sethostname                  = 74,
-	SYS_setrlimit                    = 75,
-	SYS_getrlimit                    = 76,
-	SYS_getrusage                    = 77,
-	SYS_gettimeofday                 = 78,
-	SYS_settimeofday                 = 79,
-	SYS_getgroups                    = 80,
-	SYS_setgroups                    = 81,
-	SYS_select                       = 82,
-	SYS_symlink                      = 83,
-	SYS_oldlstat                     = 84,
-	SYS_readlink                     = 85,
-	SYS_swapon                       = 87,
-	SYS_reboot                       = 88,
-	SYS_readdir                      = 89,
-	SYS_mmap                         = 90,
-	SYS_munmap                       = 91,
-	SYS_truncate                     = 92,
-	SYS_ftruncate                    = 93,
-	SYS_fchmod                       = 94,
-	SYS_fchown                       = 95,
-	SYS_getpriority                  = 96,
-	SYS_setpriority                  = 97,
-	SYS_statfs                       = 99,
-	SYS_fstatfs                      = 100,
-	SYS_ioperm                       = 101,
-	SYS_socketcall                   = 102,
-	SYS_syslog                       = 103,
-	SYS_setitimer                    = 104,
-	SYS_getitimer                    = 105,
-	SYS_stat                         = 106,
-	SYS_lstat                        = 107,
-	SYS_fstat                        = 108,
-	SYS_olduname                     = 109,
-	SYS_iopl                         = 110,
-	SYS_vhangup                      = 111,
-	SYS_vm86old                      = 113,
-	SYS_wait4                        = 114,
-	SYS_swapoff                      = 115,
-	SYS_sysinfo                      = 116,
-	SYS_ipc                          = 117,
-	SYS_fsync                        = 118,
-	SYS_sigreturn                    = 119,
-	SYS_clone                        = 120,
-	SYS_setdomainname                = 121,
-	SYS_uname                        = 122,
-	SYS_modify_ldt                   = 123,
-	SYS_adjtimex                     = 124,
-	SYS_mprotect                     = 125,
-	SYS_sigprocmask                  = 126,
-	SYS_init_module                  = 128,
-	SYS_delete_module                = 129,
-	SYS_quotactl                     = 131,
-	SYS_getpgid                      = 132,
-	SYS_fchdir                       = 133,
-	SYS_personality                  = 136,
-	SYS_setfsuid                     = 138,
-	SYS_setfsgid                     = 139,
-	SYS__llseek                      = 140,
-	SYS_getdents                     = 141,
-	SYS__newselect                   = 142,
-	SYS_flock                        = 143,
-	SYS_msync                        = 144,
-	SYS_readv                        = 145,
-	SYS_writev                       = 146,
-	SYS_getsid                       = 147,
-	SYS_fdatasync                    = 148,
-	SYS_mlock                        = 150,
-	SYS_munlock                      = 151,
-	SYS_mlockall                     = 152,
-	SYS_munlockall                   = 153,
-	SYS_sched_setparam               = 154,
-	SYS_sched_getparam               = 155,
-	SYS_sched_setscheduler           = 156,
-	SYS_sched_getscheduler           = 157,
-	SYS_sched_yield                  = 158,
-	SYS_sched_get_priority_max       = 159,
-	SYS_sched_get_priority_min       = 160,
-	SYS_sched_rr_get_interval        = 161,
-	SYS_nanosleep                    = 162,
-	SYS_mremap                       = 163,
-	SYS_setresuid                    = 164,
-	SYS_getresuid                    = 165,
-	SYS_vm86                         = 166,
-	SYS_poll                         = 168,
-	SYS_setresgid                    = 170,
-	SYS_getresgid                    = 171,
-	SYS_prctl                        = 172,
-	SYS_rt_sigreturn                 = 173,
-	SYS_rt_sigaction                 = 174,
-	SYS_rt_sigprocmask               = 175,
-	SYS_rt_sigpending                = 176,
-	SYS_rt_sigtimedwait              = 177,
-	SYS_rt_sigqueueinfo              = 178,
-	SYS_rt_sigsuspend                = 179,
-	SYS_pread64                      = 180,
-	SYS_pwrite64                     = 181,
-	SYS_chown                        = 182,
-	SYS_getcwd                       = 183,
-	SYS_capget                       = 184,
-	SYS_capset                       = 185,
-	SYS_sigaltstack                  = 186,
-	SYS_sendfile                     = 187,
-	SYS_vfork                        = 190,
-	SYS_ugetrlimit                   = 191,
-	SYS_mmap2                        = 192,
-	SYS_truncate64                   = 193,
-	SYS_ftruncate64                  = 194,
-	SYS_stat64                       = 195,
-	SYS_lstat64                      = 196,
-	SYS_fstat64                      = 197,
-	SYS_lchown32                     = 198,
-	SYS_getuid32                     = 199,
-	SYS_getgid32                     = 200,
-	SYS_geteuid32                    = 201,
-	SYS_getegid32                    = 202,
-	SYS_setreuid32                   = 203,
-	SYS_setregid32                   = 204,
-	SYS_getgroups32                  = 205,
-	SYS_setgroups32                  = 206,
-	SYS_fchown32                     = 207,
-	SYS_setresuid32                  = 208,
-	SYS_getresuid32                  = 209,
-	SYS_setresgid32                  = 210,
-	SYS_getresgid32                  = 211,
-	SYS_chown32                      = 212,
-	SYS_setuid32                     = 213,
-	SYS_setgid32                     = 214,
-	SYS_setfsuid32                   = 215,
-	SYS_setfsgid32                   = 216,
-	SYS_pivot_root                   = 217,
-	SYS_mincore                      = 218,
-	SYS_madvise                      = 219,
-	SYS_getdents64                   = 220,
-	SYS_fcntl64                      = 221,
-	SYS_gettid                       = 224,
-	SYS_readahead                    = 225,
-	SYS_setxattr                     = 226,
-	SYS_lsetxattr                    = 227,
-	SYS_fsetxattr                    = 228,
-	SYS_getxattr                     = 229,
-	SYS_lgetxattr                    = 230,
-	SYS_fgetxattr                    = 231,
-	SYS_listxattr                    = 232,
-	SYS_llistxattr                   = 233,
-	SYS_flistxattr                   = 234,
-	SYS_removexattr                  = 235,
-	SYS_lremovexattr                 = 236,
-	SYS_fremovexattr                 = 237,
-	SYS_tkill                        = 238,
-	SYS_sendfile64                   = 239,
-	SYS_futex                        = 240,
-	SYS_sched_setaffinity            = 241,
-	SYS_sched_getaffinity            = 242,
-	SYS_set_thread_area              = 243,
-	SYS_get_thread_area              = 244,
-	SYS_io_setup                     = 245,
-	SYS_io_destroy                   = 246,
-	SYS_io_getevents                 = 247,
-	SYS_io_submit                    = 248,
-	SYS_io_cancel                    = 249,
-	SYS_fadvise64                    = 250,
-	SYS_exit_group                   = 252,
-	SYS_lookup_dcookie               = 253,
-	SYS_epoll_create                 = 254,
-	SYS_epoll_ctl                    = 255,
-	SYS_epoll_wait                   = 256,
-	SYS_set_tid_address              = 258,
-	SYS_timer_create                 = 259,
-	SYS_timer_settime                = 260,
-	SYS_timer_gettime                = 261,
-	SYS_timer_getoverrun             = 262,
-	SYS_timer_delete                 = 263,
-	SYS_clock_settime                = 264,
-	SYS_clock_gettime                = 265,
-	SYS_clock_getres                 = 266,
-	SYS_clock_nanosleep              = 267,
-	SYS_statfs64                     = 268,
-	SYS_fstatfs64                    = 269,
-	SYS_tgkill                       = 270,
-	SYS_utimes                       = 271,
-	SYS_fadvise64_64                 = 272,
-	SYS_mbind                        = 274,
-	SYS_get_mempolicy                = 275,
-	SYS_set_mempolicy                = 276,
-	SYS_mq_open                      = 277,
-	SYS_mq_unlink                    = 278,
-	SYS_mq_timedsend                 = 279,
-	SYS_mq_timedreceive              = 280,
-	SYS_mq_notify                    = 281,
-	SYS_mq_getsetattr                = 282,
-	SYS_kexec_load                   = 283,
-	SYS_waitid                       = 284,
-	SYS_add_key                      = 286,
-	SYS_request_key                  = 287,
-	SYS_keyctl                       = 288,
-	SYS_ioprio_set                   = 289,
-	SYS_ioprio_get                   = 290,
-	SYS_inotify_init                 = 291,
-	SYS_inotify_add_watch            = 292,
-	SYS_inotify_rm_watch             = 293,
-	SYS_migrate_pages                = 294,
-	SYS_openat                       = 295,
-	SYS_mkdirat                      = 296,
-	SYS_mknodat                      = 297,
-	SYS_fchownat                     = 298,
-	SYS_futimesat                    = 299,
-	SYS_fstatat64                    = 300,
-	SYS_unlinkat                     = 301,
-	SYS_renameat                     = 302,
-	SYS_linkat                       = 303,
-	SYS_symlinkat                    = 304,
-	SYS_readlinkat                   = 305,
-	SYS_fchmodat                     = 306,
-	SYS_faccessat                    = 307,
-	SYS_pselect6                     = 308,
-	SYS_ppoll                        = 309,
-	SYS_unshare                      = 310,
-	SYS_set_robust_list              = 311,
-	SYS_get_robust_list              = 312,
-	SYS_splice                       = 313,
-	SYS_sync_file_range              = 314,
-	SYS_tee                          = 315,
-	SYS_vmsplice                     = 316,
-	SYS_move_pages                   = 317,
-	SYS_getcpu                       = 318,
-	SYS_epoll_pwait                  = 319,
-	SYS_utimensat                    = 320,
-	SYS_signalfd                     = 321,
-	SYS_timerfd_create               = 322,
-	SYS_eventfd                      = 323,
-	SYS_fallocate                    = 324,
-	SYS_timerfd_settime              = 325,
-	SYS_timerfd_gettime              = 326,
-	SYS_signalfd4                    = 327,
-	SYS_eventfd2                     = 328,
-	SYS_epoll_create1                = 329,
-	SYS_dup3                         = 330,
-	SYS_pipe2                        = 331,
-	SYS_inotify_init1                = 332,
-	SYS_preadv                       = 333,
-	SYS_pwritev                      = 334,
-	SYS_rt_tgsigqueueinfo            = 335,
-	SYS_perf_event_open              = 336,
-	SYS_recvmmsg                     = 337,
-	SYS_fanotify_init                = 338,
-	SYS_fanotify_mark                = 339,
-	SYS_prlimit64                    = 340,
-	SYS_name_to_handle_at            = 341,
-	SYS_open_by_handle_at            = 342,
-	SYS_clock_adjtime                = 343,
-	SYS_syncfs                       = 344,
-	SYS_sendmmsg                     = 345,
-	SYS_setns                        = 346,
-	SYS_process_vm_readv             = 347,
-	SYS_process_vm_writev            = 348,
-	SYS_kcmp                         = 349,
-	SYS_finit_module                 = 350,
-	SYS_sched_setattr                = 351,
-	SYS_sched_getattr                = 352,
-	SYS_renameat2                    = 353,
-	SYS_seccomp                      = 354,
-	SYS_getrandom                    = 355,
-	SYS_memfd_create                 = 356,
-	SYS_bpf                          = 357,
-	SYS_execveat                     = 358,
-	SYS_socket                       = 359,
-	SYS_socketpair                   = 360,
-	SYS_bind                         = 361,
-	SYS_connect                      = 362,
-	SYS_listen                       = 363,
-	SYS_accept4                      = 364,
-	SYS_getsockopt                   = 365,
-	SYS_setsockopt                   = 366,
-	SYS_getsockname                  = 367,
-	SYS_getpeername                  = 368,
-	SYS_sendto                       = 369,
-	SYS_sendmsg                      = 370,
-	SYS_recvfrom                     = 371,
-	SYS_recvmsg                      = 372,
-	SYS_shutdown                     = 373,
-	SYS_userfaultfd                  = 374,
-	SYS_membarrier                   = 375,
-	SYS_mlock2                       = 376,
-	SYS_copy_file_range              = 377,
-	SYS_preadv2                      = 378,
-	SYS_pwritev2                     = 379,
-	SYS_pkey_mprotect                = 380,
-	SYS_pkey_alloc                   = 381,
-	SYS_pkey_free                    = 382,
-	SYS_statx                        = 383,
-	SYS_arch_prctl                   = 384,
-	SYS_io_pgetevents                = 385,
-	SYS_rseq                         = 386,
-	SYS_semget                       = 393,
-	SYS_semctl                       = 394,
-	SYS_shmget                       = 395,
-	SYS_shmctl                       = 396,
-	SYS_shmat                        = 397,
-	SYS_shmdt                        = 398,
-	SYS_msgget                       = 399,
-	SYS_msgsnd                       = 400,
-	SYS_msgrcv                       = 401,
-	SYS_msgctl                       = 402,
-	SYS_clock_gettime64              = 403,
-	SYS_clock_settime64              = 404,
-	SYS_clock_adjtime64              = 405,
-	SYS_clock_getres_time64          = 406,
-	SYS_clock_nanosleep_time64       = 407,
-	SYS_timer_gettime64              = 408,
-	SYS_timer_settime64              = 409,
-	SYS_timerfd_gettime64            = 410,
-	SYS_timerfd_settime64            = 411,
-	SYS_utimensat_time64             = 412,
-	SYS_pselect6_time64              = 413,
-	SYS_ppoll_time64                 = 414,
-	SYS_io_pgetevents_time64         = 416,
-	SYS_recvmmsg_time64              = 417,
-	SYS_mq_timedsend_time64          = 418,
-	SYS_mq_timedreceive_time64       = 419,
-	SYS_semtimedop_time64            = 420,
-	SYS_rt_sigtimedwait_time64       = 421,
-	SYS_futex_time64                 = 422,
-	SYS_sched_rr_get_interval_time64 = 423,
-	SYS_pidfd_send_signal            = 424,
-	SYS_io_uring_setup               = 425,
-	SYS_io_uring_enter               = 426,
-	SYS_io_uring_register            = 427,
-	SYS_open_tree                    = 428,
-	SYS_move_mount                   = 429,
-	SYS_fsopen                       = 430,
-	SYS_fsconfig                     = 431,
-	SYS_fsmount                      = 432,
-	SYS_fspick                       = 433,
-	SYS_pidfd_open                   = 434,
-	SYS_clone3                       = 435,
-	SYS_close_range                  = 436,
-	SYS_openat2                      = 437,
-	SYS_pidfd_getfd                  = 438,
-	SYS_faccessat2                   = 439,
-	SYS_process_madvise              = 440,
-	SYS_epoll_pwait2                 = 441,
-	SYS_mount_setattr                = 442,
-	SYS_quotactl_fd                  = 443,
-	SYS_landlock_create_ruleset      = 444,
-	SYS_landlock_add_rule            = 445,
-	SYS_landlock_restrict_self       = 446,
-	SYS_memfd_secret                 = 447,
-	SYS_process_mrelease             = 448,
-	SYS_futex_waitv                  = 449,
-	SYS_set_mempolicy_home_node      = 450,
+	SYS_exit                         = 1   (Int),
+	SYS_fork                         = 2   (),
+	SYS_read                         = 3   (Fd, PtrOut, Len),
+	SYS_write                        = 4   (Fd, PtrIn, Len),
+	SYS_open                         = 5   (PtrIn, Flags, Flags),
+	SYS_close                        = 6   (Fd),
+	SYS_waitpid                      = 7   (Int, PtrOut, Flags),
+	SYS_creat                        = 8   (PtrIn, Flags),
+	SYS_link                         = 9   (PtrIn, PtrIn),
+	SYS_unlink                       = 10  (PtrIn),
+	SYS_execve                       = 11  (PtrIn, PtrIn, PtrIn),
+	SYS_chdir                        = 12  (PtrIn),
+	SYS_time                         = 13  (PtrOut),
+	SYS_mknod                        = 14  (PtrIn, Flags, Int),
+	SYS_chmod                        = 15  (PtrIn, Flags),
+	SYS_lchown                       = 16  (PtrIn, Int, Int),
+	SYS_oldstat                      = 18  (PtrIn, PtrOut),
+	SYS_lseek                        = 19  (Fd, Int, Int),
+	SYS_getpid                       = 20  (),
+	SYS_mount                        = 21  (PtrIn, PtrIn, PtrIn, Flags, PtrIn),
+	SYS_umount                       = 22  (PtrIn),
+	SYS_setuid                       = 23  (Int),
+	SYS_getuid                       = 24  (),
+	SYS_stime                        = 25  (PtrIn),
+	SYS_ptrace                       = 26  (Flags, Int, Addr, Int),
+	SYS_alarm                        = 27  (Int),
+	SYS_oldfstat                     = 28  (Fd, PtrOut),
+	SYS_pause                        = 29  (),
+	SYS_utime                        = 30  (PtrIn, PtrIn),
+	SYS_access                       = 33  (PtrIn, Flags),
+	SYS_nice                         = 34  (Int),
+	SYS_sync                         = 36  (),
+	SYS_kill                         = 37  (Int, Int),
+	SYS_rename                       = 38  (PtrIn, PtrIn),
+	SYS_mkdir                        = 39  (PtrIn, Flags),
+	SYS_rmdir                        = 40  (PtrIn),
+	SYS_dup                          = 41  (Fd),
+	SYS_pipe                         = 42  (PtrOut),
+	SYS_times                        = 43  (PtrOut),
+	SYS_brk                          = 45  (Addr),
+	SYS_setgid                       = 46  (Int),
+	SYS_getgid                       = 47  (),
+	SYS_signal                       = 48  (Int, Addr),
+	SYS_geteuid                      = 49  (),
+	SYS_getegid                      = 50  (),
+	SYS_acct                         = 51  (PtrIn),
+	SYS_umount2                      = 52  (PtrIn, Flags),
+	SYS_ioctl                        = 54  (Fd, Flags, Int),
+	SYS_fcntl                        = 55  (Fd, Flags, Int),
+	SYS_setpgid                      = 57  (Int, Int),
+	SYS_oldolduname                  = 59  (PtrOut),
+	SYS_umask                        = 60  (Flags),
+	SYS_chroot                       = 61  (PtrIn),
+	SYS_ustat                        = 62  (Int, PtrOut),
+	SYS_dup2                         = 63  (Fd, Fd),
+	SYS_getppid                      = 64  (),
+	SYS_getpgrp                      = 65  (),
+	SYS_setsid                       = 66  (),
+	SYS_sigaction                    = 67  (Int, PtrIn, PtrOut),
+	SYS_sgetmask                     = 68  (),
+	SYS_ssetmask                     = 69  (Flags),
+	SYS_setreuid                     = 70  (Int, Int),
+	SYS_setregid                     = 71  (Int, Int),
+	SYS_sigsuspend                   = 72  (Int, Int, Flags),
+	SYS_sigpending                   = 73  (PtrOut),
+	SYS_sethostname                  = 74  (PtrIn, Len),
+	SYS_setrlimit                    = 75  (Int, PtrIn),
+	SYS_getrlimit                    = 76  (Int, PtrOut),
+	SYS_getrusage                    = 77  (Int, PtrOut),
+	SYS_gettimeofday                 = 78  (PtrOut, PtrOut),
+	SYS_settimeofday                 = 79  (PtrIn, PtrIn),
+	SYS_getgroups                    = 80  (Len, PtrOut),
+	SYS_setgroups                    = 81  (Len, PtrIn),
+	SYS_select                       = 82  (PtrIn),
+	SYS_symlink                      = 83  (PtrIn, PtrIn),
+	SYS_oldlstat                     = 84  (PtrIn, PtrOut),
+	SYS_readlink                     = 85  (PtrIn, PtrOut, Len),
+	SYS_swapon                       = 87  (PtrIn, Flags),
+	SYS_reboot                       = 88  (Int, Int, Flags, PtrIn),
+	SYS_readdir                      = 89  (Fd, PtrOut, Int),
+	SYS_mmap                         = 90  (PtrIn),
+	SYS_munmap                       = 91  (Addr, Len),
+	SYS_truncate                     = 92  (PtrIn, Int),
+	SYS_ftruncate                    = 93  (Fd, Int),
+	SYS_fchmod                       = 94  (Fd, Flags),
+	SYS_fchown                       = 95  (Fd, Int, Int),
+	SYS_getpriority                  = 96  (Int, Int),
+	SYS_setpriority                  = 97  (Int, Int, Int),
+	SYS_statfs                       = 99  (PtrIn, PtrOut),
+	SYS_fstatfs                      = 100 (Fd, PtrOut),
+	SYS_ioperm                       = 101 (Int, Len, Int),
+	SYS_socketcall                   = 102 (Flags, PtrIn),
+	SYS_syslog                       = 103 (Flags, PtrOut, Len),
+	SYS_setitimer                    = 104 (Int, PtrIn, PtrOut),
+	SYS_getitimer                    = 105 (Int, PtrOut),
+	SYS_stat                         = 106 (PtrIn, PtrOut),
+	SYS_lstat                        = 107 (PtrIn, PtrOut),
+	SYS_fstat                        = 108 (Fd, PtrOut),
+	SYS_olduname                     = 109 (PtrOut),
+	SYS_iopl                         = 110 (Int),
+	SYS_vhangup                      = 111 (),
+	SYS_vm86old                      = 113 (PtrInOut),
+	SYS_wait4                        = 114 (Int, PtrOut, Flags, PtrOut),
+	SYS_swapoff                      = 115 (PtrIn),
+	SYS_sysinfo                      = 116 (PtrOut),
+	SYS_ipc                          = 117 (Flags, Int, Int, Int, PtrInOut, Int),
+	SYS_fsync                        = 118 (Fd),
+	SYS_sigreturn                    = 119 (),
+	SYS_clone                        = 120 (Flags, Addr, PtrOut, PtrIn, PtrOut),
+	SYS_setdomainname                = 121 (PtrIn, Len),
+	SYS_uname                        = 122 (PtrOut),
+	SYS_modify_ldt                   = 123 (Flags, PtrInOut, Len),
+	SYS_adjtimex                     = 124 (PtrInOut),
+	SYS_mprotect                     = 125 (Addr, Len, Flags),
+	SYS_sigprocmask                  = 126 (Int, PtrIn, PtrOut),
+	SYS_init_module                  = 128 (PtrIn, Len, PtrIn),
+	SYS_delete_module                = 129 (PtrIn, Flags),
+	SYS_quotactl                     = 131 (Flags, PtrIn, Int, PtrInOut),
+	SYS_getpgid                      = 132 (Int),
+	SYS_fchdir                       = 133 (Fd),
+	SYS_personality                  = 136 (Flags),
+	SYS_setfsuid                     = 138 (Int),
+	SYS_setfsgid                     = 139 (Int),
+	SYS__llseek                      = 140 (Fd, Int64High, Int64Low, PtrOut, Int),
+	SYS_getdents                     = 141 (Fd, PtrOut, Len),
+	SYS__newselect                   = 142 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut),
+	SYS_flock                        = 143 (Fd, Flags),
+	SYS_msync                        = 144 (Addr, Len, Flags),
+	SYS_readv                        = 145 (Fd, PtrOut, Len),
+	SYS_writev                       = 146 (Fd, PtrIn, Len),
+	SYS_getsid                       = 147 (Int),
+	SYS_fdatasync                    = 148 (Fd),
+	SYS_mlock                        = 150 (Addr, Len),
+	SYS_munlock                      = 151 (Addr, Len),
+	SYS_mlockall                     = 152 (Flags),
+	SYS_munlockall                   = 153 (),
+	SYS_sched_setparam               = 154 (Int, PtrIn),
+	SYS_sched_getparam               = 155 (Int, PtrOut),
+	SYS_sched_setscheduler           = 156 (Int, Int, PtrIn),
+	SYS_sched_getscheduler           = 157 (Int),
+	SYS_sched_yield                  = 158 (),
+	SYS_sched_get_priority_max       = 159 (Int),
+	SYS_sched_get_priority_min       = 160 (Int),
+	SYS_sched_rr_get_interval        = 161 (Int, PtrOut),
+	SYS_nanosleep                    = 162 (PtrIn, PtrOut),
+	SYS_mremap                       = 163 (Addr, Len, Len, Flags, Addr),
+	SYS_setresuid                    = 164 (Int, Int, Int),
+	SYS_getresuid                    = 165 (PtrOut, PtrOut, PtrOut),
+	SYS_vm86                         = 166 (Flags, PtrInOut),
+	SYS_poll                         = 168 (PtrInOut, Len, Int),
+	SYS_setresgid                    = 170 (Int, Int, Int),
+	SYS_getresgid                    = 171 (PtrOut, PtrOut, PtrOut),
+	SYS_prctl                        = 172 (Flags, Int, Int, Int, Int),
+	SYS_rt_sigreturn                 = 173 (),
+	SYS_rt_sigaction                 = 174 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigprocmask               = 175 (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigpending                = 176 (PtrOut, Len),
+	SYS_rt_sigtimedwait              = 177 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_rt_sigqueueinfo              = 178 (Int, Int, PtrIn),
+	SYS_rt_sigsuspend                = 179 (PtrIn, Len),
+	SYS_pread64                      = 180 (Fd, PtrOut, Len, Int64Low, Int64High),
+	SYS_pwrite64                     = 181 (Fd, PtrIn, Len, Int64Low, Int64High),
+	SYS_chown                        = 182 (PtrIn, Int, Int),
+	SYS_getcwd                       = 183 (PtrOut, Len),
+	SYS_capget                       = 184 (PtrInOut, PtrOut),
+	SYS_capset                       = 185 (PtrIn, PtrIn),
+	SYS_sigaltstack                  = 186 (PtrIn, PtrOut),
+	SYS_sendfile                     = 187 (Fd, Fd, PtrInOut, Len),
+	SYS_vfork                        = 190 (),
+	SYS_ugetrlimit                   = 191 (Int, PtrOut),
+	SYS_mmap2                        = 192 (Addr, Len, Flags, Flags, Fd, Int),
+	SYS_truncate64                   = 193 (PtrIn, Int64Low, Int64High),
+	SYS_ftruncate64                  = 194 (Fd, Int64Low, Int64High),
+	SYS_stat64                       = 195 (PtrIn, PtrOut),
+	SYS_lstat64                      = 196 (PtrIn, PtrOut),
+	SYS_fstat64                      = 197 (Fd, PtrOut),
+	SYS_lchown32                     = 198 (PtrIn, Int, Int),
+	SYS_getuid32                     = 199 (),
+	SYS_getgid32                     = 200 (),
+	SYS_geteuid32                    = 201 (),
+	SYS_getegid32                    = 202 (),
+	SYS_setreuid32                   = 203 (Int, Int),
+	SYS_setregid32                   = 204 (Int, Int),
+	SYS_getgroups32                  = 205 (Len, PtrOut),
+	SYS_setgroups32                  = 206 (Len, PtrIn),
+	SYS_fchown32                     = 207 (Fd, Int, Int),
+	SYS_setresuid32                  = 208 (Int, Int, Int),
+	SYS_getresuid32                  = 209 (PtrOut, PtrOut, PtrOut),
+	SYS_setresgid32                  = 210 (Int, Int, Int),
+	SYS_getresgid32                  = 211 (PtrOut, PtrOut, PtrOut),
+	SYS_chown32                      = 212 (PtrIn, Int, Int),
+	SYS_setuid32                     = 213 (Int),
+	SYS_setgid32                     = 214 (Int),
+	SYS_setfsuid32                   = 215 (Int),
+	SYS_setfsgid32                   = 216 (Int),
+	SYS_pivot_root                   = 217 (PtrIn, PtrIn),
+	SYS_mincore                      = 218 (Addr, Len, PtrOut),
+	SYS_madvise                      = 219 (Addr, Len, Flags),
+	SYS_getdents64                   = 220 (Fd, PtrOut, Len),
+	SYS_fcntl64                      = 221 (Fd, Flags, Int),
+	SYS_gettid                       = 224 (),
+	SYS_readahead                    = 225 (Fd, Int64Low, Int64High, Len),
+	SYS_setxattr                     = 226 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_lsetxattr                    = 227 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_fsetxattr                    = 228 (Fd, PtrIn, PtrIn, Len, Flags),
+	SYS_getxattr                     = 229 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_lgetxattr                    = 230 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_fgetxattr                    = 231 (Fd, PtrIn, PtrOut, Len),
+	SYS_listxattr                    = 232 (PtrIn, PtrOut, Len),
+	SYS_llistxattr                   = 233 (PtrIn, PtrOut, Len),
+	SYS_flistxattr                   = 234 (Fd, PtrOut, Len),
+	SYS_removexattr                  = 235 (PtrIn, PtrIn),
+	SYS_lremovexattr                 = 236 (PtrIn, PtrIn),
+	SYS_fremovexattr                 = 237 (Fd, PtrIn),
+	SYS_tkill                        = 238 (Int, Int),
+	SYS_sendfile64                   = 239 (Fd, Fd, PtrInOut, Len),
+	SYS_futex                        = 240 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_setaffinity            = 241 (Int, Len, PtrIn),
+	SYS_sched_getaffinity            = 242 (Int, Len, PtrOut),
+	SYS_set_thread_area              = 243 (PtrInOut),
+	SYS_get_thread_area              = 244 (PtrInOut),
+	SYS_io_setup                     = 245 (Len, PtrOut),
+	SYS_io_destroy                   = 246 (Int),
+	SYS_io_getevents                 = 247 (Int, Len, Len, PtrOut, PtrIn),
+	SYS_io_submit                    = 248 (Int, Len, PtrIn),
+	SYS_io_cancel                    = 249 (Int, PtrIn, PtrOut),
+	SYS_fadvise64                    = 250 (Fd, Int64Low, Int64High, Len, Int),
+	SYS_exit_group                   = 252 (Int),
+	SYS_lookup_dcookie               = 253 (Int64Low, Int64High, PtrOut, Len),
+	SYS_epoll_create                 = 254 (Int),
+	SYS_epoll_ctl                    = 255 (Fd, Flags, Fd, PtrIn),
+	SYS_epoll_wait                   = 256 (Fd, PtrOut, Len, Int),
+	SYS_set_tid_address              = 258 (Addr),
+	SYS_timer_create                 = 259 (Int, PtrIn, PtrOut),
+	SYS_timer_settime                = 260 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime                = 261 (Int, PtrOut),
+	SYS_timer_getoverrun             = 262 (Int),
+	SYS_timer_delete                 = 263 (Int),
+	SYS_clock_settime                = 264 (Int, PtrIn),
+	SYS_clock_gettime                = 265 (Int, PtrOut),
+	SYS_clock_getres                 = 266 (Int, PtrOut),
+	SYS_clock_nanosleep              = 267 (Int, Flags, PtrIn, PtrOut),
+	SYS_statfs64                     = 268 (PtrIn, Len, PtrOut),
+	SYS_fstatfs64                    = 269 (Fd, Len, PtrOut),
+	SYS_tgkill                       = 270 (Int, Int, Int),
+	SYS_utimes                       = 271 (PtrIn, PtrIn),
+	SYS_fadvise64_64                 = 272 (Fd, Int64Low, Int64High, Int64Low, Int64High, Int),
+	SYS_mbind                        = 274 (Addr, Len, Flags, PtrIn, Len, Flags),
+	SYS_get_mempolicy                = 275 (PtrOut, PtrOut, Len, Addr, Flags),
+	SYS_set_mempolicy                = 276 (Flags, PtrIn, Len),
+	SYS_mq_open                      = 277 (PtrIn, Flags, Flags, PtrIn),
+	SYS_mq_unlink                    = 278 (PtrIn),
+	SYS_mq_timedsend                 = 279 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive              = 280 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_mq_notify                    = 281 (Fd, PtrIn),
+	SYS_mq_getsetattr                = 282 (Fd, PtrIn, PtrOut),
+	SYS_kexec_load                   = 283 (Addr, Len, PtrIn, Flags),
+	SYS_waitid                       = 284 (Int, Int, PtrOut, Flags, PtrOut),
+	SYS_add_key                      = 286 (PtrIn, PtrIn, PtrIn, Len, Int),
+	SYS_request_key                  = 287 (PtrIn, PtrIn, PtrIn, Int),
+	SYS_keyctl                       = 288 (Flags, Int, Int, Int, Int),
+	SYS_ioprio_set                   = 289 (Int, Int, Int),
+	SYS_ioprio_get                   = 290 (Int, Int),
+	SYS_inotify_init                 = 291 (),
+	SYS_inotify_add_watch            = 292 (Fd, PtrIn, Flags),
+	SYS_inotify_rm_watch             = 293 (Fd, Int),
+	SYS_migrate_pages                = 294 (Int, Len, PtrIn, PtrIn),
+	SYS_openat                       = 295 (Fd, PtrIn, Flags, Flags),
+	SYS_mkdirat                      = 296 (Fd, PtrIn, Flags),
+	SYS_mknodat                      = 297 (Fd, PtrIn, Flags, Int),
+	SYS_fchownat                     = 298 (Fd, PtrIn, Int, Int, Flags),
+	SYS_futimesat                    = 299 (Fd, PtrIn, PtrIn),
+	SYS_fstatat64                    = 300 (Fd, PtrIn, PtrOut, Flags),
+	SYS_unlinkat                     = 301 (Fd, PtrIn, Flags),
+	SYS_renameat                     = 302 (Fd, PtrIn, Fd, PtrIn),
+	SYS_linkat                       = 303 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_symlinkat                    = 304 (PtrIn, Fd, PtrIn),
+	SYS_readlinkat                   = 305 (Fd, PtrIn, PtrOut, Len),
+	SYS_fchmodat                     = 306 (Fd, PtrIn, Flags),
+	SYS_faccessat                    = 307 (Fd, PtrIn, Flags),
+	SYS_pselect6                     = 308 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll                        = 309 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_unshare                      = 310 (Flags),
+	SYS_set_robust_list              = 311 (Addr, Len),
+	SYS_get_robust_list              = 312 (Int, PtrOut, PtrOut),
+	SYS_splice                       = 313 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_sync_file_range              = 314 (Fd, Int64Low, Int64High, Int64Low, Int64High, Flags),
+	SYS_tee                          = 315 (Fd, Fd, Len, Flags),
+	SYS_vmsplice                     = 316 (Fd, PtrIn, Len, Flags),
+	SYS_move_pages                   = 317 (Int, Len, PtrIn, PtrIn, PtrOut, Flags),
+	SYS_getcpu                       = 318 (PtrOut, PtrOut, PtrOut),
+	SYS_epoll_pwait                  = 319 (Fd, PtrOut, Len, Int, PtrIn, Len),
+	SYS_utimensat                    = 320 (Fd, PtrIn, PtrIn, Flags),
+	SYS_signalfd                     = 321 (Fd, PtrIn, Len),
+	SYS_timerfd_create               = 322 (Int, Flags),
+	SYS_eventfd                      = 323 (Int),
+	SYS_fallocate                    = 324 (Fd, Flags, Int64Low, Int64High, Int64Low, Int64High),
+	SYS_timerfd_settime              = 325 (Fd, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime              = 326 (Fd, PtrOut),
+	SYS_signalfd4                    = 327 (Fd, PtrIn, Len, Flags),
+	SYS_eventfd2                     = 328 (Int, Flags),
+	SYS_epoll_create1                = 329 (Flags),
+	SYS_dup3                         = 330 (Fd, Fd, Flags),
+	SYS_pipe2                        = 331 (PtrOut, Flags),
+	SYS_inotify_init1                = 332 (Flags),
+	SYS_preadv                       = 333 (Fd, PtrOut, Len, Int64Low, Int64High),
+	SYS_pwritev                      = 334 (Fd, PtrIn, Len, Int64Low, Int64High),
+	SYS_rt_tgsigqueueinfo            = 335 (Int, Int, Int, PtrIn),
+	SYS_perf_event_open              = 336 (PtrInOut, Int, Int, Fd, Flags),
+	SYS_recvmmsg                     = 337 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_fanotify_init                = 338 (Flags, Flags),
+	SYS_fanotify_mark                = 339 (Fd, Flags, Int64Low, Int64High, Fd, PtrIn),
+	SYS_prlimit64                    = 340 (Int, Int, PtrIn, PtrOut),
+	SYS_name_to_handle_at            = 341 (Fd, PtrIn, PtrInOut, PtrOut, Flags),
+	SYS_open_by_handle_at            = 342 (Fd, PtrIn, Flags),
+	SYS_clock_adjtime                = 343 (Int, PtrInOut),
+	SYS_syncfs                       = 344 (Fd),
+	SYS_sendmmsg                     = 345 (Fd, PtrInOut, Len, Flags),
+	SYS_setns                        = 346 (Fd, Flags),
+	SYS_process_vm_readv             = 347 (Int, PtrOut, Len, PtrIn, Len, Flags),
+	SYS_process_vm_writev            = 348 (Int, PtrIn, Len, PtrIn, Len, Flags),
+	SYS_kcmp                         = 349 (Int, Int, Int, Int, Int),
+	SYS_finit_module                 = 350 (Fd, PtrIn, Flags),
+	SYS_sched_setattr                = 351 (Int, PtrIn, Flags),
+	SYS_sched_getattr                = 352 (Int, PtrOut, Len, Flags),
+	SYS_renameat2                    = 353 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_seccomp                      = 354 (Flags, Flags, PtrInOut),
+	SYS_getrandom                    = 355 (PtrOut, Len, Flags),
+	SYS_memfd_create                 = 356 (PtrIn, Flags),
+	SYS_bpf                          = 357 (Flags, PtrInOut, Len),
+	SYS_execveat                     = 358 (Fd, PtrIn, PtrIn, PtrIn, Flags),
+	SYS_socket                       = 359 (Int, Flags, Int),
+	SYS_socketpair                   = 360 (Int, Flags, Int, PtrOut),
+	SYS_bind                         = 361 (Fd, PtrIn, Len),
+	SYS_connect                      = 362 (Fd, PtrIn, Len),
+	SYS_listen                       = 363 (Fd, Int),
+	SYS_accept4                      = 364 (Fd, PtrOut, PtrInOut, Flags),
+	SYS_getsockopt                   = 365 (Fd, Int, Int, PtrOut, PtrInOut),
+	SYS_setsockopt                   = 366 (Fd, Int, Int, PtrIn, Len),
+	SYS_getsockname                  = 367 (Fd, PtrOut, PtrInOut),
+	SYS_getpeername                  = 368 (Fd, PtrOut, PtrInOut),
+	SYS_sendto                       = 369 (Fd, PtrIn, Len, Flags, PtrIn, Len),
+	SYS_sendmsg                      = 370 (Fd, PtrIn, Flags),
+	SYS_recvfrom                     = 371 (Fd, PtrOut, Len, Flags, PtrOut, PtrInOut),
+	SYS_recvmsg                      = 372 (Fd, PtrInOut, Flags),
+	SYS_shutdown                     = 373 (Fd, Int),
+	SYS_userfaultfd                  = 374 (Flags),
+	SYS_membarrier                   = 375 (Flags, Flags, Int),
+	SYS_mlock2                       = 376 (Addr, Len, Flags),
+	SYS_copy_file_range              = 377 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_preadv2                      = 378 (Fd, PtrOut, Len, Int64Low, Int64High, Flags),
+	SYS_pwritev2                     = 379 (Fd, PtrIn, Len, Int64Low, Int64High, Flags),
+	SYS_pkey_mprotect                = 380 (Addr, Len, Flags, Int),
+	SYS_pkey_alloc                   = 381 (Flags, Flags),
+	SYS_pkey_free                    = 382 (Int),
+	SYS_statx                        = 383 (Fd, PtrIn, Flags, Flags, PtrOut),
+	SYS_arch_prctl                   = 384 (Flags, Addr),
+	SYS_io_pgetevents                = 385 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_rseq                         = 386 (PtrInOut, Len, Flags, Int),
+	SYS_semget                       = 393 (Int, Len, Flags),
+	SYS_semctl                       = 394 (Int, Int, Flags, Int),
+	SYS_shmget                       = 395 (Int, Len, Flags),
+	SYS_shmctl                       = 396 (Int, Flags, PtrInOut),
+	SYS_shmat                        = 397 (Int, Addr, Flags),
+	SYS_shmdt                        = 398 (Addr),
+	SYS_msgget                       = 399 (Int, Flags),
+	SYS_msgsnd                       = 400 (Int, PtrIn, Len, Flags),
+	SYS_msgrcv                       = 401 (Int, PtrOut, Len, Int, Flags),
+	SYS_msgctl                       = 402 (Int, Flags, PtrInOut),
+	SYS_clock_gettime64              = 403 (Int, PtrOut),
+	SYS_clock_settime64              = 404 (Int, PtrIn),
+	SYS_clock_adjtime64              = 405 (Int, PtrInOut),
+	SYS_clock_getres_time64          = 406 (Int, PtrOut),
+	SYS_clock_nanosleep_time64       = 407 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime64              = 408 (Int, PtrOut),
+	SYS_timer_settime64              = 409 (Int, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime64            = 410 (Fd, PtrOut),
+	SYS_timerfd_settime64            = 411 (Fd, Flags, PtrIn, PtrOut),
+	SYS_utimensat_time64             = 412 (Fd, PtrIn, PtrIn, Flags),
+	SYS_pselect6_time64              = 413 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll_time64                 = 414 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_io_pgetevents_time64         = 416 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_recvmmsg_time64              = 417 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_mq_timedsend_time64          = 418 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive_time64       = 419 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_semtimedop_time64            = 420 (Int, PtrIn, Len, PtrIn),
+	SYS_rt_sigtimedwait_time64       = 421 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_futex_time64                 = 422 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_rr_get_interval_time64 = 423 (Int, PtrOut),
+	SYS_pidfd_send_signal            = 424 (Fd, Int, PtrIn, Flags),
+	SYS_io_uring_setup               = 425 (Len, PtrInOut),
+	SYS_io_uring_enter               = 426 (Fd, Len, Len, Flags, PtrIn, Len),
+	SYS_io_uring_register            = 427 (Fd, Flags, PtrInOut, Len),
+	SYS_open_tree                    = 428 (Fd, PtrIn, Flags),
+	SYS_move_mount                   = 429 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_fsopen                       = 430 (PtrIn, Flags),
+	SYS_fsconfig                     = 431 (Fd, Flags, PtrIn, PtrIn, Int),
+	SYS_fsmount                      = 432 (Fd, Flags, Flags),
+	SYS_fspick                       = 433 (Fd, PtrIn, Flags),
+	SYS_pidfd_open                   = 434 (Int, Flags),
+	SYS_clone3                       = 435 (PtrIn, Len),
+	SYS_close_range                  = 436 (Fd, Fd, Flags),
+	SYS_openat2                      = 437 (Fd, PtrIn, PtrIn, Len),
+	SYS_pidfd_getfd                  = 438 (Fd, Fd, Flags),
+	SYS_faccessat2                   = 439 (Fd, PtrIn, Flags, Flags),
+	SYS_process_madvise              = 440 (Fd, PtrIn, Len, Flags, Flags),
+	SYS_epoll_pwait2                 = 441 (Fd, PtrOut, Len, PtrIn, PtrIn, Len),
+	SYS_mount_setattr                = 442 (Fd, PtrIn, Flags, PtrIn, Len),
+	SYS_quotactl_fd                  = 443 (Fd, Flags, Int, PtrInOut),
+	SYS_landlock_create_ruleset      = 444 (PtrIn, Len, Flags),
+	SYS_landlock_add_rule            = 445 (Fd, Int, PtrIn, Flags),
+	SYS_landlock_restrict_self       = 446 (Fd, Flags),
+	SYS_memfd_secret                 = 447 (Flags),
+	SYS_process_mrelease             = 448 (Fd, Flags),
+	SYS_futex_waitv                  = 449 (PtrIn, Len, Flags, PtrIn, Int),
+	SYS_set_mempolicy_home_node      = 450 (Addr, Len, Int, Flags),
 }
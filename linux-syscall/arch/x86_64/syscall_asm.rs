@@ -38,111 +38,187 @@ impl Result {
 single_register_result64!(Result);
 
 #[cfg(not(doc))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! syscall {
-	($nr:expr $(,)?) => {{
+macro_rules! __syscall_with_options {
+	([$($opt:ident),*] $nr:expr $(,)?) => {{
 		let mut out_rax;
 		core::arch::asm!(
 			"syscall",
-			in("rax") u32::from(Into::<$crate::Syscall>::into($nr)),
+			in("rax") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
 			out("rcx") _,
 			out("r11") _,
 			lateout("rax") out_rax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86_64::Result::new(out_rax)
 	}};
-	($nr:expr, $a1:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr $(,)?) => {{
 		let mut out_rax;
 		core::arch::asm!(
 			"syscall",
-			in("rax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("rdi") $a1,
+			in("rax") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
 			out("rcx") _,
 			out("r11") _,
 			lateout("rax") out_rax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86_64::Result::new(out_rax)
 	}};
-	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr $(,)?) => {{
 		let mut out_rax;
 		core::arch::asm!(
 			"syscall",
-			in("rax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("rdi") $a1,
-			in("rsi") $a2,
+			in("rax") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
 			out("rcx") _,
 			out("r11") _,
 			lateout("rax") out_rax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86_64::Result::new(out_rax)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
 		let mut out_rax;
 		core::arch::asm!(
 			"syscall",
-			in("rax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("rdi") $a1,
-			in("rsi") $a2,
-			in("rdx") $a3,
+			in("rax") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
 			out("rcx") _,
 			out("r11") _,
 			lateout("rax") out_rax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86_64::Result::new(out_rax)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
 		let mut out_rax;
 		core::arch::asm!(
 			"syscall",
-			in("rax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("rdi") $a1,
-			in("rsi") $a2,
-			in("rdx") $a3,
-			in("r10") $a4,
+			in("rax") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
 			out("rcx") _,
 			out("r11") _,
 			lateout("rax") out_rax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86_64::Result::new(out_rax)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
 		let mut out_rax;
 		core::arch::asm!(
 			"syscall",
-			in("rax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("rdi") $a1,
-			in("rsi") $a2,
-			in("rdx") $a3,
-			in("r10") $a4,
-			in("r8")  $a5,
+			in("rax") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r8")  $crate::IntoSyscallArg::into_syscall_arg($a5),
 			out("rcx") _,
 			out("r11") _,
 			lateout("rax") out_rax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86_64::Result::new(out_rax)
 	}};
-	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+	([$($opt:ident),*] $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
 		let mut out_rax;
 		core::arch::asm!(
 			"syscall",
-			in("rax") u32::from(Into::<$crate::Syscall>::into($nr)),
-			in("rdi") $a1,
-			in("rsi") $a2,
-			in("rdx") $a3,
-			in("r10") $a4,
-			in("r8")  $a5,
-			in("r9")  $a6,
+			in("rax") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r8")  $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("r9")  $crate::IntoSyscallArg::into_syscall_arg($a6),
 			out("rcx") _,
 			out("r11") _,
 			lateout("rax") out_rax,
-			options(nostack),
+			options(nostack, preserves_flags, $($opt),*),
 		);
 		$crate::arch::x86_64::Result::new(out_rax)
 	}};
 }
+
+#[cfg(not(doc))]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($nr:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<0>::into_syscall($nr)),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<1>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<2>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<3>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<4>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<5>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r8")  $crate::IntoSyscallArg::into_syscall_arg($a5),
+			options(noreturn, nostack),
+		)
+	}};
+	($nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {{
+		core::arch::asm!(
+			"syscall",
+			in("rax") u32::from($crate::IntoSyscall::<6>::into_syscall($nr)),
+			in("rdi") $crate::IntoSyscallArg::into_syscall_arg($a1),
+			in("rsi") $crate::IntoSyscallArg::into_syscall_arg($a2),
+			in("rdx") $crate::IntoSyscallArg::into_syscall_arg($a3),
+			in("r10") $crate::IntoSyscallArg::into_syscall_arg($a4),
+			in("r8")  $crate::IntoSyscallArg::into_syscall_arg($a5),
+			in("r9")  $crate::IntoSyscallArg::into_syscall_arg($a6),
+			options(noreturn, nostack),
+		)
+	}};
+}
@@ -16,349 +16,349 @@
 #![allow(non_upper_case_globals)]
 
 syscall_constants! {
-	SYS_read                    = 0,
-	SYS_write                   = 1,
-	SYS_open                    = 2,
-	SYS_close                   = 3,
-	SYS_stat                    = 4,
-	SYS_fstat                   = 5,
-	SYS_lstat                   = 6,
-	SYS_poll                    = 7,
-	SYS_lseek                   = 8,
-	SYS_mmap                    = 9,
-	SYS_mprotect                = 10,
-	SYS_munmap                  = 11,
-	SYS_brk                     = 12,
-	SYS_rt_sigaction            = 13,
-	SYS_rt_sigprocmask          = 14,
-	SYS_rt_sigreturn            = 15,
-	SYS_ioctl                   = 16,
-	SYS_pread64                 = 17,
-	SYS_pwrite64                = 18,
-	SYS_readv                   = 19,
-	SYS_writev                  = 20,
-	SYS_access                  = 21,
-	SYS_pipe                    = 22,
-	SYS_select                  = 23,
-	SYS_sched_yield             = 24,
-	SYS_mremap                  = 25,
-	SYS_msync                   = 26,
-	SYS_mincore                 = 27,
-	SYS_madvise                 = 28,
-	SYS_shmget                  = 29,
-	SYS_shmat                   = 30,
-	SYS_shmctl                  = 31,
-	SYS_dup                     = 32,
-	SYS_dup2                    = 33,
-	SYS_pause                   = 34,
-	SYS_nanosleep               = 35,
-	SYS_getitimer               = 36,
-	SYS_alarm                   = 37,
-	SYS_setitimer               = 38,
-	SYS_getpid                  = 39,
-	SYS_sendfile                = 40,
-	SYS_socket                  = 41,
-	SYS_connect                 = 42,
-	SYS_accept                  = 43,
-	SYS_sendto                  = 44,
-	SYS_recvfrom                = 45,
-	SYS_sendmsg                 = 46,
-	SYS_recvmsg                 = 47,
-	SYS_shutdown                = 48,
-	SYS_bind                    = 49,
-	SYS_listen                  = 50,
-	SYS_getsockname             = 51,
-	SYS_getpeername             = 52,
-	SYS_socketpair              = 53,
-	SYS_setsockopt              = 54,
-	SYS_getsockopt              = 55,
-	SYS_clone                   = 56,
-	SYS_fork                    = 57,
-	SYS_vfork                   = 58,
-	SYS_execve                  = 59,
-	SYS_exit                    = 60,
-	SYS_wait4                   = 61,
-	SYS_kill                    = 62,
-	SYS_uname                   = 63,
-	SYS_semget                  = 64,
-	SYS_semop                   = 65,
-	SYS_semctl                  = 66,
-	SYS_shmdt                   = 67,
-	SYS_msgget                  = 68,
-	SYS_msgsnd                  = 69,
-	SYS_msgrcv                  = 70,
-	SYS_msgctl                  = 71,
-	SYS_fcntl                   = 72,
-	SYS_flock                   = 73,
-	SYS_fsync                   = 74,
-	SYS_fdatasync               = 75,
-	SYS_truncate                = 76,
-	SYS_ftruncate               = 77,
-	SYS_getdents                = 78,
-	SYS_getcwd                  = 79,
-	SYS_chdir                   = 80,
-	SYS_fchdir                  = 81,
-	SYS_rename                  = 82,
-	SYS_mkdir                   = 83,
-	SYS_rmdir                   = 84,
-	SYS_creat                   = 85,
-	SYS_link                    = 86,
-	SYS_unlink                  = 87,
-	SYS_symlink                 = 88,
-	SYS_readlink                = 89,
-	SYS_chmod                   = 90,
-	SYS_fchmod                  = 91,
-	SYS_chown                   = 92,
-	SYS_fchown                  = 93,
-	SYS_lchown                  = 94,
-	SYS_umask                   = 95,
-	SYS_gettimeofday            = 96,
-	SYS_getrlimit               = 97,
-	SYS_getrusage               = 98,
-	SYS_sysinfo                 = 99,
-	SYS_times                   = 100,
-	SYS_ptrace                  = 101,
-	SYS_getuid                  = 102,
-	SYS_syslog                  = 103,
-	SYS_getgid                  = 104,
-	SYS_setuid                  = 105,
-	SYS_setgid                  = 106,
-	SYS_geteuid                 = 107,
-	SYS_getegid                 = 108,
-	SYS_setpgid                 = 109,
-	SYS_getppid                 = 110,
-	SYS_getpgrp                 = 111,
-	SYS_setsid                  = 112,
-	SYS_setreuid                = 113,
-	SYS_setregid                = 114,
-	SYS_getgroups               = 115,
-	SYS_setgroups               = 116,
-	SYS_setresuid               = 117,
-	SYS_getresuid               = 118,
-	SYS_setresgid               = 119,
-	SYS_getresgid               = 120,
-	SYS_getpgid                 = 121,
-	SYS_setfsuid                = 122,
-	SYS_setfsgid                = 123,
-	SYS_getsid                  = 124,
-	SYS_capget                  = 125,
-	SYS_capset                  = 126,
-	SYS_rt_sigpending           = 127,
-	SYS_rt_sigtimedwait         = 128,
-	SYS_rt_sigqueueinfo         = 129,
-	SYS_rt_sigsuspend           = 130,
-	SYS_sigaltstack             = 131,
-	SYS_utime                   = 132,
-	SYS_mknod                   = 133,
-	SYS_personality             = 135,
-	SYS_ustat                   = 136,
-	SYS_statfs                  = 137,
-	SYS_fstatfs                 = 138,
-	SYS_getpriority             = 140,
-	SYS_setpriority             = 141,
-	SYS_sched_setparam          = 142,
-	SYS_sched_getparam          = 143,
-	SYS_sched_setscheduler      = 144,
-	SYS_sched_getscheduler      = 145,
-	SYS_sched_get_priority_max  = 146,
-	SYS_sched_get_priority_min  = 147,
-	SYS_sched_rr_get_interval   = 148,
-	SYS_mlock                   = 149,
-	SYS_munlock                 = 150,
-	SYS_mlockall                = 151,
-	SYS_munlockall              = 152,
-	SYS_vhangup                 = 153,
-	SYS_modify_ldt              = 154,
-	SYS_pivot_root              = 155,
-	SYS_prctl                   = 157,
-	SYS_arch_prctl              = 158,
-	SYS_adjtimex                = 159,
-	SYS_setrlimit               = 160,
-	SYS_chroot                  = 161,
-	SYS_sync                    = 162,
-	SYS_acct                    = 163,
-	SYS_settimeofday            = 164,
-	SYS_mount                   = 165,
-	SYS_umount2                 = 166,
-	SYS_swapon                  = 167,
-	SYS_swapoff                 = 168,
-	SYS_reboot                  = 169,
-	SYS_sethostname             = 170,
-	SYS_setdomainname           = 171,
-	SYS_iopl                    = 172,
-	SYS_ioperm                  = 173,
-	SYS_init_module             = 175,
-	SYS_delete_module           = 176,
-	SYS_quotactl                = 179,
-	SYS_gettid                  = 186,
-	SYS_readahead               = 187,
-	SYS_setxattr                = 188,
-	SYS_lsetxattr               = 189,
-	SYS_fsetxattr               = 190,
-	SYS_getxattr                = 191,
-	SYS_lgetxattr               = 192,
-	SYS_fgetxattr               = 193,
-	SYS_listxattr               = 194,
-	SYS_llistxattr              = 195,
-	SYS_flistxattr              = 196,
-	SYS_removexattr             = 197,
-	SYS_lremovexattr            = 198,
-	SYS_fremovexattr            = 199,
-	SYS_tkill                   = 200,
-	SYS_time                    = 201,
-	SYS_futex                   = 202,
-	SYS_sched_setaffinity       = 203,
-	SYS_sched_getaffinity       = 204,
-	SYS_set_thread_area         = 205,
-	SYS_io_setup                = 206,
-	SYS_io_destroy              = 207,
-	SYS_io_getevents            = 208,
-	SYS_io_submit               = 209,
-	SYS_io_cancel               = 210,
-	SYS_get_thread_area         = 211,
-	SYS_lookup_dcookie          = 212,
-	SYS_epoll_create            = 213,
-	SYS_getdents64              = 217,
-	SYS_set_tid_address         = 218,
-	SYS_semtimedop              = 220,
-	SYS_fadvise64               = 221,
-	SYS_timer_create            = 222,
-	SYS_timer_settime           = 223,
-	SYS_timer_gettime           = 224,
-	SYS_timer_getoverrun        = 225,
-	SYS_timer_delete            = 226,
-	SYS_clock_settime           = 227,
-	SYS_clock_gettime           = 228,
-	SYS_clock_getres            = 229,
-	SYS_clock_nanosleep         = 230,
-	SYS_exit_group              = 231,
-	SYS_epoll_wait              = 232,
-	SYS_epoll_ctl               = 233,
-	SYS_tgkill                  = 234,
-	SYS_utimes                  = 235,
-	SYS_mbind                   = 237,
-	SYS_set_mempolicy           = 238,
-	SYS_get_mempolicy           = 239,
-	SYS_mq_open                 = 240,
-	SYS_mq_unlink               = 241,
-	SYS_mq_timedsend            = 242,
-	SYS_mq_timedreceive         = 243,
-	SYS_mq_notify               = 244,
-	SYS_mq_getsetattr           = 245,
-	SYS_kexec_load              = 246,
-	SYS_waitid                  = 247,
-	SYS_add_key                 = 248,
-	SYS_request_key             = 249,
-	SYS_keyctl                  = 250,
-	SYS_ioprio_set              = 251,
-	SYS_ioprio_get              = 252,
-	SYS_inotify_init            = 253,
-	SYS_inotify_add_watch       = 254,
-	SYS_inotify_rm_watch        = 255,
-	SYS_migrate_pages           = 256,
-	SYS_openat                  = 257,
-	SYS_mkdirat                 = 258,
-	SYS_mknodat                 = 259,
-	SYS_fchownat                = 260,
-	SYS_futimesat               = 261,
-	SYS_newfstatat              = 262,
-	SYS_unlinkat                = 263,
-	SYS_renameat                = 264,
-	SYS_linkat                  = 265,
-	SYS_symlinkat               = 266,
-	SYS_readlinkat              = 267,
-	SYS_fchmodat                = 268,
-	SYS_faccessat               = 269,
-	SYS_pselect6                = 270,
-	SYS_ppoll                   = 271,
-	SYS_unshare                 = 272,
-	SYS_set_robust_list         = 273,
-	SYS_get_robust_list         = 274,
-	SYS_splice                  = 275,
-	SYS_tee                     = 276,
-	SYS_sync_file_range         = 277,
-	SYS_vmsplice                = 278,
-	SYS_move_pages              = 279,
-	SYS_utimensat               = 280,
-	SYS_epoll_pwait             = 281,
-	SYS_signalfd                = 282,
-	SYS_timerfd_create          = 283,
-	SYS_eventfd                 = 284,
-	SYS_fallocate               = 285,
-	SYS_timerfd_settime         = 286,
-	SYS_timerfd_gettime         = 287,
-	SYS_accept4                 = 288,
-	SYS_signalfd4               = 289,
-	SYS_eventfd2                = 290,
-	SYS_epoll_create1           = 291,
-	SYS_dup3                    = 292,
-	SYS_pipe2                   = 293,
-	SYS_inotify_init1           = 294,
-	SYS_preadv                  = 295,
-	SYS_pwritev                 = 296,
-	SYS_rt_tgsigqueueinfo       = 297,
-	SYS_perf_event_open         = 298,
-	SYS_recvmmsg                = 299,
-	SYS_fanotify_init           = 300,
-	SYS_fanotify_mark           = 301,
-	SYS_prlimit64               = 302,
-	SYS_name_to_handle_at       = 303,
-	SYS_open_by_handle_at       = 304,
-	SYS_clock_adjtime           = 305,
-	SYS_syncfs                  = 306,
-	SYS_sendmmsg                = 307,
-	SYS_setns                   = 308,
-	SYS_getcpu                  = 309,
-	SYS_process_vm_readv        = 310,
-	SYS_process_vm_writev       = 311,
-	SYS_kcmp                    = 312,
-	SYS_finit_module            = 313,
-	SYS_sched_setattr           = 314,
-	SYS_sched_getattr           = 315,
-	SYS_renameat2               = 316,
-	SYS_seccomp                 = 317,
-	SYS_getrandom               = 318,
-	SYS_memfd_create            = 319,
-	SYS_kexec_file_load         = 320,
-	SYS_bpf                     = 321,
-	SYS_execveat                = 322,
-	SYS_userfaultfd             = 323,
-	SYS_membarrier              = 324,
-	SYS_mlock2                  = 325,
-	SYS_copy_file_range         = 326,
-	SYS_preadv2                 = 327,
-	SYS_pwritev2                = 328,
-	SYS_pkey_mprotect           = 329,
-	SYS_pkey_alloc              = 330,
-	SYS_pkey_free               = 331,
-	SYS_statx                   = 332,
-	SYS_io_pgetevents           = 333,
-	SYS_rseq                    = 334,
-	SYS_pidfd_send_signal       = 424,
-	SYS_io_uring_setup          = 425,
-	SYS_io_uring_enter          = 426,
-	SYS_io_uring_register       = 427,
-	SYS_open_tree               = 428,
-	SYS_move_mount              = 429,
-	SYS_fsopen                  = 430,
-	SYS_fsconfig                = 431,
-	SYS_fsmount                 = 432,
-	SYS_fspick                  = 433,
-	SYS_pidfd_open              = 434,
-	SYS_clone3                  = 435,
-	SYS_close_range             = 436,
-	SYS_openat2                 = 437,
-	SYS_pidfd_getfd             = 438,
-	SYS_faccessat2              = 439,
-	SYS_process_madvise         = 440,
-	SYS_epoll_pwait2            = 441,
-	SYS_mount_setattr           = 442,
-	SYS_quotactl_fd             = 443,
-	SYS_landlock_create_ruleset = 444,
-	SYS_landlock_add_rule       = 445,
-	SYS_landlock_restrict_self  = 446,
-	SYS_memfd_secret            = 447,
-	SYS_process_mrelease        = 448,
-	SYS_futex_waitv             = 449,
-	SYS_set_mempolicy_home_node = 450,
+	SYS_read                    = 0   (Fd, PtrOut, Len),
+	SYS_write                   = 1   (Fd, PtrIn, Len),
+	SYS_open                    = 2   (PtrIn, Flags, Flags),
+	SYS_close                   = 3   (Fd),
+	SYS_stat                    = 4   (PtrIn, PtrOut),
+	SYS_fstat                   = 5   (Fd, PtrOut),
+	SYS_lstat                   = 6   (PtrIn, PtrOut),
+	SYS_poll                    = 7   (PtrInOut, Len, Int),
+	SYS_lseek                   = 8   (Fd, Int64, Int),
+	SYS_mmap                    = 9   (Addr, Len, Flags, Flags, Fd, Int64),
+	SYS_mprotect                = 10  (Addr, Len, Flags),
+	SYS_munmap                  = 11  (Addr, Len),
+	SYS_brk                     = 12  (Addr),
+	SYS_rt_sigaction            = 13  (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigprocmask          = 14  (Int, PtrIn, PtrOut, Len),
+	SYS_rt_sigreturn            = 15  (),
+	SYS_ioctl                   = 16  (Fd, Flags, Int),
+	SYS_pread64                 = 17  (Fd, PtrOut, Len, Int64),
+	SYS_pwrite64                = 18  (Fd, PtrIn, Len, Int64),
+	SYS_readv                   = 19  (Fd, PtrOut, Len),
+	SYS_writev                  = 20  (Fd, PtrIn, Len),
+	SYS_access                  = 21  (PtrIn, Flags),
+	SYS_pipe                    = 22  (PtrOut),
+	SYS_select                  = 23  (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut),
+	SYS_sched_yield             = 24  (),
+	SYS_mremap                  = 25  (Addr, Len, Len, Flags, Addr),
+	SYS_msync                   = 26  (Addr, Len, Flags),
+	SYS_mincore                 = 27  (Addr, Len, PtrOut),
+	SYS_madvise                 = 28  (Addr, Len, Flags),
+	SYS_shmget                  = 29  (Int, Len, Flags),
+	SYS_shmat                   = 30  (Int, Addr, Flags),
+	SYS_shmctl                  = 31  (Int, Flags, PtrInOut),
+	SYS_dup                     = 32  (Fd),
+	SYS_dup2                    = 33  (Fd, Fd),
+	SYS_pause                   = 34  (),
+	SYS_nanosleep               = 35  (PtrIn, PtrOut),
+	SYS_getitimer               = 36  (Int, PtrOut),
+	SYS_alarm                   = 37  (Int),
+	SYS_setitimer               = 38  (Int, PtrIn, PtrOut),
+	SYS_getpid                  = 39  (),
+	SYS_sendfile                = 40  (Fd, Fd, PtrInOut, Len),
+	SYS_socket                  = 41  (Int, Flags, Int),
+	SYS_connect                 = 42  (Fd, PtrIn, Len),
+	SYS_accept                  = 43  (Fd, PtrOut, PtrInOut),
+	SYS_sendto                  = 44  (Fd, PtrIn, Len, Flags, PtrIn, Len),
+	SYS_recvfrom                = 45  (Fd, PtrOut, Len, Flags, PtrOut, PtrInOut),
+	SYS_sendmsg                 = 46  (Fd, PtrIn, Flags),
+	SYS_recvmsg                 = 47  (Fd, PtrInOut, Flags),
+	SYS_shutdown                = 48  (Fd, Int),
+	SYS_bind                    = 49  (Fd, PtrIn, Len),
+	SYS_listen                  = 50  (Fd, Int),
+	SYS_getsockname             = 51  (Fd, PtrOut, PtrInOut),
+	SYS_getpeername             = 52  (Fd, PtrOut, PtrInOut),
+	SYS_socketpair              = 53  (Int, Flags, Int, PtrOut),
+	SYS_setsockopt              = 54  (Fd, Int, Int, PtrIn, Len),
+	SYS_getsockopt              = 55  (Fd, Int, Int, PtrOut, PtrInOut),
+	SYS_clone                   = 56  (Flags, Addr, PtrOut, PtrOut, Addr),
+	SYS_fork                    = 57  (),
+	SYS_vfork                   = 58  (),
+	SYS_execve                  = 59  (PtrIn, PtrIn, PtrIn),
+	SYS_exit                    = 60  (Int),
+	SYS_wait4                   = 61  (Int, PtrOut, Flags, PtrOut),
+	SYS_kill                    = 62  (Int, Int),
+	SYS_uname                   = 63  (PtrOut),
+	SYS_semget                  = 64  (Int, Len, Flags),
+	SYS_semop                   = 65  (Int, PtrIn, Len),
+	SYS_semctl                  = 66  (Int, Int, Flags, Int),
+	SYS_shmdt                   = 67  (Addr),
+	SYS_msgget                  = 68  (Int, Flags),
+	SYS_msgsnd                  = 69  (Int, PtrIn, Len, Flags),
+	SYS_msgrcv                  = 70  (Int, PtrOut, Len, Int, Flags),
+	SYS_msgctl                  = 71  (Int, Flags, PtrInOut),
+	SYS_fcntl                   = 72  (Fd, Flags, Int),
+	SYS_flock                   = 73  (Fd, Flags),
+	SYS_fsync                   = 74  (Fd),
+	SYS_fdatasync               = 75  (Fd),
+	SYS_truncate                = 76  (PtrIn, Int64),
+	SYS_ftruncate               = 77  (Fd, Int64),
+	SYS_getdents                = 78  (Fd, PtrOut, Len),
+	SYS_getcwd                  = 79  (PtrOut, Len),
+	SYS_chdir                   = 80  (PtrIn),
+	SYS_fchdir                  = 81  (Fd),
+	SYS_rename                  = 82  (PtrIn, PtrIn),
+	SYS_mkdir                   = 83  (PtrIn, Flags),
+	SYS_rmdir                   = 84  (PtrIn),
+	SYS_creat                   = 85  (PtrIn, Flags),
+	SYS_link                    = 86  (PtrIn, PtrIn),
+	SYS_unlink                  = 87  (PtrIn),
+	SYS_symlink                 = 88  (PtrIn, PtrIn),
+	SYS_readlink                = 89  (PtrIn, PtrOut, Len),
+	SYS_chmod                   = 90  (PtrIn, Flags),
+	SYS_fchmod                  = 91  (Fd, Flags),
+	SYS_chown                   = 92  (PtrIn, Int, Int),
+	SYS_fchown                  = 93  (Fd, Int, Int),
+	SYS_lchown                  = 94  (PtrIn, Int, Int),
+	SYS_umask                   = 95  (Flags),
+	SYS_gettimeofday            = 96  (PtrOut, PtrOut),
+	SYS_getrlimit               = 97  (Int, PtrOut),
+	SYS_getrusage               = 98  (Int, PtrOut),
+	SYS_sysinfo                 = 99  (PtrOut),
+	SYS_times                   = 100 (PtrOut),
+	SYS_ptrace                  = 101 (Flags, Int, Addr, Int),
+	SYS_getuid                  = 102 (),
+	SYS_syslog                  = 103 (Flags, PtrOut, Len),
+	SYS_getgid                  = 104 (),
+	SYS_setuid                  = 105 (Int),
+	SYS_setgid                  = 106 (Int),
+	SYS_geteuid                 = 107 (),
+	SYS_getegid                 = 108 (),
+	SYS_setpgid                 = 109 (Int, Int),
+	SYS_getppid                 = 110 (),
+	SYS_getpgrp                 = 111 (),
+	SYS_setsid                  = 112 (),
+	SYS_setreuid                = 113 (Int, Int),
+	SYS_setregid                = 114 (Int, Int),
+	SYS_getgroups               = 115 (Len, PtrOut),
+	SYS_setgroups               = 116 (Len, PtrIn),
+	SYS_setresuid               = 117 (Int, Int, Int),
+	SYS_getresuid               = 118 (PtrOut, PtrOut, PtrOut),
+	SYS_setresgid               = 119 (Int, Int, Int),
+	SYS_getresgid               = 120 (PtrOut, PtrOut, PtrOut),
+	SYS_getpgid                 = 121 (Int),
+	SYS_setfsuid                = 122 (Int),
+	SYS_setfsgid                = 123 (Int),
+	SYS_getsid                  = 124 (Int),
+	SYS_capget                  = 125 (PtrInOut, PtrOut),
+	SYS_capset                  = 126 (PtrIn, PtrIn),
+	SYS_rt_sigpending           = 127 (PtrOut, Len),
+	SYS_rt_sigtimedwait         = 128 (PtrIn, PtrOut, PtrIn, Len),
+	SYS_rt_sigqueueinfo         = 129 (Int, Int, PtrIn),
+	SYS_rt_sigsuspend           = 130 (PtrIn, Len),
+	SYS_sigaltstack             = 131 (PtrIn, PtrOut),
+	SYS_utime                   = 132 (PtrIn, PtrIn),
+	SYS_mknod                   = 133 (PtrIn, Flags, Int),
+	SYS_personality             = 135 (Flags),
+	SYS_ustat                   = 136 (Int, PtrOut),
+	SYS_statfs                  = 137 (PtrIn, PtrOut),
+	SYS_fstatfs                 = 138 (Fd, PtrOut),
+	SYS_getpriority             = 140 (Int, Int),
+	SYS_setpriority             = 141 (Int, Int, Int),
+	SYS_sched_setparam          = 142 (Int, PtrIn),
+	SYS_sched_getparam          = 143 (Int, PtrOut),
+	SYS_sched_setscheduler      = 144 (Int, Int, PtrIn),
+	SYS_sched_getscheduler      = 145 (Int),
+	SYS_sched_get_priority_max  = 146 (Int),
+	SYS_sched_get_priority_min  = 147 (Int),
+	SYS_sched_rr_get_interval   = 148 (Int, PtrOut),
+	SYS_mlock                   = 149 (Addr, Len),
+	SYS_munlock                 = 150 (Addr, Len),
+	SYS_mlockall                = 151 (Flags),
+	SYS_munlockall              = 152 (),
+	SYS_vhangup                 = 153 (),
+	SYS_modify_ldt              = 154 (Flags, PtrInOut, Len),
+	SYS_pivot_root              = 155 (PtrIn, PtrIn),
+	SYS_prctl                   = 157 (Flags, Int, Int, Int, Int),
+	SYS_arch_prctl              = 158 (Flags, Addr),
+	SYS_adjtimex                = 159 (PtrInOut),
+	SYS_setrlimit               = 160 (Int, PtrIn),
+	SYS_chroot                  = 161 (PtrIn),
+	SYS_sync                    = 162 (),
+	SYS_acct                    = 163 (PtrIn),
+	SYS_settimeofday            = 164 (PtrIn, PtrIn),
+	SYS_mount                   = 165 (PtrIn, PtrIn, PtrIn, Flags, PtrIn),
+	SYS_umount2                 = 166 (PtrIn, Flags),
+	SYS_swapon                  = 167 (PtrIn, Flags),
+	SYS_swapoff                 = 168 (PtrIn),
+	SYS_reboot                  = 169 (Int, Int, Flags, PtrIn),
+	SYS_sethostname             = 170 (PtrIn, Len),
+	SYS_setdomainname           = 171 (PtrIn, Len),
+	SYS_iopl                    = 172 (Int),
+	SYS_ioperm                  = 173 (Int, Len, Int),
+	SYS_init_module             = 175 (PtrIn, Len, PtrIn),
+	SYS_delete_module           = 176 (PtrIn, Flags),
+	SYS_quotactl                = 179 (Flags, PtrIn, Int, PtrInOut),
+	SYS_gettid                  = 186 (),
+	SYS_readahead               = 187 (Fd, Int64, Len),
+	SYS_setxattr                = 188 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_lsetxattr               = 189 (PtrIn, PtrIn, PtrIn, Len, Flags),
+	SYS_fsetxattr               = 190 (Fd, PtrIn, PtrIn, Len, Flags),
+	SYS_getxattr                = 191 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_lgetxattr               = 192 (PtrIn, PtrIn, PtrOut, Len),
+	SYS_fgetxattr               = 193 (Fd, PtrIn, PtrOut, Len),
+	SYS_listxattr               = 194 (PtrIn, PtrOut, Len),
+	SYS_llistxattr              = 195 (PtrIn, PtrOut, Len),
+	SYS_flistxattr              = 196 (Fd, PtrOut, Len),
+	SYS_removexattr             = 197 (PtrIn, PtrIn),
+	SYS_lremovexattr            = 198 (PtrIn, PtrIn),
+	SYS_fremovexattr            = 199 (Fd, PtrIn),
+	SYS_tkill                   = 200 (Int, Int),
+	SYS_time                    = 201 (PtrOut),
+	SYS_futex                   = 202 (PtrInOut, Flags, Int, PtrIn, PtrInOut, Int),
+	SYS_sched_setaffinity       = 203 (Int, Len, PtrIn),
+	SYS_sched_getaffinity       = 204 (Int, Len, PtrOut),
+	SYS_set_thread_area         = 205 (PtrInOut),
+	SYS_io_setup                = 206 (Len, PtrOut),
+	SYS_io_destroy              = 207 (Int),
+	SYS_io_getevents            = 208 (Int, Len, Len, PtrOut, PtrIn),
+	SYS_io_submit               = 209 (Int, Len, PtrIn),
+	SYS_io_cancel               = 210 (Int, PtrIn, PtrOut),
+	SYS_get_thread_area         = 211 (PtrInOut),
+	SYS_lookup_dcookie          = 212 (Int64, PtrOut, Len),
+	SYS_epoll_create            = 213 (Int),
+	SYS_getdents64              = 217 (Fd, PtrOut, Len),
+	SYS_set_tid_address         = 218 (Addr),
+	SYS_semtimedop              = 220 (Int, PtrIn, Len, PtrIn),
+	SYS_fadvise64               = 221 (Fd, Int64, Len, Int),
+	SYS_timer_create            = 222 (Int, PtrIn, PtrOut),
+	SYS_timer_settime           = 223 (Int, Flags, PtrIn, PtrOut),
+	SYS_timer_gettime           = 224 (Int, PtrOut),
+	SYS_timer_getoverrun        = 225 (Int),
+	SYS_timer_delete            = 226 (Int),
+	SYS_clock_settime           = 227 (Int, PtrIn),
+	SYS_clock_gettime           = 228 (Int, PtrOut),
+	SYS_clock_getres            = 229 (Int, PtrOut),
+	SYS_clock_nanosleep         = 230 (Int, Flags, PtrIn, PtrOut),
+	SYS_exit_group              = 231 (Int),
+	SYS_epoll_wait              = 232 (Fd, PtrOut, Len, Int),
+	SYS_epoll_ctl               = 233 (Fd, Flags, Fd, PtrIn),
+	SYS_tgkill                  = 234 (Int, Int, Int),
+	SYS_utimes                  = 235 (PtrIn, PtrIn),
+	SYS_mbind                   = 237 (Addr, Len, Flags, PtrIn, Len, Flags),
+	SYS_set_mempolicy           = 238 (Flags, PtrIn, Len),
+	SYS_get_mempolicy           = 239 (PtrOut, PtrOut, Len, Addr, Flags),
+	SYS_mq_open                 = 240 (PtrIn, Flags, Flags, PtrIn),
+	SYS_mq_unlink               = 241 (PtrIn),
+	SYS_mq_timedsend            = 242 (Fd, PtrIn, Len, Int, PtrIn),
+	SYS_mq_timedreceive         = 243 (Fd, PtrOut, Len, PtrOut, PtrIn),
+	SYS_mq_notify               = 244 (Fd, PtrIn),
+	SYS_mq_getsetattr           = 245 (Fd, PtrIn, PtrOut),
+	SYS_kexec_load              = 246 (Addr, Len, PtrIn, Flags),
+	SYS_waitid                  = 247 (Int, Int, PtrOut, Flags, PtrOut),
+	SYS_add_key                 = 248 (PtrIn, PtrIn, PtrIn, Len, Int),
+	SYS_request_key             = 249 (PtrIn, PtrIn, PtrIn, Int),
+	SYS_keyctl                  = 250 (Flags, Int, Int, Int, Int),
+	SYS_ioprio_set              = 251 (Int, Int, Int),
+	SYS_ioprio_get              = 252 (Int, Int),
+	SYS_inotify_init            = 253 (),
+	SYS_inotify_add_watch       = 254 (Fd, PtrIn, Flags),
+	SYS_inotify_rm_watch        = 255 (Fd, Int),
+	SYS_migrate_pages           = 256 (Int, Len, PtrIn, PtrIn),
+	SYS_openat                  = 257 (Fd, PtrIn, Flags, Flags),
+	SYS_mkdirat                 = 258 (Fd, PtrIn, Flags),
+	SYS_mknodat                 = 259 (Fd, PtrIn, Flags, Int),
+	SYS_fchownat                = 260 (Fd, PtrIn, Int, Int, Flags),
+	SYS_futimesat               = 261 (Fd, PtrIn, PtrIn),
+	SYS_newfstatat              = 262 (Fd, PtrIn, PtrOut, Flags),
+	SYS_unlinkat                = 263 (Fd, PtrIn, Flags),
+	SYS_renameat                = 264 (Fd, PtrIn, Fd, PtrIn),
+	SYS_linkat                  = 265 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_symlinkat               = 266 (PtrIn, Fd, PtrIn),
+	SYS_readlinkat              = 267 (Fd, PtrIn, PtrOut, Len),
+	SYS_fchmodat                = 268 (Fd, PtrIn, Flags),
+	SYS_faccessat               = 269 (Fd, PtrIn, Flags),
+	SYS_pselect6                = 270 (Int, PtrInOut, PtrInOut, PtrInOut, PtrInOut, PtrIn),
+	SYS_ppoll                   = 271 (PtrInOut, Len, PtrInOut, PtrIn, Len),
+	SYS_unshare                 = 272 (Flags),
+	SYS_set_robust_list         = 273 (Addr, Len),
+	SYS_get_robust_list         = 274 (Int, PtrOut, PtrOut),
+	SYS_splice                  = 275 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_tee                     = 276 (Fd, Fd, Len, Flags),
+	SYS_sync_file_range         = 277 (Fd, Int64, Int64, Flags),
+	SYS_vmsplice                = 278 (Fd, PtrIn, Len, Flags),
+	SYS_move_pages              = 279 (Int, Len, PtrIn, PtrIn, PtrOut, Flags),
+	SYS_utimensat               = 280 (Fd, PtrIn, PtrIn, Flags),
+	SYS_epoll_pwait             = 281 (Fd, PtrOut, Len, Int, PtrIn, Len),
+	SYS_signalfd                = 282 (Fd, PtrIn, Len),
+	SYS_timerfd_create          = 283 (Int, Flags),
+	SYS_eventfd                 = 284 (Int),
+	SYS_fallocate               = 285 (Fd, Flags, Int64, Int64),
+	SYS_timerfd_settime         = 286 (Fd, Flags, PtrIn, PtrOut),
+	SYS_timerfd_gettime         = 287 (Fd, PtrOut),
+	SYS_accept4                 = 288 (Fd, PtrOut, PtrInOut, Flags),
+	SYS_signalfd4               = 289 (Fd, PtrIn, Len, Flags),
+	SYS_eventfd2                = 290 (Int, Flags),
+	SYS_epoll_create1           = 291 (Flags),
+	SYS_dup3                    = 292 (Fd, Fd, Flags),
+	SYS_pipe2                   = 293 (PtrOut, Flags),
+	SYS_inotify_init1           = 294 (Flags),
+	SYS_preadv                  = 295 (Fd, PtrOut, Len, Int64, Int),
+	SYS_pwritev                 = 296 (Fd, PtrIn, Len, Int64, Int),
+	SYS_rt_tgsigqueueinfo       = 297 (Int, Int, Int, PtrIn),
+	SYS_perf_event_open         = 298 (PtrInOut, Int, Int, Fd, Flags),
+	SYS_recvmmsg                = 299 (Fd, PtrInOut, Len, Flags, PtrInOut),
+	SYS_fanotify_init           = 300 (Flags, Flags),
+	SYS_fanotify_mark           = 301 (Fd, Flags, Int64, Fd, PtrIn),
+	SYS_prlimit64               = 302 (Int, Int, PtrIn, PtrOut),
+	SYS_name_to_handle_at       = 303 (Fd, PtrIn, PtrInOut, PtrOut, Flags),
+	SYS_open_by_handle_at       = 304 (Fd, PtrIn, Flags),
+	SYS_clock_adjtime           = 305 (Int, PtrInOut),
+	SYS_syncfs                  = 306 (Fd),
+	SYS_sendmmsg                = 307 (Fd, PtrInOut, Len, Flags),
+	SYS_setns                   = 308 (Fd, Flags),
+	SYS_getcpu                  = 309 (PtrOut, PtrOut, PtrOut),
+	SYS_process_vm_readv        = 310 (Int, PtrOut, Len, PtrIn, Len, Flags),
+	SYS_process_vm_writev       = 311 (Int, PtrIn, Len, PtrIn, Len, Flags),
+	SYS_kcmp                    = 312 (Int, Int, Int, Int, Int),
+	SYS_finit_module            = 313 (Fd, PtrIn, Flags),
+	SYS_sched_setattr           = 314 (Int, PtrIn, Flags),
+	SYS_sched_getattr           = 315 (Int, PtrOut, Len, Flags),
+	SYS_renameat2               = 316 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_seccomp                 = 317 (Flags, Flags, PtrInOut),
+	SYS_getrandom               = 318 (PtrOut, Len, Flags),
+	SYS_memfd_create            = 319 (PtrIn, Flags),
+	SYS_kexec_file_load         = 320 (Fd, Fd, Len, PtrIn, Flags),
+	SYS_bpf                     = 321 (Flags, PtrInOut, Len),
+	SYS_execveat                = 322 (Fd, PtrIn, PtrIn, PtrIn, Flags),
+	SYS_userfaultfd             = 323 (Flags),
+	SYS_membarrier              = 324 (Flags, Flags, Int),
+	SYS_mlock2                  = 325 (Addr, Len, Flags),
+	SYS_copy_file_range         = 326 (Fd, PtrInOut, Fd, PtrInOut, Len, Flags),
+	SYS_preadv2                 = 327 (Fd, PtrOut, Len, Int64, Int, Flags),
+	SYS_pwritev2                = 328 (Fd, PtrIn, Len, Int64, Int, Flags),
+	SYS_pkey_mprotect           = 329 (Addr, Len, Flags, Int),
+	SYS_pkey_alloc              = 330 (Flags, Flags),
+	SYS_pkey_free               = 331 (Int),
+	SYS_statx                   = 332 (Fd, PtrIn, Flags, Flags, PtrOut),
+	SYS_io_pgetevents           = 333 (Int, Len, Len, PtrOut, PtrIn, PtrIn),
+	SYS_rseq                    = 334 (PtrInOut, Len, Flags, Int),
+	SYS_pidfd_send_signal       = 424 (Fd, Int, PtrIn, Flags),
+	SYS_io_uring_setup          = 425 (Len, PtrInOut),
+	SYS_io_uring_enter          = 426 (Fd, Len, Len, Flags, PtrIn, Len),
+	SYS_io_uring_register       = 427 (Fd, Flags, PtrInOut, Len),
+	SYS_open_tree               = 428 (Fd, PtrIn, Flags),
+	SYS_move_mount              = 429 (Fd, PtrIn, Fd, PtrIn, Flags),
+	SYS_fsopen                  = 430 (PtrIn, Flags),
+	SYS_fsconfig                = 431 (Fd, Flags, PtrIn, PtrIn, Int),
+	SYS_fsmount                 = 432 (Fd, Flags, Flags),
+	SYS_fspick                  = 433 (Fd, PtrIn, Flags),
+	SYS_pidfd_open              = 434 (Int, Flags),
+	SYS_clone3                  = 435 (PtrIn, Len),
+	SYS_close_range             = 436 (Fd, Fd, Flags),
+	SYS_openat2                 = 437 (Fd, PtrIn, PtrIn, Len),
+	SYS_pidfd_getfd             = 438 (Fd, Fd, Flags),
+	SYS_faccessat2              = 439 (Fd, PtrIn, Flags, Flags),
+	SYS_process_madvise         = 440 (Fd, PtrIn, Len, Flags, Flags),
+	SYS_epoll_pwait2            = 441 (Fd, PtrOut, Len, PtrIn, PtrIn, Len),
+	SYS_mount_setattr           = 442 (Fd, PtrIn, Flags, PtrIn, Len),
+	SYS_quotactl_fd             = 443 (Fd, Flags, Int, PtrInOut),
+	SYS_landlock_create_ruleset = 444 (PtrIn, Len, Flags),
+	SYS_landlock_add_rule       = 445 (Fd, Int, PtrIn, Flags),
+	SYS_landlock_restrict_self  = 446 (Fd, Flags),
+	SYS_memfd_secret            = 447 (Flags),
+	SYS_process_mrelease        = 448 (Fd, Flags),
+	SYS_futex_waitv             = 449 (PtrIn, Len, Flags, PtrIn, Int),
+	SYS_set_mempolicy_home_node = 450 (Addr, Len, Int, Flags),
 }
@@ -0,0 +1,48 @@
+/* Copyright (c) 2022 John Millikin <john@john-millikin.com>
+ *
+ * Permission to use, copy, modify, and/or distribute this software for any
+ * purpose with or without fee is hereby granted.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH
+ * REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY
+ * AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
+ * INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
+ * LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
+ * OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
+ * PERFORMANCE OF THIS SOFTWARE.
+ *
+ * SPDX-License-Identifier: 0BSD
+ */
+
+/* Functions exported by the `linux-syscall` crate's `extern-c` feature.
+ *
+ * Each function invokes the syscall `nr` with the given arguments. On success
+ * the syscall's result is returned; on failure the negated error number is
+ * returned.
+ */
+
+#ifndef LINUX_SYSCALL_H
+#define LINUX_SYSCALL_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+long linux_syscall0(unsigned int nr);
+long linux_syscall1(unsigned int nr, unsigned long a1);
+long linux_syscall2(unsigned int nr, unsigned long a1, unsigned long a2);
+long linux_syscall3(unsigned int nr, unsigned long a1, unsigned long a2,
+                    unsigned long a3);
+long linux_syscall4(unsigned int nr, unsigned long a1, unsigned long a2,
+                    unsigned long a3, unsigned long a4);
+long linux_syscall5(unsigned int nr, unsigned long a1, unsigned long a2,
+                    unsigned long a3, unsigned long a4, unsigned long a5);
+long linux_syscall6(unsigned int nr, unsigned long a1, unsigned long a2,
+                    unsigned long a3, unsigned long a4, unsigned long a5,
+                    unsigned long a6);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* LINUX_SYSCALL_H */
@@ -31,7 +31,7 @@
 //! let stdout: i32 = 1;
 //! let hello = "Hello, world!\n\0";
 //! let rc = unsafe {
-//! 	syscall!(SYS_write, stdout, hello.as_ptr(), hello.len())
+//!     syscall!(SYS_write, stdout, hello.as_ptr(), hello.len())
 //! };
 //! rc.check()?;
 //! # Ok(())
@@ -55,9 +55,39 @@
 //!   `SYS_mmap` on `x86` and `x86_64`.
 
 #![no_std]
+#![cfg_attr(
+	any(target_arch = "mips", target_arch = "mips64"),
+	feature(asm_experimental_arch)
+)]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt;
 
 use linux_errno::Error;
 
+// Defines items that use the syscall table or `syscall!` of the target
+// architecture. They are omitted on Linux targets without an `arch` module.
+macro_rules! native_arch_items {
+	($($item:item)*) => {$(
+		#[cfg(any(
+			target_arch = "arm",
+			target_arch = "aarch64",
+			target_arch = "loongarch64",
+			target_arch = "mips",
+			all(target_arch = "mips64", target_pointer_width = "64"),
+			target_arch = "powerpc64",
+			target_arch = "riscv32",
+			target_arch = "riscv64",
+			target_arch = "s390x",
+			target_arch = "x86",
+			target_arch = "x86_64",
+		))]
+		$item
+	)*};
+}
+
 /// An architecture-specific syscall number.
 #[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Syscall {
@@ -71,6 +101,97 @@ impl Syscall {
 	}
 }
 
+native_arch_items! {
+	impl Syscall {
+		/// Returns the name of this syscall for the current target, or `None`
+		/// if the syscall number is not known.
+		///
+		/// Names are returned without the `SYS_` prefix, for example
+		/// `"openat"`. Use the `name_of` function of an [`arch`] module to
+		/// look up syscall names for other architectures.
+		///
+		/// # Example
+		///
+		/// ```
+		/// # use linux_syscall::*;
+		/// assert_eq!(SYS_write.name(), Some("write"));
+		/// assert_eq!(Syscall::from_u32(u32::MAX).name(), None);
+		/// ```
+		#[inline]
+		pub const fn name(self) -> Option<&'static str> {
+			crate::name_of(self)
+		}
+
+		/// Returns the syscall with the given name for the current target,
+		/// or `None` if no such syscall is defined.
+		///
+		/// The name may be given with or without the `SYS_` prefix. Use the
+		/// `from_name` function of an [`arch`] module to look up syscall
+		/// numbers for other architectures.
+		///
+		/// # Example
+		///
+		/// ```
+		/// # use linux_syscall::*;
+		/// assert_eq!(Syscall::from_name("write"), Some(SYS_write));
+		/// assert_eq!(Syscall::from_name("SYS_write"), Some(SYS_write));
+		/// assert_eq!(Syscall::from_name("no_such_syscall"), None);
+		/// ```
+		#[inline]
+		pub fn from_name(name: &str) -> Option<Syscall> {
+			crate::from_name(name)
+		}
+
+		/// Returns the argument kinds of this syscall for the current target,
+		/// or `None` if the syscall number is not known.
+		///
+		/// Use the `args_of` function of an [`arch`] module to look up syscall
+		/// arguments for other architectures.
+		///
+		/// # Example
+		///
+		/// ```
+		/// # use linux_syscall::*;
+		/// assert_eq!(
+		///     SYS_write.args(),
+		///     Some(&[ArgKind::Fd, ArgKind::PtrIn, ArgKind::Len][..]),
+		/// );
+		/// ```
+		#[inline]
+		pub const fn args(self) -> Option<&'static [ArgKind]> {
+			crate::args_of(self)
+		}
+
+		/// Returns the number of argument registers used by this syscall for
+		/// the current target, or `None` if the syscall number is not known.
+		#[inline]
+		pub const fn arg_count(self) -> Option<usize> {
+			match self.args() {
+				Some(args) => Some(args.len()),
+				None => None,
+			}
+		}
+	}
+
+	impl core::str::FromStr for Syscall {
+		type Err = ParseSyscallError;
+
+		#[inline]
+		fn from_str(name: &str) -> core::result::Result<Syscall, Self::Err> {
+			Syscall::from_name(name).ok_or(ParseSyscallError { _priv: () })
+		}
+	}
+
+	impl fmt::Display for Syscall {
+		fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			match self.name() {
+				Some(name) => f.write_str(name),
+				None => fmt::Display::fmt(&self.nr, f),
+			}
+		}
+	}
+}
+
 impl From<u32> for Syscall {
 	#[inline]
 	fn from(nr: u32) -> Syscall {
@@ -85,299 +206,2020 @@ impl From<Syscall> for u32 {
 	}
 }
 
-/// Check whether a syscall succeeded or failed.
-pub trait Result {
-	fn check(&self) -> core::result::Result<(), Error>;
-}
-
-/// Interpret a syscall result as a 32-bit integer.
-pub trait Result32: Result {
-	fn try_i32(&self) -> core::result::Result<i32, Error>;
-
-	fn try_u32(&self) -> core::result::Result<u32, Error>;
+/// Conversion into a [`Syscall`] that accepts `ARGS` arguments.
+///
+/// This trait is used by [`syscall!`] to check the number of arguments
+/// passed to a syscall. It is implemented for all types that implement
+/// [`Into<Syscall>`](Syscall), which accept any number of arguments, and
+/// for [`TypedSyscall<ARGS>`](TypedSyscall).
+pub trait IntoSyscall<const ARGS: usize> {
+	fn into_syscall(self) -> Syscall;
 }
 
-/// Interpret a syscall result as a 64-bit integer.
-pub trait Result64: Result {
-	fn try_i64(&self) -> core::result::Result<i64, Error>;
-
-	fn try_u64(&self) -> core::result::Result<u64, Error>;
+impl<T: Into<Syscall>, const ARGS: usize> IntoSyscall<ARGS> for T {
+	#[inline]
+	fn into_syscall(self) -> Syscall {
+		self.into()
+	}
 }
 
-/// Interpret a syscall result as a pointer.
-pub trait ResultPtr: Result {
-	fn try_ptr(&self) -> core::result::Result<*const (), Error>;
-
-	fn try_ptr_mut(&self) -> core::result::Result<*mut (), Error>;
-}
+/// The type of a syscall argument register.
+///
+/// This is `usize` on all targets except `x32`, which passes syscall arguments
+/// in 64-bit registers.
+#[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
+pub type ArgRegister = usize;
 
-/// Interpret a syscall result as a pointer-sized integer.
-pub trait ResultSize: Result {
-	fn try_isize(&self) -> core::result::Result<isize, Error>;
+/// The type of a syscall argument register.
+///
+/// This is `usize` on all targets except `x32`, which passes syscall arguments
+/// in 64-bit registers.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+pub type ArgRegister = u64;
 
-	fn try_usize(&self) -> core::result::Result<usize, Error>;
+/// Conversion of a value into a syscall argument register.
+///
+/// This trait is used by [`syscall!`] to convert each of its arguments.
+/// It is implemented for:
+///
+/// * Integers that fit in an argument register. Signed integers are sign
+///   extended. `i64` and `u64` are only accepted on targets with 64-bit
+///   registers; see [`syscall_split!`] for passing them on 32-bit targets.
+/// * `bool`, as `0` or `1`.
+/// * [`Syscall`], as its number.
+/// * Raw pointers and [`NonNull`](core::ptr::NonNull), as the address they
+///   point to.
+/// * References to types implementing [`ArgPointee`], as the address they
+///   point to. For slices and `CStr` this is the address of the first
+///   element; the length must be passed separately.
+/// * `Option` of a reference or `NonNull`, with `None` passed as a null
+///   pointer.
+/// * With the `std` feature, `BorrowedFd` and references to the standard
+///   library types that own a file descriptor (such as `OwnedFd`, `File` and
+///   `TcpStream`), as the file descriptor number. Other types implementing
+///   `AsFd` can be passed with `as_fd()`.
+///
+/// # Example
+///
+/// A file descriptor newtype can be passed to [`syscall!`] by implementing
+/// this trait:
+///
+/// ```
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// # fn main() -> core::result::Result<(), linux_errno::Error> {
+/// struct Fd(i32);
+///
+/// impl IntoSyscallArg for Fd {
+///     fn into_syscall_arg(self) -> ArgRegister {
+///         self.0.into_syscall_arg()
+///     }
+/// }
+///
+/// let hello = b"Hello, world!\n";
+/// let rc = unsafe { syscall!(SYS_write, Fd(1), &hello[..], hello.len()) };
+/// rc.check()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait IntoSyscallArg {
+	fn into_syscall_arg(self) -> ArgRegister;
 }
 
-macro_rules! single_register_result {
-	($arch_result:ty) => {
-		use linux_errno::Error;
+/// Marker for types that can be passed to a syscall by reference.
+///
+/// References are only accepted by [`IntoSyscallArg`] when their target
+/// implements this trait, so that a value such as `&42usize` or `&file` isn't
+/// silently passed as an address. It is implemented for slices, arrays and
+/// `CStr`.
+///
+/// Structures shared with the kernel should be `#[repr(C)]` and implement this
+/// trait. Other values can be passed as raw pointers.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// # fn main() -> core::result::Result<(), linux_errno::Error> {
+/// #[repr(C)]
+/// #[derive(Default)]
+/// struct RLimit {
+///     cur: u64,
+///     max: u64,
+/// }
+///
+/// impl ArgPointee for RLimit {}
+///
+/// const RLIMIT_CPU: u32 = 0;
+/// let mut limit = RLimit::default();
+/// let rc = unsafe {
+///     syscall!(SYS_prlimit64, 0, RLIMIT_CPU, None::<&RLimit>, &mut limit)
+/// };
+/// rc.check()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait ArgPointee {}
 
-		impl $crate::Result for $arch_result {
-			#[inline]
-			fn check(&self) -> core::result::Result<(), Error> {
-				if self.0 >= self::MAX_ERRNO {
-					return Err(new_err(self.0 as u16));
-				}
-				Ok(())
-			}
-		}
+impl<T> ArgPointee for [T] {}
 
-		#[inline]
-		#[cold]
-		const fn new_err(truncated_register: u16) -> Error {
-			let (err, _) = truncated_register.overflowing_neg();
-			unsafe { Error::new_unchecked(err) }
-		}
+impl<T, const N: usize> ArgPointee for [T; N] {}
 
-		impl $crate::ResultPtr for $arch_result {
-			#[inline]
-			fn try_ptr(&self) -> core::result::Result<*const (), Error> {
-				$crate::Result::check(self)?;
-				Ok(self.0 as *const ())
-			}
+impl ArgPointee for core::ffi::CStr {}
 
+macro_rules! into_syscall_arg_unsigned {
+	($($t:ty),*) => {$(
+		impl IntoSyscallArg for $t {
 			#[inline]
-			fn try_ptr_mut(&self) -> core::result::Result<*mut (), Error> {
-				$crate::Result::check(self)?;
-				Ok(self.0 as *mut ())
+			fn into_syscall_arg(self) -> ArgRegister {
+				self as ArgRegister
 			}
 		}
+	)*};
+}
 
-		impl<T> core::convert::TryFrom<$arch_result> for *const T {
-			type Error = Error;
-
+macro_rules! into_syscall_arg_signed {
+	($($t:ty),*) => {$(
+		impl IntoSyscallArg for $t {
 			#[inline]
-			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
-				$crate::ResultPtr::try_ptr(&rc).map(|p| p.cast())
+			fn into_syscall_arg(self) -> ArgRegister {
+				self as i64 as ArgRegister
 			}
 		}
+	)*};
+}
 
-		impl<T> core::convert::TryFrom<$arch_result> for *mut T {
-			type Error = Error;
-
-			#[inline]
-			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
-				$crate::ResultPtr::try_ptr_mut(&rc).map(|p| p.cast())
-			}
-		}
+into_syscall_arg_unsigned!(u8, u16, u32, usize);
+into_syscall_arg_signed!(i8, i16, i32, isize);
 
-		impl $crate::ResultSize for $arch_result {
-			#[inline]
-			fn try_isize(&self) -> core::result::Result<isize, Error> {
-				$crate::Result::check(self)?;
-				Ok(self.0 as isize)
-			}
+#[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+into_syscall_arg_unsigned!(u64);
 
-			#[inline]
-			fn try_usize(&self) -> core::result::Result<usize, Error> {
-				$crate::Result::check(self)?;
-				Ok(self.0 as usize)
-			}
-		}
+#[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+into_syscall_arg_signed!(i64);
 
-		impl core::convert::TryFrom<$arch_result> for isize {
-			type Error = Error;
+impl IntoSyscallArg for bool {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		self as ArgRegister
+	}
+}
 
-			#[inline]
-			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
-				$crate::ResultSize::try_isize(&rc)
-			}
-		}
+impl IntoSyscallArg for Syscall {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		self.nr as ArgRegister
+	}
+}
 
-		impl core::convert::TryFrom<$arch_result> for usize {
-			type Error = Error;
+impl<T: ?Sized> IntoSyscallArg for *const T {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		self as *const () as usize as ArgRegister
+	}
+}
 
-			#[inline]
-			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
-				$crate::ResultSize::try_usize(&rc)
-			}
-		}
-	};
+impl<T: ?Sized> IntoSyscallArg for *mut T {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		self as *const () as usize as ArgRegister
+	}
 }
 
-#[cfg(any(target_pointer_width = "32", doc))]
-macro_rules! single_register_result32 {
-	($arch_result:ty) => {
-		single_register_result!($arch_result);
+impl<T: ?Sized> IntoSyscallArg for core::ptr::NonNull<T> {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		self.as_ptr().into_syscall_arg()
+	}
+}
 
-		const MAX_ERRNO: u32 = (-4095i32) as u32;
+impl<T: ArgPointee + ?Sized> IntoSyscallArg for &T {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		(self as *const T).into_syscall_arg()
+	}
+}
 
-		impl $crate::Result32 for $arch_result {
-			#[inline]
-			fn try_i32(&self) -> core::result::Result<i32, Error> {
-				$crate::Result::check(self)?;
-				Ok(self.0 as i32)
-			}
+impl<T: ArgPointee + ?Sized> IntoSyscallArg for &mut T {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		(self as *mut T).into_syscall_arg()
+	}
+}
 
-			#[inline]
-			fn try_u32(&self) -> core::result::Result<u32, Error> {
-				$crate::Result::check(self)?;
-				Ok(self.0)
-			}
+impl<T: ArgPointee + ?Sized> IntoSyscallArg for Option<&T> {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		match self {
+			Some(value) => value.into_syscall_arg(),
+			None => 0,
 		}
+	}
+}
 
-		impl core::convert::TryFrom<$arch_result> for i32 {
-			type Error = Error;
+impl<T: ArgPointee + ?Sized> IntoSyscallArg for Option<&mut T> {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		match self {
+			Some(value) => value.into_syscall_arg(),
+			None => 0,
+		}
+	}
+}
 
-			#[inline]
-			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
-				$crate::Result32::try_i32(&rc)
-			}
+impl<T: ?Sized> IntoSyscallArg for Option<core::ptr::NonNull<T>> {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		match self {
+			Some(value) => value.into_syscall_arg(),
+			None => 0,
 		}
+	}
+}
 
-		impl core::convert::TryFrom<$arch_result> for u32 {
-			type Error = Error;
+#[cfg(feature = "std")]
+impl IntoSyscallArg for std::os::unix::io::BorrowedFd<'_> {
+	#[inline]
+	fn into_syscall_arg(self) -> ArgRegister {
+		use std::os::unix::io::AsRawFd;
+		self.as_raw_fd().into_syscall_arg()
+	}
+}
 
+#[cfg(feature = "std")]
+macro_rules! into_syscall_arg_fd_ref {
+	($($t:ty),* $(,)?) => {$(
+		impl IntoSyscallArg for &$t {
 			#[inline]
-			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
-				$crate::Result32::try_u32(&rc)
+			fn into_syscall_arg(self) -> ArgRegister {
+				use std::os::unix::io::AsRawFd;
+				self.as_raw_fd().into_syscall_arg()
 			}
 		}
-	};
+	)*};
 }
 
-#[cfg(any(target_pointer_width = "64", doc))]
-macro_rules! single_register_result64 {
-	($arch_result:ty) => {
-		single_register_result!($arch_result);
+#[cfg(feature = "std")]
+into_syscall_arg_fd_ref!(
+	std::os::unix::io::OwnedFd,
+	std::fs::File,
+	std::io::Stdin,
+	std::io::Stdout,
+	std::io::Stderr,
+	std::net::TcpListener,
+	std::net::TcpStream,
+	std::net::UdpSocket,
+	std::os::unix::net::UnixDatagram,
+	std::os::unix::net::UnixListener,
+	std::os::unix::net::UnixStream,
+	std::process::ChildStdin,
+	std::process::ChildStdout,
+	std::process::ChildStderr,
+);
+
+/// A syscall number with a known number of arguments.
+///
+/// Passing a `TypedSyscall` to [`syscall!`] with the wrong number of
+/// arguments is a compile-time error. Typed syscall constants for each
+/// architecture are defined in its `typed` module.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// # fn main() -> core::result::Result<(), linux_errno::Error> {
+/// let stdout: i32 = 1;
+/// let hello = "Hello, world!\n\0";
+/// let rc = unsafe {
+///     syscall!(typed::SYS_write, stdout, hello.as_ptr(), hello.len())
+/// };
+/// rc.check()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// let stdout: i32 = 1;
+/// let _ = unsafe { syscall!(typed::SYS_write, stdout) };
+/// ```
+#[cfg(any(feature = "typed", doc))]
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TypedSyscall<const ARGS: usize> {
+	syscall: Syscall,
+}
 
-		const MAX_ERRNO: u64 = (-4095i64) as u64;
+#[cfg(any(feature = "typed", doc))]
+impl<const ARGS: usize> TypedSyscall<ARGS> {
+	/// Returns a `TypedSyscall` without checking the number of arguments.
+	///
+	/// # Safety
+	///
+	/// `syscall` must use `ARGS` argument registers on the target it will be
+	/// invoked on.
+	#[inline]
+	pub const unsafe fn new_unchecked(syscall: Syscall) -> Self {
+		Self { syscall }
+	}
 
-		impl $crate::Result64 for $arch_result {
-			#[inline]
-			fn try_i64(&self) -> core::result::Result<i64, Error> {
-				$crate::Result::check(self)?;
-				Ok(self.0 as i64)
-			}
+	#[inline]
+	pub const fn syscall(self) -> Syscall {
+		self.syscall
+	}
+}
 
-			#[inline]
-			fn try_u64(&self) -> core::result::Result<u64, Error> {
-				$crate::Result::check(self)?;
-				Ok(self.0)
+native_arch_items! {
+	#[cfg(any(feature = "typed", doc))]
+	impl<const ARGS: usize> TypedSyscall<ARGS> {
+		/// Returns a `TypedSyscall` if `syscall` uses `ARGS` argument registers
+		/// on the current target, or `None` otherwise.
+		#[inline]
+		pub const fn new(syscall: Syscall) -> Option<Self> {
+			match syscall.arg_count() {
+				Some(count) if count == ARGS => Some(Self { syscall }),
+				_ => None,
 			}
 		}
+	}
+}
 
-		impl core::convert::TryFrom<$arch_result> for i64 {
-			type Error = Error;
+#[cfg(any(feature = "typed", doc))]
+impl<const ARGS: usize> IntoSyscall<ARGS> for TypedSyscall<ARGS> {
+	#[inline]
+	fn into_syscall(self) -> Syscall {
+		self.syscall
+	}
+}
 
-			#[inline]
-			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
+/// A coarse classification of a syscall argument.
+///
+/// Each `ArgKind` describes a single argument register. On 32-bit
+/// architectures a 64-bit argument occupies two registers, described by
+/// [`Int64Low`](ArgKind::Int64Low) and [`Int64High`](ArgKind::Int64High), and
+/// may be preceded by a [`Pad`](ArgKind::Pad) register to satisfy the
+/// architecture's register pair alignment. The two halves are listed in
+/// little-endian order; big-endian targets pass the high half first.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ArgKind {
+	/// A file descriptor.
+	Fd,
+	/// An integer, such as a process ID, signal number, or user ID.
+	Int,
+	/// A bitmask of flags, a file mode, or a command code.
+	Flags,
+	/// The length of a buffer, or a count of elements.
+	Len,
+	/// An address that is not dereferenced by the syscall.
+	Addr,
+	/// A pointer to memory that is read by the kernel.
+	PtrIn,
+	/// A pointer to memory that is written by the kernel.
+	PtrOut,
+	/// A pointer to memory that is both read and written by the kernel.
+	PtrInOut,
+	/// A 64-bit integer, such as a file offset.
+	Int64,
+	/// The low 32 bits of a 64-bit integer.
+	Int64Low,
+	/// The high 32 bits of a 64-bit integer.
+	Int64High,
+	/// An unused register.
+	Pad,
+}
+
+/// A 64-bit syscall argument.
+///
+/// On 32-bit architectures a 64-bit argument is passed in a pair of registers.
+/// When written as `Arg64(value)` in the arguments of [`syscall_split!`], it
+/// expands into the register pair, and any padding register, required by the
+/// target's calling convention.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Arg64(pub u64);
+
+impl Arg64 {
+	/// Returns the low 32 bits of the argument.
+	#[inline]
+	pub const fn low(self) -> u32 {
+		self.0 as u32
+	}
+
+	/// Returns the high 32 bits of the argument.
+	#[inline]
+	pub const fn high(self) -> u32 {
+		(self.0 >> 32) as u32
+	}
+
+	#[doc(hidden)]
+	#[inline]
+	pub const fn register_pair(self) -> (u32, u32) {
+		if cfg!(target_endian = "big") {
+			(self.high(), self.low())
+		} else {
+			(self.low(), self.high())
+		}
+	}
+}
+
+/// An error returned when parsing an unknown syscall name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseSyscallError {
+	_priv: (),
+}
+
+impl fmt::Display for ParseSyscallError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("unknown syscall name")
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseSyscallError {}
+
+/// An error returned by a syscall, along with the syscall that failed.
+///
+/// The `Display` implementation names the syscall and error number, for
+/// example `openat: ENOENT`.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// const AT_FDCWD: i32 = -100;
+/// let path = "/nonexistent\0";
+/// let rc = unsafe { syscall!(SYS_openat, AT_FDCWD, path.as_ptr(), 0) };
+/// let err = rc.check_with(SYS_openat).unwrap_err();
+/// assert_eq!(err.to_string(), "openat: ENOENT");
+///
+/// let err = rc.try_isize().context(SYS_openat).unwrap_err();
+/// assert_eq!(err.errno(), linux_errno::ENOENT);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SyscallError {
+	syscall: Syscall,
+	errno: Error,
+}
+
+impl SyscallError {
+	#[inline]
+	pub const fn new(syscall: Syscall, errno: Error) -> SyscallError {
+		SyscallError { syscall, errno }
+	}
+
+	/// Returns the syscall that failed.
+	#[inline]
+	pub const fn syscall(self) -> Syscall {
+		self.syscall
+	}
+
+	/// Returns the error number reported by the syscall.
+	#[inline]
+	pub const fn errno(self) -> Error {
+		self.errno
+	}
+}
+
+native_arch_items! {
+	impl fmt::Display for SyscallError {
+		fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			write!(f, "{}: {:?}", self.syscall, self.errno)
+		}
+	}
+}
+
+impl From<SyscallError> for Error {
+	#[inline]
+	fn from(err: SyscallError) -> Error {
+		err.errno
+	}
+}
+
+native_arch_items! {
+	#[cfg(feature = "std")]
+	impl std::error::Error for SyscallError {}
+}
+
+#[cfg(feature = "std")]
+impl From<SyscallError> for std::io::Error {
+	#[inline]
+	fn from(err: SyscallError) -> std::io::Error {
+		io_error(err.errno)
+	}
+}
+
+#[cfg(feature = "std")]
+#[inline]
+fn io_error(errno: Error) -> std::io::Error {
+	std::io::Error::from_raw_os_error(i32::from(errno.get()))
+}
+
+/// Check whether a syscall succeeded or failed.
+pub trait Result {
+	fn check(&self) -> core::result::Result<(), Error>;
+
+	/// Check whether a syscall succeeded or failed, returning a
+	/// [`SyscallError`] that identifies `syscall` on failure.
+	#[inline]
+	fn check_with(
+		&self,
+		syscall: Syscall,
+	) -> core::result::Result<(), SyscallError> {
+		self.check().context(syscall)
+	}
+
+	/// Check whether a syscall succeeded or failed, returning a
+	/// `std::io::Error` on failure.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # #[macro_use] extern crate linux_syscall;
+	/// # use linux_syscall::*;
+	/// # fn main() -> std::io::Result<()> {
+	/// let file = std::fs::File::create("/dev/null")?;
+	/// let hello = b"Hello, world!\n";
+	/// let rc = unsafe { syscall!(SYS_write, &file, &hello[..], hello.len()) };
+	/// rc.check_io()?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "std")]
+	#[inline]
+	fn check_io(&self) -> std::io::Result<()> {
+		self.check().map_err(io_error)
+	}
+}
+
+/// Attach the failed syscall to an error returned by the `Result*` traits.
+///
+/// This is implemented for `core::result::Result<T, linux_errno::Error>`, so
+/// that results of methods such as [`ResultSize::try_usize`] can be converted
+/// into a [`SyscallError`].
+pub trait ErrorContext<T> {
+	fn context(self, syscall: Syscall)
+		-> core::result::Result<T, SyscallError>;
+}
+
+impl<T> ErrorContext<T> for core::result::Result<T, Error> {
+	#[inline]
+	fn context(
+		self,
+		syscall: Syscall,
+	) -> core::result::Result<T, SyscallError> {
+		self.map_err(|errno| SyscallError::new(syscall, errno))
+	}
+}
+
+/// Interpret a syscall result as a 32-bit integer.
+pub trait Result32: Result {
+	fn try_i32(&self) -> core::result::Result<i32, Error>;
+
+	fn try_u32(&self) -> core::result::Result<u32, Error>;
+}
+
+/// Interpret a syscall result as a 64-bit integer.
+pub trait Result64: Result {
+	fn try_i64(&self) -> core::result::Result<i64, Error>;
+
+	fn try_u64(&self) -> core::result::Result<u64, Error>;
+}
+
+/// Interpret a syscall result as a pointer.
+pub trait ResultPtr: Result {
+	fn try_ptr(&self) -> core::result::Result<*const (), Error>;
+
+	fn try_ptr_mut(&self) -> core::result::Result<*mut (), Error>;
+}
+
+/// Interpret a syscall result as a pointer-sized integer.
+pub trait ResultSize: Result {
+	fn try_isize(&self) -> core::result::Result<isize, Error>;
+
+	fn try_usize(&self) -> core::result::Result<usize, Error>;
+
+	/// Interpret a syscall result as a newly opened file descriptor.
+	///
+	/// Errors are returned as `std::io::Error`, so that this can be used with
+	/// `?` in functions returning `std::io::Result`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # #[macro_use] extern crate linux_syscall;
+	/// # use linux_syscall::*;
+	/// # fn main() -> std::io::Result<()> {
+	/// const AT_FDCWD: i32 = -100;
+	/// const O_WRONLY: i32 = 1;
+	/// let path = "/dev/null\0";
+	/// let rc = unsafe { syscall!(SYS_openat, AT_FDCWD, path.as_ptr(), O_WRONLY) };
+	/// let fd = unsafe { rc.try_owned_fd() }?;
+	///
+	/// let hello = b"Hello, world!\n";
+	/// let rc = unsafe { syscall!(SYS_write, &fd, &hello[..], hello.len()) };
+	/// rc.check_with(SYS_write)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Safety
+	///
+	/// On success, the syscall must have returned an open file descriptor that
+	/// is not owned by any other value. It will be closed when the returned
+	/// `OwnedFd` is dropped.
+	#[cfg(feature = "std")]
+	#[inline]
+	unsafe fn try_owned_fd(
+		&self,
+	) -> std::io::Result<std::os::unix::io::OwnedFd> {
+		use std::os::unix::io::FromRawFd;
+		let fd = self.try_isize().map_err(io_error)?;
+		Ok(std::os::unix::io::OwnedFd::from_raw_fd(fd as i32))
+	}
+}
+
+/// Interpret the results of syscalls that may succeed with values in the
+/// error range.
+///
+/// Results are normally checked by treating values in `-4095..=-1` as errors,
+/// but a few syscalls can return such values on success. On architectures
+/// that report errors with a separate flag (such as `mips` and `powerpc64`)
+/// these results are unambiguous.
+///
+/// The `PTRACE_PEEKTEXT`, `PTRACE_PEEKDATA` and `PTRACE_PEEKUSER` requests of
+/// `ptrace` are not ambiguous and have no method here. The raw syscall writes
+/// the requested word to `*data` and returns 0, so its result can be checked
+/// with [`Result::check`]. Only the C library wrapper returns the word itself,
+/// and needs `errno` to tell a word in the error range from a failure.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// # fn main() -> core::result::Result<(), linux_errno::Error> {
+/// const PRIO_PROCESS: i32 = 0;
+/// let rc = unsafe { syscall!(SYS_getpriority, PRIO_PROCESS, 0) };
+/// let nice = rc.try_getpriority()?;
+/// assert!((-20..=19).contains(&nice));
+/// # Ok(())
+/// # }
+/// ```
+pub trait ResultDecode: Result {
+	/// Interpret the result of `fcntl(F_GETOWN)`.
+	///
+	/// Returns the process ID, or the negated process group ID, that receives
+	/// signals for the file descriptor.
+	///
+	/// On architectures without a separate error flag, the negated ID of a
+	/// process group below 4096 is in the error range. Such results are
+	/// reported as process groups, except for `-EBADF` and `-EINVAL`: these
+	/// are the errors `F_GETOWN` can report, so they are returned as errors
+	/// even though they might mean process group 9 or 22. Any other error,
+	/// such as a denial by a security module, is misreported as a process
+	/// group. Use `F_GETOWN_EX` where this matters.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # #[macro_use] extern crate linux_syscall;
+	/// # use linux_syscall::*;
+	/// # #[cfg(target_arch = "riscv32")]
+	/// # use linux_syscall::SYS_fcntl64 as SYS_fcntl;
+	/// # fn main() -> core::result::Result<(), linux_errno::Error> {
+	/// #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+	/// const F_GETOWN: i32 = 9;
+	/// #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+	/// const F_GETOWN: i32 = 23;
+	///
+	/// const AT_FDCWD: i32 = -100;
+	/// let path = "/dev/null\0";
+	/// let rc = unsafe { syscall!(SYS_openat, AT_FDCWD, path.as_ptr(), 0) };
+	/// let fd = rc.try_isize()? as i32;
+	///
+	/// let rc = unsafe { syscall!(SYS_fcntl, fd, F_GETOWN) };
+	/// assert_eq!(rc.try_fcntl_getown()?, 0);
+	///
+	/// let rc = unsafe { syscall!(SYS_fcntl, -1, F_GETOWN) };
+	/// assert_eq!(rc.try_fcntl_getown(), Err(linux_errno::EBADF));
+	/// # Ok(())
+	/// # }
+	/// ```
+	fn try_fcntl_getown(&self) -> core::result::Result<i32, Error>;
+
+	/// Interpret the result of `getpriority`.
+	///
+	/// The syscall returns the nice value biased by 20, so that it is always
+	/// positive. This method returns the nice value in the range `-20..=19`.
+	fn try_getpriority(&self) -> core::result::Result<i32, Error>;
+}
+
+// Implements the `Result*` traits for an architecture's syscall result type.
+//
+// The type must have inherent methods `value(&self)`, returning the result
+// register, and `errno(&self)`, returning the error number if the syscall
+// failed. It must also have `fcntl_getown_errno(&self)`, which is `errno()`
+// adjusted for `fcntl(F_GETOWN)` results. Architectures with an unusual error
+// convention can provide these directly; the common conventions are covered
+// by `single_register_result*!` and `error_flag_result*!`.
+macro_rules! syscall_result {
+	($arch_result:ty, u32) => {
+		syscall_result!(@common $arch_result, i32);
+
+		impl $crate::Result32 for $arch_result {
+			#[inline]
+			fn try_i32(&self) -> core::result::Result<i32, Error> {
+				$crate::Result::check(self)?;
+				Ok(self.value() as i32)
+			}
+
+			#[inline]
+			fn try_u32(&self) -> core::result::Result<u32, Error> {
+				$crate::Result::check(self)?;
+				Ok(self.value())
+			}
+		}
+
+		impl core::convert::TryFrom<$arch_result> for i32 {
+			type Error = Error;
+
+			#[inline]
+			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
+				$crate::Result32::try_i32(&rc)
+			}
+		}
+
+		impl core::convert::TryFrom<$arch_result> for u32 {
+			type Error = Error;
+
+			#[inline]
+			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
+				$crate::Result32::try_u32(&rc)
+			}
+		}
+	};
+	($arch_result:ty, u64) => {
+		syscall_result!(@common $arch_result, i64);
+
+		impl $crate::Result64 for $arch_result {
+			#[inline]
+			fn try_i64(&self) -> core::result::Result<i64, Error> {
+				$crate::Result::check(self)?;
+				Ok(self.value() as i64)
+			}
+
+			#[inline]
+			fn try_u64(&self) -> core::result::Result<u64, Error> {
+				$crate::Result::check(self)?;
+				Ok(self.value())
+			}
+		}
+
+		impl core::convert::TryFrom<$arch_result> for i64 {
+			type Error = Error;
+
+			#[inline]
+			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
 				$crate::Result64::try_i64(&rc)
 			}
 		}
 
-		impl core::convert::TryFrom<$arch_result> for u64 {
-			type Error = Error;
+		impl core::convert::TryFrom<$arch_result> for u64 {
+			type Error = Error;
+
+			#[inline]
+			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
+				$crate::Result64::try_u64(&rc)
+			}
+		}
+	};
+	(@common $arch_result:ty, $signed:ty) => {
+		use linux_errno::Error;
+
+		impl $crate::Result for $arch_result {
+			#[inline]
+			fn check(&self) -> core::result::Result<(), Error> {
+				match self.errno() {
+					Some(errno) => Err(new_err(errno)),
+					None => Ok(()),
+				}
+			}
+		}
+
+		#[inline]
+		#[cold]
+		const fn new_err(errno: u16) -> Error {
+			unsafe { Error::new_unchecked(errno) }
+		}
+
+		impl $crate::ResultPtr for $arch_result {
+			#[inline]
+			fn try_ptr(&self) -> core::result::Result<*const (), Error> {
+				$crate::Result::check(self)?;
+				Ok(self.value() as *const ())
+			}
+
+			#[inline]
+			fn try_ptr_mut(&self) -> core::result::Result<*mut (), Error> {
+				$crate::Result::check(self)?;
+				Ok(self.value() as *mut ())
+			}
+		}
+
+		impl<T> core::convert::TryFrom<$arch_result> for *const T {
+			type Error = Error;
+
+			#[inline]
+			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
+				$crate::ResultPtr::try_ptr(&rc).map(|p| p.cast())
+			}
+		}
+
+		impl<T> core::convert::TryFrom<$arch_result> for *mut T {
+			type Error = Error;
+
+			#[inline]
+			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
+				$crate::ResultPtr::try_ptr_mut(&rc).map(|p| p.cast())
+			}
+		}
+
+		impl $crate::ResultSize for $arch_result {
+			#[inline]
+			fn try_isize(&self) -> core::result::Result<isize, Error> {
+				$crate::Result::check(self)?;
+				Ok(self.value() as $signed as isize)
+			}
+
+			#[inline]
+			fn try_usize(&self) -> core::result::Result<usize, Error> {
+				$crate::Result::check(self)?;
+				Ok(self.value() as usize)
+			}
+		}
+
+		impl core::convert::TryFrom<$arch_result> for isize {
+			type Error = Error;
+
+			#[inline]
+			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
+				$crate::ResultSize::try_isize(&rc)
+			}
+		}
+
+		impl core::convert::TryFrom<$arch_result> for usize {
+			type Error = Error;
+
+			#[inline]
+			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
+				$crate::ResultSize::try_usize(&rc)
+			}
+		}
+
+		impl $crate::ResultDecode for $arch_result {
+			#[inline]
+			fn try_fcntl_getown(&self) -> core::result::Result<i32, Error> {
+				match self.fcntl_getown_errno() {
+					Some(errno) => Err(new_err(errno)),
+					None => Ok(self.value() as $signed as i32),
+				}
+			}
+
+			#[inline]
+			fn try_getpriority(&self) -> core::result::Result<i32, Error> {
+				$crate::Result::check(self)?;
+				Ok(20 - self.value() as i32)
+			}
+		}
+	};
+}
+
+// A result held in a single register, with errors returned as values in the
+// range `-4095..=-1`.
+#[cfg(any(
+	not(any(
+		target_arch = "mips",
+		target_arch = "mips64",
+		target_arch = "powerpc64",
+	)),
+	doc,
+))]
+macro_rules! single_register_result {
+	($arch_result:ty, $register:tt) => {
+		impl $arch_result {
+			#[inline]
+			const fn value(&self) -> $register {
+				self.0
+			}
+
+			#[inline]
+			const fn errno(&self) -> Option<u16> {
+				if self.0 >= MAX_ERRNO {
+					let (err, _) = (self.0 as u16).overflowing_neg();
+					return Some(err);
+				}
+				None
+			}
+
+			// A negated process group ID may be in the error range, so only
+			// the errors `fcntl(F_GETOWN)` reports for a bad file descriptor
+			// or command are treated as failures.
+			#[inline]
+			fn fcntl_getown_errno(&self) -> Option<u16> {
+				match self.errno() {
+					Some(err)
+						if err == linux_errno::EBADF.get()
+							|| err == linux_errno::EINVAL.get() =>
+					{
+						Some(err)
+					},
+					_ => None,
+				}
+			}
+		}
+
+		syscall_result!($arch_result, $register);
+	};
+}
+
+#[cfg(any(
+	all(target_pointer_width = "32", not(target_arch = "mips")),
+	target_arch = "x86_64",
+	doc,
+))]
+macro_rules! single_register_result32 {
+	($arch_result:ty) => {
+		const MAX_ERRNO: u32 = (-4095i32) as u32;
+
+		single_register_result!($arch_result, u32);
+	};
+}
+
+#[cfg(any(
+	all(
+		target_pointer_width = "64",
+		not(any(target_arch = "mips64", target_arch = "powerpc64")),
+	),
+	target_arch = "x86_64",
+	doc,
+))]
+macro_rules! single_register_result64 {
+	($arch_result:ty) => {
+		const MAX_ERRNO: u64 = (-4095i64) as u64;
+
+		single_register_result!($arch_result, u64);
+	};
+}
+
+// A result held in a value register and a separate error flag, with errors
+// returned as positive values. The result type has `value` and `error` fields.
+#[cfg(any(
+	target_arch = "mips",
+	target_arch = "mips64",
+	target_arch = "powerpc64",
+	doc,
+))]
+macro_rules! error_flag_result {
+	($arch_result:ty, $register:tt) => {
+		impl $arch_result {
+			#[inline]
+			const fn value(&self) -> $register {
+				self.value
+			}
+
+			#[inline]
+			const fn errno(&self) -> Option<u16> {
+				if self.error {
+					return Some(self.value as u16);
+				}
+				None
+			}
+
+			#[inline]
+			const fn fcntl_getown_errno(&self) -> Option<u16> {
+				self.errno()
+			}
+		}
+
+		syscall_result!($arch_result, $register);
+	};
+}
+
+#[cfg(any(target_arch = "mips", doc))]
+macro_rules! error_flag_result32 {
+	($arch_result:ty) => {
+		error_flag_result!($arch_result, u32);
+	};
+}
+
+#[cfg(any(target_arch = "mips64", target_arch = "powerpc64", doc))]
+macro_rules! error_flag_result64 {
+	($arch_result:ty) => {
+		error_flag_result!($arch_result, u64);
+	};
+}
+
+macro_rules! syscall_constants {
+	( $( $name:ident = $value:literal ( $( $arg:ident ),* ) , )+ ) => {
+		use $crate::Syscall;
+		$(
+			pub const $name: Syscall = Syscall::from_u32($value);
+		)*
+
+		const SYSCALLS: [(&str, Syscall); [$($value),*].len()] = [
+			$(
+				($crate::syscall_name(stringify!($name)), $name),
+			)*
+		];
+
+		/// All syscalls defined for this architecture, as `(name, syscall)`
+		/// pairs in ascending order of syscall number.
+		///
+		/// Names are given without the `SYS_` prefix.
+		pub const ALL: &[(&str, Syscall)] = &SYSCALLS;
+
+		const SYSCALLS_BY_NAME: [(&str, Syscall); SYSCALLS.len()] =
+			$crate::sort_by_name(SYSCALLS);
+
+		/// Returns the syscall with the given name, or `None` if no such
+		/// syscall is defined for this architecture.
+		///
+		/// The name may be given with or without the `SYS_` prefix, for
+		/// example `"openat"` or `"SYS_openat"`.
+		pub fn from_name(name: &str) -> Option<Syscall> {
+			let name = name.strip_prefix("SYS_").unwrap_or(name);
+			match SYSCALLS_BY_NAME.binary_search_by(|(n, _)| (*n).cmp(name)) {
+				Ok(idx) => Some(SYSCALLS_BY_NAME[idx].1),
+				Err(_) => None,
+			}
+		}
+
+		/// Returns the name of a syscall, or `None` if the syscall number is
+		/// not defined for this architecture.
+		pub const fn name_of(syscall: Syscall) -> Option<&'static str> {
+			match syscall.nr {
+				$(
+					$value => {
+						const NAME: &str = $crate::syscall_name(stringify!($name));
+						Some(NAME)
+					},
+				)*
+				_ => None,
+			}
+		}
+
+		/// Syscall numbers with a known number of arguments.
+		///
+		/// See [`TypedSyscall`](crate::TypedSyscall) for details.
+		#[cfg(any(feature = "typed", doc))]
+		pub mod typed {
+			use $crate::{ArgKind, TypedSyscall};
+			$(
+				pub const $name: TypedSyscall<{
+					<[ArgKind]>::len(&[$( ArgKind::$arg ),*])
+				}> = unsafe { TypedSyscall::new_unchecked(super::$name) };
+			)*
+		}
+
+		/// Returns the argument kinds of a syscall, or `None` if the syscall
+		/// number is not defined for this architecture.
+		///
+		/// The returned slice has one [`ArgKind`](crate::ArgKind) for each
+		/// argument register used by the syscall.
+		pub const fn args_of(
+			syscall: Syscall,
+		) -> Option<&'static [$crate::ArgKind]> {
+			match syscall.nr {
+				$(
+					$value => Some(&[$( $crate::ArgKind::$arg ),*]),
+				)*
+				_ => None,
+			}
+		}
+	};
+}
+
+// Sorts a syscall table by name, for binary search in `from_name()`.
+//
+// This is a heapsort because the const evaluator is slow enough that an
+// insertion sort noticeably increases build times.
+const fn sort_by_name<const N: usize>(
+	mut table: [(&'static str, Syscall); N],
+) -> [(&'static str, Syscall); N] {
+	let mut start = N / 2;
+	while start > 0 {
+		start -= 1;
+		table = sift_down(table, start, N);
+	}
+	let mut end = N;
+	while end > 1 {
+		end -= 1;
+		let tmp = table[0];
+		table[0] = table[end];
+		table[end] = tmp;
+		table = sift_down(table, 0, end);
+	}
+	table
+}
+
+const fn sift_down<const N: usize>(
+	mut table: [(&'static str, Syscall); N],
+	mut root: usize,
+	end: usize,
+) -> [(&'static str, Syscall); N] {
+	loop {
+		let mut child = 2 * root + 1;
+		if child >= end {
+			return table;
+		}
+		if child + 1 < end && str_lt(table[child].0, table[child + 1].0) {
+			child += 1;
+		}
+		if !str_lt(table[root].0, table[child].0) {
+			return table;
+		}
+		let tmp = table[root];
+		table[root] = table[child];
+		table[child] = tmp;
+		root = child;
+	}
+}
+
+const fn str_lt(a: &str, b: &str) -> bool {
+	let a = a.as_bytes();
+	let b = b.as_bytes();
+	let mut ii = 0;
+	while ii < a.len() && ii < b.len() {
+		if a[ii] != b[ii] {
+			return a[ii] < b[ii];
+		}
+		ii += 1;
+	}
+	a.len() < b.len()
+}
+
+// Strips the `SYS_` prefix from a syscall constant's identifier.
+const fn syscall_name(ident: &'static str) -> &'static str {
+	let mut name = ident.as_bytes();
+	let mut ii = 0;
+	while ii < "SYS_".len() {
+		if let Some((_, rest)) = name.split_first() {
+			name = rest;
+		}
+		ii += 1;
+	}
+	match core::str::from_utf8(name) {
+		Ok(name) => name,
+		Err(_) => panic!("syscall names must be ASCII"),
+	}
+}
+
+/// Linux syscall numbers for specific target architectures.
+pub mod arch {
+	/// Linux syscall numbers for the `aarch64` architecture.
+	#[cfg(any(target_arch = "aarch64", doc))]
+	pub mod aarch64 {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `arm` architecture.
+	#[cfg(any(target_arch = "arm", doc))]
+	pub mod arm {
+		mod syscall_asm;
+		pub use self::syscall_asm::{cacheflush, set_tls, Result};
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `loongarch64` architecture.
+	#[cfg(any(target_arch = "loongarch64", doc))]
+	pub mod loongarch64 {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `mips` architecture (`o32` ABI).
+	///
+	/// `asm!` is not yet stable on this architecture, so invoking
+	/// [`syscall!`](crate::syscall!) requires a nightly toolchain and
+	/// `#![feature(asm_experimental_arch)]`.
+	///
+	/// The kernel may overwrite the `hi` and `lo` registers during a syscall,
+	/// and `asm!` can't declare them as clobbered on MIPS. Support for this
+	/// architecture is therefore best-effort: it relies on the compiler not
+	/// keeping values in `hi` or `lo` across a syscall.
+	#[cfg(any(target_arch = "mips", doc))]
+	pub mod mips {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `mips64` architecture (`n64` ABI).
+	///
+	/// `asm!` is not yet stable on this architecture, so invoking
+	/// [`syscall!`](crate::syscall!) requires a nightly toolchain and
+	/// `#![feature(asm_experimental_arch)]`.
+	///
+	/// The kernel may overwrite the `hi` and `lo` registers during a syscall,
+	/// and `asm!` can't declare them as clobbered on MIPS. Support for this
+	/// architecture is therefore best-effort: it relies on the compiler not
+	/// keeping values in `hi` or `lo` across a syscall.
+	#[cfg(any(all(target_arch = "mips64", target_pointer_width = "64"), doc))]
+	pub mod mips64 {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `powerpc64` architecture.
+	#[cfg(any(target_arch = "powerpc64", doc))]
+	pub mod powerpc64 {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `riscv32` architecture.
+	#[cfg(any(target_arch = "riscv32", doc))]
+	pub mod riscv32 {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `riscv64` architecture.
+	#[cfg(any(target_arch = "riscv64", doc))]
+	pub mod riscv64 {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `s390x` architecture.
+	#[cfg(any(target_arch = "s390x", doc))]
+	pub mod s390x {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `x86` architecture.
+	///
+	/// On `x86_64` targets these syscalls may be invoked through the 32-bit
+	/// compatibility entry point with [`syscall32!`](crate::syscall32!).
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64", doc))]
+	pub mod x86 {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		#[cfg(any(target_arch = "x86", doc))]
+		pub use self::syscall_asm::{set_vsyscall_entry, vsyscall_entry};
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `x86_64` architecture (`x32` ABI).
+	#[cfg(any(all(target_arch = "x86_64", target_pointer_width = "32"), doc))]
+	pub mod x32 {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+
+	/// Linux syscall numbers for the `x86_64` architecture.
+	#[cfg(any(all(target_arch = "x86_64", target_pointer_width = "64"), doc))]
+	pub mod x86_64 {
+		mod syscall_asm;
+		pub use self::syscall_asm::Result;
+
+		pub(crate) mod syscall_tbl;
+		pub use self::syscall_tbl::*;
+	}
+}
+
+#[cfg(target_arch = "arm")]
+pub use crate::arch::arm::syscall_tbl::*;
+
+#[cfg(target_arch = "aarch64")]
+pub use crate::arch::aarch64::syscall_tbl::*;
+
+#[cfg(target_arch = "loongarch64")]
+pub use crate::arch::loongarch64::syscall_tbl::*;
+
+#[cfg(target_arch = "mips")]
+pub use crate::arch::mips::syscall_tbl::*;
+
+#[cfg(all(target_arch = "mips64", target_pointer_width = "64"))]
+pub use crate::arch::mips64::syscall_tbl::*;
+
+#[cfg(target_arch = "powerpc64")]
+pub use crate::arch::powerpc64::syscall_tbl::*;
+
+#[cfg(target_arch = "riscv32")]
+pub use crate::arch::riscv32::syscall_tbl::*;
+
+#[cfg(target_arch = "riscv64")]
+pub use crate::arch::riscv64::syscall_tbl::*;
+
+#[cfg(target_arch = "s390x")]
+pub use crate::arch::s390x::syscall_tbl::*;
+
+#[cfg(target_arch = "x86")]
+pub use crate::arch::x86::syscall_tbl::*;
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+pub use crate::arch::x32::syscall_tbl::*;
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+pub use crate::arch::x86_64::syscall_tbl::*;
+
+// The syscall result type for the current target.
+#[cfg(target_arch = "arm")]
+type NativeResult = crate::arch::arm::Result;
+
+#[cfg(target_arch = "aarch64")]
+type NativeResult = crate::arch::aarch64::Result;
+
+#[cfg(target_arch = "loongarch64")]
+type NativeResult = crate::arch::loongarch64::Result;
+
+#[cfg(target_arch = "mips")]
+type NativeResult = crate::arch::mips::Result;
+
+#[cfg(all(target_arch = "mips64", target_pointer_width = "64"))]
+type NativeResult = crate::arch::mips64::Result;
+
+#[cfg(target_arch = "powerpc64")]
+type NativeResult = crate::arch::powerpc64::Result;
+
+#[cfg(target_arch = "riscv32")]
+type NativeResult = crate::arch::riscv32::Result;
+
+#[cfg(target_arch = "riscv64")]
+type NativeResult = crate::arch::riscv64::Result;
+
+#[cfg(target_arch = "s390x")]
+type NativeResult = crate::arch::s390x::Result;
+
+#[cfg(target_arch = "x86")]
+type NativeResult = crate::arch::x86::Result;
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+type NativeResult = crate::arch::x32::Result;
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+type NativeResult = crate::arch::x86_64::Result;
+
+/// Invokes a Linux syscall.
+///
+/// `$syscall` must be a value that implements [`Into<Syscall>`](Syscall), or
+/// a [`TypedSyscall`] that accepts the given number of arguments.
+/// Other arguments must implement [`IntoSyscallArg`], such as integers,
+/// pointers or references.
+///
+/// The returned value is an architecture-specific implementation of [`Result`].
+///
+/// Additional traits implemented by syscall results vary by architecture.
+/// For all architectures currently supported by this library:
+///
+/// * The [`ResultSize`], [`ResultPtr`] and [`ResultDecode`] traits are
+///   implemented.
+/// * One of the [`Result32`] or [`Result64`] traits is implemented, according
+///   to the native word size.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// # fn main() -> core::result::Result<(), linux_errno::Error> {
+/// let stdout: i32 = 1;
+/// let hello = "Hello, world!\n\0";
+/// let rc = unsafe {
+///     syscall!(SYS_write, stdout, hello.as_ptr(), hello.len())
+/// };
+/// rc.check()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Safety
+///
+/// Very unsafe. See the [module documentation](self) for details.
+#[cfg(doc)]
+#[macro_export]
+macro_rules! syscall {
+	($syscall:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {
+		()
+	};
+}
+
+#[cfg(not(doc))]
+#[macro_export]
+macro_rules! syscall {
+	($($args:tt)*) => {
+		$crate::__syscall_with_options!([] $($args)*)
+	};
+}
+
+/// Invokes a Linux syscall that does not return.
+///
+/// Accepts the same arguments as [`syscall!`], but the expression has type
+/// `!` and no result is produced. This is intended for syscalls such as
+/// `exit`, `exit_group` and `rt_sigreturn`, or an `execve` that is known to
+/// succeed.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// fn exit(code: i32) -> ! {
+///     unsafe { syscall_noreturn!(SYS_exit_group, code) }
+/// }
+/// # fn main() { exit(0) }
+/// ```
+///
+/// # Safety
+///
+/// Very unsafe. See the [module documentation](self) for details.
+///
+/// If the syscall does return, for example because `execve` failed, the
+/// behavior is undefined.
+#[cfg(doc)]
+#[macro_export]
+macro_rules! syscall_noreturn {
+	($syscall:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {
+		()
+	};
+}
+
+/// Invokes a Linux syscall that does not access memory.
+///
+/// Accepts the same arguments as [`syscall!`] and returns the same result, but
+/// lets the compiler assume that no memory is read or written by the syscall.
+/// This is intended for syscalls such as `getpid`, `gettid` and
+/// `sched_yield`. The syscall is still assumed to have side effects, so it
+/// is not removed or merged with other calls.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// # fn main() -> core::result::Result<(), linux_errno::Error> {
+/// let pid = unsafe { syscall_nomem!(SYS_getpid) };
+/// assert_eq!(pid.try_usize()?, std::process::id() as usize);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Safety
+///
+/// Very unsafe. See the [module documentation](self) for details.
+///
+/// The syscall must not read or write any memory accessible to the program.
+#[macro_export]
+macro_rules! syscall_nomem {
+	($($args:tt)*) => {
+		$crate::__syscall_with_options!([nomem] $($args)*)
+	};
+}
+
+/// Invokes a Linux syscall that does not write memory.
+///
+/// Accepts the same arguments as [`syscall!`] and returns the same result, but
+/// lets the compiler assume that memory is not written by the syscall. This
+/// is intended for syscalls that only read from their arguments, such as
+/// `write` or `access`.
+///
+/// # Safety
+///
+/// Very unsafe. See the [module documentation](self) for details.
+///
+/// The syscall must not write any memory accessible to the program.
+#[macro_export]
+macro_rules! syscall_readonly {
+	($($args:tt)*) => {
+		$crate::__syscall_with_options!([readonly] $($args)*)
+	};
+}
 
-			#[inline]
-			fn try_from(rc: $arch_result) -> core::result::Result<Self, Error> {
-				$crate::Result64::try_u64(&rc)
+/// Invokes a Linux syscall, retrying while it fails with `EINTR`.
+///
+/// Accepts the same arguments as [`syscall!`] and returns the result of the
+/// first attempt that did not fail with `EINTR`.
+///
+/// The argument expressions are evaluated again for each attempt, so any side
+/// effects they have are repeated. Bind the result of an expression such as
+/// `iter.next()` to a local variable first if it must only be evaluated once.
+///
+/// An expression may follow the arguments after a `;`. It is evaluated before
+/// each retry, and can be used to update arguments that must change between
+/// attempts. Syscalls with a relative timeout need this so that retries don't
+/// extend the total wait:
+///
+/// * `nanosleep` writes the remaining time to its second argument, so
+///   `syscall_retry!(SYS_nanosleep, &req, &mut rem; req = rem)` sleeps for
+///   the requested time in total.
+/// * `ppoll` and `pselect6` update their timeout argument with the remaining
+///   time, so they can be retried without a hook.
+/// * Syscalls that don't report the remaining time, such as `epoll_wait` or a
+///   `futex` wait, need a hook that reads the clock and recomputes the timeout
+///   from a deadline.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// # fn main() -> core::result::Result<(), linux_errno::Error> {
+/// const AT_FDCWD: i32 = -100;
+/// let path = "/dev/zero\0";
+/// let rc = unsafe { syscall!(SYS_openat, AT_FDCWD, path.as_ptr(), 0) };
+/// let fd = rc.try_isize()? as i32;
+///
+/// let mut buf = [1u8; 64];
+/// let mut retries = 0;
+/// let rc = unsafe {
+///     syscall_retry!(SYS_read, fd, &mut buf[..], buf.len(); retries += 1)
+/// };
+/// assert_eq!(rc.try_usize()?, buf.len());
+/// assert_eq!(buf, [0u8; 64]);
+///
+/// unsafe { syscall!(SYS_close, fd) }.check()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Safety
+///
+/// Very unsafe. See the [module documentation](self) for details.
+#[macro_export]
+macro_rules! syscall_retry {
+	($syscall:expr $(, $arg:expr)* $(,)? $(; $retry:expr)?) => {
+		loop {
+			let rc = $crate::syscall!($syscall $(, $arg)*);
+			if !$crate::__is_eintr(&rc) {
+				break rc;
 			}
+			$($retry;)?
 		}
 	};
 }
 
-macro_rules! syscall_constants {
-	( $( $name:ident = $value:literal , )+ ) => {
-		use $crate::Syscall;
-		$(
-			pub const $name: Syscall = Syscall::from_u32($value);
-		)*
-	};
+#[doc(hidden)]
+#[inline]
+pub fn __is_eintr<R: Result>(rc: &R) -> bool {
+	rc.check() == Err(linux_errno::EINTR)
 }
 
-/// Linux syscall numbers for specific target architectures.
-pub mod arch {
-	/// Linux syscall numbers for the `aarch64` architecture.
-	#[cfg(any(target_arch = "aarch64", doc))]
-	pub mod aarch64 {
-		mod syscall_asm;
-		pub use self::syscall_asm::Result;
+native_arch_items! {
+	/// Invokes a Linux syscall with no arguments.
+	///
+	/// This is equivalent to [`syscall!`], but can be used where a function is
+	/// required, for example as a function pointer. Arguments are passed as
+	/// [`ArgRegister`] values, which can be produced with
+	/// [`IntoSyscallArg::into_syscall_arg`]. The returned value is the
+	/// [`arch`] result type for the current target.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use linux_syscall::*;
+	/// # fn main() -> core::result::Result<(), linux_errno::Error> {
+	/// let pid = unsafe { syscall0(SYS_getpid) };
+	/// assert_eq!(pid.try_usize()?, std::process::id() as usize);
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[inline]
+	pub unsafe fn syscall0(nr: Syscall) -> NativeResult {
+		crate::syscall!(nr)
+	}
 
-		pub(crate) mod syscall_tbl;
-		pub use self::syscall_tbl::*;
+	/// Invokes a Linux syscall with one argument.
+	///
+	/// See [`syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[inline]
+	pub unsafe fn syscall1(nr: Syscall, a1: ArgRegister) -> NativeResult {
+		crate::syscall!(nr, a1)
 	}
 
-	/// Linux syscall numbers for the `arm` architecture.
-	#[cfg(any(target_arch = "arm", doc))]
-	pub mod arm {
-		mod syscall_asm;
-		pub use self::syscall_asm::Result;
+	/// Invokes a Linux syscall with two arguments.
+	///
+	/// See [`syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[inline]
+	pub unsafe fn syscall2(
+		nr: Syscall,
+		a1: ArgRegister,
+		a2: ArgRegister,
+	) -> NativeResult {
+		crate::syscall!(nr, a1, a2)
+	}
 
-		pub(crate) mod syscall_tbl;
-		pub use self::syscall_tbl::*;
+	/// Invokes a Linux syscall with three arguments.
+	///
+	/// See [`syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[inline]
+	pub unsafe fn syscall3(
+		nr: Syscall,
+		a1: ArgRegister,
+		a2: ArgRegister,
+		a3: ArgRegister,
+	) -> NativeResult {
+		crate::syscall!(nr, a1, a2, a3)
 	}
 
-	/// Linux syscall numbers for the `riscv64` architecture.
-	#[cfg(any(target_arch = "riscv64", doc))]
-	pub mod riscv64 {
-		mod syscall_asm;
-		pub use self::syscall_asm::Result;
+	/// Invokes a Linux syscall with four arguments.
+	///
+	/// See [`syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[inline]
+	pub unsafe fn syscall4(
+		nr: Syscall,
+		a1: ArgRegister,
+		a2: ArgRegister,
+		a3: ArgRegister,
+		a4: ArgRegister,
+	) -> NativeResult {
+		crate::syscall!(nr, a1, a2, a3, a4)
+	}
 
-		pub(crate) mod syscall_tbl;
-		pub use self::syscall_tbl::*;
+	/// Invokes a Linux syscall with five arguments.
+	///
+	/// See [`syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[inline]
+	pub unsafe fn syscall5(
+		nr: Syscall,
+		a1: ArgRegister,
+		a2: ArgRegister,
+		a3: ArgRegister,
+		a4: ArgRegister,
+		a5: ArgRegister,
+	) -> NativeResult {
+		crate::syscall!(nr, a1, a2, a3, a4, a5)
 	}
 
-	/// Linux syscall numbers for the `x86` architecture.
-	#[cfg(any(target_arch = "x86", doc))]
-	pub mod x86 {
-		mod syscall_asm;
-		pub use self::syscall_asm::Result;
+	/// Invokes a Linux syscall with six arguments.
+	///
+	/// See [`syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[inline]
+	pub unsafe fn syscall6(
+		nr: Syscall,
+		a1: ArgRegister,
+		a2: ArgRegister,
+		a3: ArgRegister,
+		a4: ArgRegister,
+		a5: ArgRegister,
+		a6: ArgRegister,
+	) -> NativeResult {
+		crate::syscall!(nr, a1, a2, a3, a4, a5, a6)
+	}
 
-		pub(crate) mod syscall_tbl;
-		pub use self::syscall_tbl::*;
+	/// A C header declaring the functions exported by the `extern-c` feature.
+	///
+	/// The header is also distributed as `linux-syscall/linux-syscall.h`.
+	/// This constant lets a build script write it out for C code in the same
+	/// project.
+	#[cfg(feature = "extern-c")]
+	pub const C_HEADER: &str = include_str!("linux-syscall.h");
+
+	#[cfg(feature = "extern-c")]
+	#[inline]
+	fn c_result(rc: NativeResult) -> isize {
+		match rc.try_isize() {
+			Ok(value) => value,
+			Err(err) => -(err.get() as isize),
+		}
 	}
 
-	/// Linux syscall numbers for the `x86_64` architecture.
-	#[cfg(any(target_arch = "x86_64", doc))]
-	pub mod x86_64 {
-		mod syscall_asm;
-		pub use self::syscall_asm::Result;
+	/// Invokes a Linux syscall with no arguments, for callers using the C ABI.
+	///
+	/// This function is exported with an unmangled name when the `extern-c`
+	/// feature is enabled, and is declared in [`C_HEADER`]. On failure the
+	/// negated error number is returned, as with the raw syscall ABI of most
+	/// architectures.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[cfg(feature = "extern-c")]
+	#[no_mangle]
+	pub unsafe extern "C" fn linux_syscall0(nr: u32) -> isize {
+		c_result(crate::syscall!(Syscall::from_u32(nr)))
+	}
 
-		pub(crate) mod syscall_tbl;
-		pub use self::syscall_tbl::*;
+	/// Invokes a Linux syscall with one argument, for callers using the C ABI.
+	///
+	/// See [`linux_syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[cfg(feature = "extern-c")]
+	#[no_mangle]
+	pub unsafe extern "C" fn linux_syscall1(nr: u32, a1: usize) -> isize {
+		c_result(crate::syscall!(Syscall::from_u32(nr), a1))
 	}
-}
 
-#[cfg(target_arch = "arm")]
-pub use crate::arch::arm::syscall_tbl::*;
+	/// Invokes a Linux syscall with two arguments, for callers using the C ABI.
+	///
+	/// See [`linux_syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[cfg(feature = "extern-c")]
+	#[no_mangle]
+	pub unsafe extern "C" fn linux_syscall2(
+		nr: u32,
+		a1: usize,
+		a2: usize,
+	) -> isize {
+		c_result(crate::syscall!(Syscall::from_u32(nr), a1, a2))
+	}
 
-#[cfg(target_arch = "aarch64")]
-pub use crate::arch::aarch64::syscall_tbl::*;
+	/// Invokes a Linux syscall with three arguments, for callers using the C
+	/// ABI.
+	///
+	/// See [`linux_syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[cfg(feature = "extern-c")]
+	#[no_mangle]
+	pub unsafe extern "C" fn linux_syscall3(
+		nr: u32,
+		a1: usize,
+		a2: usize,
+		a3: usize,
+	) -> isize {
+		c_result(crate::syscall!(Syscall::from_u32(nr), a1, a2, a3))
+	}
 
-#[cfg(target_arch = "riscv64")]
-pub use crate::arch::riscv64::syscall_tbl::*;
+	/// Invokes a Linux syscall with four arguments, for callers using the C
+	/// ABI.
+	///
+	/// See [`linux_syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[cfg(feature = "extern-c")]
+	#[no_mangle]
+	pub unsafe extern "C" fn linux_syscall4(
+		nr: u32,
+		a1: usize,
+		a2: usize,
+		a3: usize,
+		a4: usize,
+	) -> isize {
+		c_result(crate::syscall!(Syscall::from_u32(nr), a1, a2, a3, a4))
+	}
 
-#[cfg(target_arch = "x86")]
-pub use crate::arch::x86::syscall_tbl::*;
+	/// Invokes a Linux syscall with five arguments, for callers using the C
+	/// ABI.
+	///
+	/// See [`linux_syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[cfg(feature = "extern-c")]
+	#[no_mangle]
+	pub unsafe extern "C" fn linux_syscall5(
+		nr: u32,
+		a1: usize,
+		a2: usize,
+		a3: usize,
+		a4: usize,
+		a5: usize,
+	) -> isize {
+		c_result(crate::syscall!(Syscall::from_u32(nr), a1, a2, a3, a4, a5))
+	}
 
-#[cfg(target_arch = "x86_64")]
-pub use crate::arch::x86_64::syscall_tbl::*;
+	/// Invokes a Linux syscall with six arguments, for callers using the C ABI.
+	///
+	/// See [`linux_syscall0`] for details.
+	///
+	/// # Safety
+	///
+	/// Very unsafe. See the [module documentation](self) for details.
+	#[cfg(feature = "extern-c")]
+	#[no_mangle]
+	pub unsafe extern "C" fn linux_syscall6(
+		nr: u32,
+		a1: usize,
+		a2: usize,
+		a3: usize,
+		a4: usize,
+		a5: usize,
+		a6: usize,
+	) -> isize {
+		c_result(crate::syscall!(Syscall::from_u32(nr), a1, a2, a3, a4, a5, a6))
+	}
+}
 
-/// Invokes a Linux syscall.
+/// Invokes a Linux syscall through the `x86` vDSO.
 ///
-/// `$syscall` must be a value that implements [`Into<Syscall>`](Syscall).
-/// Other arguments must be valid [`asm!`](core::arch::asm!) input operands,
-/// such as integers or pointers.
+/// If the address of `__kernel_vsyscall` has been set with
+/// [`arch::x86::set_vsyscall_entry`], the syscall is made by calling it,
+/// which uses the fastest entry instruction supported by the CPU. Otherwise
+/// this is equivalent to [`syscall!`].
 ///
-/// The returned value is an architecture-specific implementation of [`Result`].
+/// The returned value is an [`arch::x86::Result`].
 ///
-/// Additional traits implemented by syscall results vary by architecture.
-/// For all architectures currently supported by this library:
+/// # Safety
 ///
-/// * The [`ResultSize`] and [`ResultPtr`] traits are implemented.
-/// * One of the [`Result32`] or [`Result64`] traits is implemented, according
-///   to the native word size.
+/// Very unsafe. See the [module documentation](self) for details.
+#[cfg(doc)]
+#[macro_export]
+macro_rules! vsyscall {
+	($syscall:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {
+		()
+	};
+}
+
+/// Invokes a 32-bit `x86` Linux syscall from an `x86_64` process.
+///
+/// The syscall is made with `int $0x80`, which enters the kernel through its
+/// 32-bit compatibility entry point. `$syscall` must be an [`arch::x86`]
+/// syscall number. Other arguments must implement [`IntoSyscallArg`], and are
+/// truncated to 32 bits.
+///
+/// The returned value is an [`arch::x86::Result`].
+///
+/// # Example
+///
+/// ```no_run
+/// # #[macro_use] extern crate linux_syscall;
+/// # use linux_syscall::*;
+/// # fn main() -> core::result::Result<(), linux_errno::Error> {
+/// use linux_syscall::arch::x86;
+///
+/// let pid = unsafe { syscall32!(x86::SYS_getpid) };
+/// assert_eq!(pid.try_u32()?, std::process::id());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Safety
+///
+/// Very unsafe. See the [module documentation](self) for details.
+///
+/// Pointers passed to 32-bit syscalls must be addressable with 32 bits,
+/// and the kernel must have been built with `CONFIG_IA32_EMULATION`.
+#[cfg(doc)]
+#[macro_export]
+macro_rules! syscall32 {
+	($syscall:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {
+		()
+	};
+	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {
+		()
+	};
+}
+
+/// Invokes a Linux syscall, splitting 64-bit arguments as needed.
+///
+/// Accepts the same arguments as [`syscall!`], except that any argument may
+/// be written as [`Arg64(value)`](Arg64). On 64-bit architectures such an
+/// argument is passed in a single register. On 32-bit architectures it is
+/// passed as a pair of registers in the target's byte order, preceded by a
+/// padding register if the calling convention requires the pair to start at
+/// an even register (as on `arm` and `mips`).
+///
+/// A few syscalls, such as `preadv`, `pwritev`, `preadv2` and `pwritev2`, take
+/// the low and high halves of a 64-bit value as two separate arguments that
+/// are never padded. Pass these as [`Arg64::low`] and [`Arg64::high`] instead
+/// of `Arg64(value)`.
+///
+/// Arguments are evaluated from left to right, followed by the syscall number.
+/// The total number of registers after splitting must not exceed six.
 ///
 /// # Example
 ///
@@ -385,27 +2227,590 @@ pub use crate::arch::x86_64::syscall_tbl::*;
 /// # #[macro_use] extern crate linux_syscall;
 /// # use linux_syscall::*;
 /// # fn main() -> core::result::Result<(), linux_errno::Error> {
-/// let stdout: i32 = 1;
-/// let hello = "Hello, world!\n\0";
+/// const AT_FDCWD: i32 = -100;
+/// let path = "/dev/zero\0";
+/// let fd = unsafe { syscall!(SYS_openat, AT_FDCWD, path.as_ptr(), 0) };
+/// let fd = fd.try_isize()? as i32;
+///
+/// let mut buf = [1u8; 16];
+/// let offset = 1u64 << 32;
 /// let rc = unsafe {
-/// 	syscall!(SYS_write, stdout, hello.as_ptr(), hello.len())
+///     syscall_split!(
+///         SYS_pread64,
+///         fd,
+///         buf.as_mut_ptr(),
+///         buf.len(),
+///         Arg64(offset),
+///     )
 /// };
-/// rc.check()?;
+/// assert_eq!(rc.try_usize()?, buf.len());
+/// assert_eq!(buf, [0u8; 16]);
+/// # unsafe { syscall!(SYS_close, fd) }.check()?;
 /// # Ok(())
 /// # }
 /// ```
 ///
+/// # Panics
+///
+/// Panics if a padding register is inserted where the syscall's
+/// [`ArgKind`] table has no [`ArgKind::Pad`], for example when `Arg64` is used
+/// for the offset of `preadv` on `arm` or `mips`.
+///
 /// # Safety
 ///
 /// Very unsafe. See the [module documentation](self) for details.
-#[cfg(doc)]
 #[macro_export]
-macro_rules! syscall {
-	($syscall:expr $(,)?) => {};
-	($syscall:expr, $a1:expr $(,)?) => {};
-	($syscall:expr, $a1:expr, $a2:expr $(,)?) => {};
-	($syscall:expr, $a1:expr, $a2:expr, $a3:expr $(,)?) => {};
-	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr $(,)?) => {};
-	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr $(,)?) => {};
-	($syscall:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr $(,)?) => {};
+macro_rules! syscall_split {
+	($nr:expr $(, $($args:tt)*)?) => {
+		$crate::__syscall_split_start!(
+			($crate::args_of, $crate::syscall),
+			$nr,
+			$($($args)*)?
+		)
+	};
+}
+
+// The state of `__syscall_split!` is the register layout, the syscall table
+// and macro used to finish the call, the syscall number, the registers
+// collected so far, the indexes of padding registers, and the parity of the
+// next register. The layout is one of:
+//
+// * `single`: a 64-bit argument is passed in one register.
+// * `pair`: a 64-bit argument is passed in the next two registers.
+// * `aligned`: a 64-bit argument is passed in an even/odd register pair,
+//   skipping a register if necessary.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_split {
+	(@unit $e:expr) => {
+		()
+	};
+	(@count [$($e:expr),*]) => {
+		[$($crate::__syscall_split!(@unit $e)),*].len()
+	};
+	(
+		@next ($layout:ident, $args_of:path, $($call:tt)*) $nr:expr,
+		[$($done:expr),*], [$($pad:expr),*], $parity:ident $(,)?
+	) => {{
+		let nr = $crate::IntoSyscall::<{
+			$crate::__syscall_split!(@count [$($done),*])
+		}>::into_syscall($nr);
+		$($crate::__check_arg64_pad($args_of(nr), nr, $pad);)*
+		$($call)*!(nr $(, $done)*)
+	}};
+	(
+		@next ($layout:ident, $($config:tt)*) $nr:expr,
+		[$($done:expr),*], [$($pad:expr),*], $parity:ident,
+		Arg64($arg:expr) $(, $($rest:tt)*)?
+	) => {
+		match $crate::Arg64($arg) {
+			arg => $crate::__syscall_split!(
+				@arg64 $layout $parity ($layout, $($config)*) $nr,
+				[$($done),*], [$($pad),*], arg, $($($rest)*)?
+			),
+		}
+	};
+	(
+		@next $config:tt $nr:expr, [$($done:expr),*], [$($pad:expr),*], even,
+		$arg:expr $(, $($rest:tt)*)?
+	) => {
+		match $arg {
+			arg => $crate::__syscall_split!(
+				@next $config $nr, [$($done,)* arg], [$($pad),*], odd,
+				$($($rest)*)?
+			),
+		}
+	};
+	(
+		@next $config:tt $nr:expr, [$($done:expr),*], [$($pad:expr),*], odd,
+		$arg:expr $(, $($rest:tt)*)?
+	) => {
+		match $arg {
+			arg => $crate::__syscall_split!(
+				@next $config $nr, [$($done,)* arg], [$($pad),*], even,
+				$($($rest)*)?
+			),
+		}
+	};
+	(
+		@arg64 single $parity:ident $config:tt $nr:expr,
+		[$($done:expr),*], [$($pad:expr),*], $arg:ident, $($rest:tt)*
+	) => {
+		$crate::__syscall_split!(
+			@next $config $nr, [$($done,)* $arg.0], [$($pad),*], $parity,
+			$($rest)*
+		)
+	};
+	(
+		@arg64 pair $parity:ident $config:tt $nr:expr,
+		[$($done:expr),*], [$($pad:expr),*], $arg:ident, $($rest:tt)*
+	) => {
+		$crate::__syscall_split!(
+			@next $config $nr,
+			[$($done,)* $arg.register_pair().0, $arg.register_pair().1],
+			[$($pad),*],
+			$parity,
+			$($rest)*
+		)
+	};
+	(
+		@arg64 aligned even $config:tt $nr:expr,
+		[$($done:expr),*], [$($pad:expr),*], $arg:ident, $($rest:tt)*
+	) => {
+		$crate::__syscall_split!(@arg64 pair even $config $nr,
+			[$($done),*], [$($pad),*], $arg, $($rest)*
+		)
+	};
+	(
+		@arg64 aligned odd $config:tt $nr:expr,
+		[$($done:expr),*], [$($pad:expr),*], $arg:ident, $($rest:tt)*
+	) => {
+		$crate::__syscall_split!(@arg64 pair even $config $nr,
+			[$($done,)* 0u32],
+			[$($pad,)* $crate::__syscall_split!(@count [$($done),*])],
+			$arg,
+			$($rest)*
+		)
+	};
+}
+
+#[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_split_start {
+	(($($config:tt)*), $nr:expr, $($args:tt)*) => {
+		$crate::__syscall_split!(
+			@next (single, $($config)*) $nr, [], [], even, $($args)*
+		)
+	};
+}
+
+#[cfg(all(
+	target_pointer_width = "32",
+	not(any(
+		target_arch = "arm",
+		target_arch = "mips",
+		target_arch = "x86_64"
+	)),
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_split_start {
+	(($($config:tt)*), $nr:expr, $($args:tt)*) => {
+		$crate::__syscall_split!(
+			@next (pair, $($config)*) $nr, [], [], even, $($args)*
+		)
+	};
+}
+
+// The `arm` EABI and `mips` o32 ABI pass 64-bit arguments in an aligned pair
+// of registers, skipping a register if necessary.
+#[cfg(any(target_arch = "arm", target_arch = "mips"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_split_start {
+	(($($config:tt)*), $nr:expr, $($args:tt)*) => {
+		$crate::__syscall_split!(
+			@next (aligned, $($config)*) $nr, [], [], even, $($args)*
+		)
+	};
+}
+
+// Some syscalls, such as `preadv`, take the halves of a 64-bit value as two
+// separate arguments. These are not aligned, so a padding register inserted
+// by `syscall_split!` would shift the remaining arguments.
+#[doc(hidden)]
+#[track_caller]
+pub fn __check_arg64_pad(args: Option<&[ArgKind]>, nr: Syscall, index: usize) {
+	if let Some(args) = args {
+		assert!(
+			args.get(index) == Some(&ArgKind::Pad),
+			"syscall {} has no padding register before argument register {}",
+			nr.nr,
+			index,
+		);
+	}
+}
+
+// 32-bit syscall tables, for testing `syscall_split!` on other hosts.
+#[cfg(test)]
+#[allow(clippy::duplicate_mod, dead_code)]
+#[path = "arch/arm/syscall_tbl.rs"]
+mod arm_tbl;
+
+#[cfg(test)]
+#[allow(clippy::duplicate_mod, dead_code)]
+#[path = "arch/mips/syscall_tbl.rs"]
+mod mips_tbl;
+
+#[cfg(test)]
+#[allow(clippy::duplicate_mod, dead_code)]
+#[path = "arch/x86/syscall_tbl.rs"]
+mod x86_tbl;
+
+#[cfg(test)]
+mod tests {
+	extern crate std;
+
+	use std::vec::Vec;
+
+	use super::*;
+	use super::{arm_tbl, mips_tbl, x86_tbl};
+
+	macro_rules! registers {
+		($nr:expr $(, $reg:expr)*) => {
+			($nr, [$(IntoSyscallArg::into_syscall_arg($reg)),*].to_vec())
+		};
+	}
+
+	// Expands `syscall_split!` with the given register layout and syscall
+	// table, returning the registers instead of invoking the syscall.
+	macro_rules! split {
+		($layout:ident, $tbl:ident :: $nr:ident $(, $($args:tt)*)?) => {
+			__syscall_split!(
+				@next ($layout, $tbl::args_of, registers) $tbl::$nr,
+				[], [], even, $($($args)*)?
+			)
+		};
+	}
+
+	#[test]
+	fn syscall_names_round_trip() {
+		for &(name, syscall) in ALL {
+			assert_eq!(name_of(syscall), Some(name));
+			assert_eq!(from_name(name), Some(syscall), "{}", name);
+			let prefixed = std::format!("SYS_{}", name);
+			assert_eq!(from_name(&prefixed), Some(syscall), "{}", prefixed);
+		}
+	}
+
+	#[test]
+	fn syscall_table_order() {
+		for pair in ALL.windows(2) {
+			assert!(pair[0].1 < pair[1].1, "{:?}", pair);
+		}
+
+		let mut names: Vec<&str> = ALL.iter().map(|&(name, _)| name).collect();
+		names.sort_unstable();
+		for pair in names.windows(2) {
+			assert_ne!(pair[0], pair[1], "duplicate syscall name");
+		}
+	}
+
+	#[test]
+	fn syscall_unknown_names() {
+		assert_eq!(from_name(""), None);
+		assert_eq!(from_name("SYS_"), None);
+		assert_eq!(from_name("no_such_syscall"), None);
+		assert_eq!(from_name("WRITE"), None);
+		assert_eq!(from_name("sys_write"), None);
+		assert_eq!(from_name("SYS_SYS_write"), None);
+		assert_eq!(from_name("write\0"), None);
+		assert_eq!(name_of(Syscall::from_u32(u32::MAX)), None);
+		assert!("no_such_syscall".parse::<Syscall>().is_err());
+	}
+
+	#[test]
+	fn syscall_memory_options_agree() {
+		let pid = Ok(std::process::id() as usize);
+		assert_eq!(unsafe { syscall!(SYS_getpid) }.try_usize(), pid);
+		assert_eq!(unsafe { syscall_nomem!(SYS_getpid) }.try_usize(), pid);
+		assert_eq!(unsafe { syscall_readonly!(SYS_getpid) }.try_usize(), pid);
+
+		let ebadf = Err(linux_errno::EBADF);
+		let buf = core::ptr::null_mut::<u8>();
+		let read = unsafe { syscall!(SYS_read, -1, buf, 0) };
+		assert_eq!(read.try_usize(), ebadf);
+		let read = unsafe { syscall_nomem!(SYS_read, -1, buf, 0) };
+		assert_eq!(read.try_usize(), ebadf);
+		let read = unsafe { syscall_readonly!(SYS_read, -1, buf, 0) };
+		assert_eq!(read.try_usize(), ebadf);
+
+		let path = "/dev/zero\0";
+		let fd = unsafe { syscall!(SYS_openat, -100, path.as_ptr(), 0) };
+		let fd = fd.try_isize().unwrap() as i32;
+
+		// Empty reads don't access memory.
+		let read = unsafe { syscall!(SYS_read, fd, buf, 0) };
+		assert_eq!(read.try_usize(), Ok(0));
+		let read = unsafe { syscall_nomem!(SYS_read, fd, buf, 0) };
+		assert_eq!(read.try_usize(), Ok(0));
+		let read = unsafe { syscall_readonly!(SYS_read, fd, buf, 0) };
+		assert_eq!(read.try_usize(), Ok(0));
+
+		let mut buf = [1u8; 16];
+		let read = unsafe { syscall!(SYS_read, fd, buf.as_mut_ptr(), 16) };
+		assert_eq!(read.try_usize(), Ok(16));
+		assert_eq!(buf, [0u8; 16]);
+
+		assert_eq!(unsafe { syscall!(SYS_close, fd) }.check(), Ok(()));
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "64")]
+	fn syscall_retry_relative_timeouts() {
+		use core::ptr::{null, null_mut};
+		use core::sync::atomic::{AtomicBool, Ordering};
+		use std::sync::Arc;
+		use std::time::{Duration, Instant};
+
+		extern "C" {
+			fn signal(signum: i32, handler: extern "C" fn(i32)) -> usize;
+		}
+		extern "C" fn on_signal(_: i32) {}
+		const SIGUSR1: i32 =
+			if cfg!(any(target_arch = "mips", target_arch = "mips64")) {
+				16
+			} else {
+				10
+			};
+		const TIMEOUT: Duration = Duration::from_millis(100);
+
+		unsafe { signal(SIGUSR1, on_signal) };
+		let pid = unsafe { syscall!(SYS_getpid) }.try_usize().unwrap();
+		let tid = unsafe { syscall!(SYS_gettid) }.try_usize().unwrap();
+
+		// Interrupt this thread until both syscalls have returned.
+		let done = Arc::new(AtomicBool::new(false));
+		let signaller = {
+			let done = done.clone();
+			std::thread::spawn(move || {
+				let start = Instant::now();
+				while !done.load(Ordering::Relaxed)
+					&& start.elapsed() < Duration::from_secs(2)
+				{
+					std::thread::sleep(Duration::from_millis(5));
+					let rc = unsafe { syscall!(SYS_tgkill, pid, tid, SIGUSR1) };
+					rc.check().unwrap();
+				}
+			})
+		};
+
+		// `struct timespec` on 64-bit targets.
+		let timespec = [0i64, TIMEOUT.as_nanos() as i64];
+
+		// nanosleep() reports the remaining time, which the hook passes to
+		// the next attempt.
+		let (mut req, mut rem) = (timespec, [0i64; 2]);
+		let mut nanosleep_retries = 0;
+		let start = Instant::now();
+		let rc = unsafe {
+			syscall_retry!(SYS_nanosleep, &req, &mut rem; {
+				req = rem;
+				nanosleep_retries += 1;
+			})
+		};
+		let nanosleep_elapsed = start.elapsed();
+
+		// ppoll() updates its timeout argument itself.
+		let mut timeout = timespec;
+		let mut ppoll_retries = 0;
+		let fds = null_mut::<u8>();
+		let start = Instant::now();
+		let rc2 = unsafe {
+			syscall_retry!(
+				SYS_ppoll, fds, 0, &mut timeout, null::<u8>(), 8;
+				ppoll_retries += 1
+			)
+		};
+		let ppoll_elapsed = start.elapsed();
+
+		done.store(true, Ordering::Relaxed);
+		signaller.join().unwrap();
+
+		assert_eq!(rc.check(), Ok(()));
+		assert!(nanosleep_retries > 0);
+		assert!(nanosleep_elapsed >= TIMEOUT);
+		assert!(nanosleep_elapsed < 5 * TIMEOUT, "{:?}", nanosleep_elapsed);
+
+		assert_eq!(rc2.try_usize(), Ok(0));
+		assert!(ppoll_retries > 0);
+		assert_eq!(timeout, [0, 0]);
+		assert!(ppoll_elapsed >= TIMEOUT);
+		assert!(ppoll_elapsed < 5 * TIMEOUT, "{:?}", ppoll_elapsed);
+	}
+
+	#[test]
+	#[cfg(target_arch = "x86")]
+	fn vsyscall_matches_syscall() {
+		use core::ffi::c_ulong;
+		use core::ptr::{null, null_mut};
+
+		extern "C" {
+			fn getauxval(kind: c_ulong) -> c_ulong;
+		}
+		const AT_SYSINFO: c_ulong = 32;
+		let sysinfo = unsafe { getauxval(AT_SYSINFO) } as *const ();
+		assert!(!sysinfo.is_null());
+
+		let path = "/dev/zero\0";
+		let fd = unsafe { syscall!(SYS_openat, -100, path.as_ptr(), 0) };
+		let fd = fd.try_isize().unwrap() as i32;
+
+		for &entry in &[null(), sysinfo, null()] {
+			unsafe { arch::x86::set_vsyscall_entry(entry) };
+
+			// rt_sigprocmask(SIG_BLOCK, NULL, &old, sizeof(old))
+			let (mut old, mut expect) = (0u64, 0u64);
+			let (set, old_ptr) = (null::<u64>(), &mut old as *mut u64);
+			let rc =
+				unsafe { vsyscall!(SYS_rt_sigprocmask, 0, set, old_ptr, 8) };
+			let expect_ptr = &mut expect as *mut u64;
+			let expect_rc =
+				unsafe { syscall!(SYS_rt_sigprocmask, 0, set, expect_ptr, 8) };
+			assert_eq!((rc, old), (expect_rc, expect));
+
+			let mut buf = [1u8; 16];
+			let (ptr, len) = (buf.as_mut_ptr(), buf.len());
+			let rc = unsafe { vsyscall!(SYS_pread64, fd, ptr, len, 1, 0) };
+			assert_eq!(rc.try_usize(), Ok(16));
+			assert_eq!(buf, [0u8; 16]);
+			let rc = unsafe { vsyscall!(SYS_pread64, -1, ptr, len, 1, 0) };
+			let expect_rc =
+				unsafe { syscall!(SYS_pread64, -1, ptr, len, 1, 0) };
+			assert_eq!(rc, expect_rc);
+
+			// mmap2(NULL, 4096, PROT_READ | PROT_WRITE,
+			//       MAP_PRIVATE | MAP_ANONYMOUS, -1, 0)
+			let null = null_mut::<u8>();
+			let rc =
+				unsafe { vsyscall!(SYS_mmap2, null, 4096, 3, 0x22, -1, 0) };
+			let addr = rc.try_usize().unwrap() as *mut u8;
+			unsafe { addr.write(1) };
+			assert_eq!(
+				unsafe { syscall!(SYS_munmap, addr, 4096) }.check(),
+				Ok(())
+			);
+			let rc = unsafe { vsyscall!(SYS_mmap2, null, 4096, 3, 0x2, -1, 0) };
+			let expect_rc =
+				unsafe { syscall!(SYS_mmap2, null, 4096, 3, 0x2, -1, 0) };
+			assert_eq!(rc, expect_rc);
+			assert_eq!(rc.check(), Err(linux_errno::EBADF));
+		}
+
+		assert_eq!(unsafe { syscall!(SYS_close, fd) }.check(), Ok(()));
+	}
+
+	#[test]
+	#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+	fn fcntl_getown_process_groups() {
+		use crate::arch::x86_64::Result;
+
+		let getown = |value: i64| Result::new(value as u64).try_fcntl_getown();
+		assert_eq!(getown(1234), Ok(1234));
+		assert_eq!(getown(-1234), Ok(-1234));
+		assert_eq!(getown(-1), Ok(-1));
+		assert_eq!(getown(-4095), Ok(-4095));
+		assert_eq!(getown(-9), Err(linux_errno::EBADF));
+		assert_eq!(getown(-22), Err(linux_errno::EINVAL));
+	}
+
+	#[test]
+	fn syscall_split_layout() {
+		const LO: usize = 0x89AB_CDEF;
+		const HI: usize = 0x0123_4567;
+		const ARG: Arg64 = Arg64(0x0123_4567_89AB_CDEF);
+		let buf = 0x1000 as *mut u8;
+
+		assert_eq!(
+			split!(
+				aligned,
+				arm_tbl::SYS_pread64,
+				3,
+				buf,
+				16usize,
+				Arg64(ARG.0)
+			),
+			(arm_tbl::SYS_pread64, std::vec![3, 0x1000, 16, 0, LO, HI]),
+		);
+		assert_eq!(
+			split!(
+				aligned,
+				mips_tbl::SYS_pread64,
+				3,
+				buf,
+				16usize,
+				Arg64(ARG.0)
+			),
+			(mips_tbl::SYS_pread64, std::vec![3, 0x1000, 16, 0, LO, HI]),
+		);
+		assert_eq!(
+			split!(
+				aligned,
+				arm_tbl::SYS_fallocate,
+				3,
+				1,
+				Arg64(ARG.0),
+				Arg64(2)
+			),
+			(arm_tbl::SYS_fallocate, std::vec![3, 1, LO, HI, 2, 0]),
+		);
+		assert_eq!(
+			split!(
+				aligned,
+				arm_tbl::SYS_arm_fadvise64_64,
+				3,
+				4,
+				Arg64(ARG.0),
+				Arg64(2),
+			),
+			(arm_tbl::SYS_arm_fadvise64_64, std::vec![3, 4, LO, HI, 2, 0]),
+		);
+		assert_eq!(
+			split!(
+				aligned,
+				arm_tbl::SYS_preadv,
+				3,
+				buf,
+				2,
+				ARG.low(),
+				ARG.high()
+			),
+			(arm_tbl::SYS_preadv, std::vec![3, 0x1000, 2, LO, HI]),
+		);
+
+		assert_eq!(
+			split!(pair, x86_tbl::SYS_pread64, 3, buf, 16usize, Arg64(ARG.0)),
+			(x86_tbl::SYS_pread64, std::vec![3, 0x1000, 16, LO, HI]),
+		);
+		assert_eq!(
+			split!(pair, x86_tbl::SYS_preadv, 3, buf, 2, Arg64(ARG.0)),
+			(x86_tbl::SYS_preadv, std::vec![3, 0x1000, 2, LO, HI]),
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "no padding register")]
+	fn syscall_split_unpadded_arg64() {
+		let buf = 0x1000 as *mut u8;
+		split!(aligned, arm_tbl::SYS_preadv, 3, buf, 2, Arg64(1));
+	}
+
+	#[test]
+	fn sort_by_name_matches_str_ord() {
+		let names = [
+			"readv",
+			"read",
+			"",
+			"readahead",
+			"a",
+			"write",
+			"b",
+			"aa",
+			"rea",
+		];
+		let mut table = [("", Syscall::from_u32(0)); 9];
+		for (ii, name) in names.iter().enumerate() {
+			table[ii] = (name, Syscall::from_u32(ii as u32));
+		}
+		let sorted = sort_by_name(table);
+
+		let mut expect = names;
+		expect.sort_unstable();
+		let got: Vec<&str> = sorted.iter().map(|&(name, _)| name).collect();
+		assert_eq!(got, expect);
+		for &(name, syscall) in &sorted {
+			assert_eq!(names[u32::from(syscall) as usize], name);
+		}
+	}
 }